@@ -0,0 +1,3 @@
+pub mod uri;
+
+pub use uri::AnsibleUri;
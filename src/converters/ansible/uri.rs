@@ -0,0 +1,183 @@
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::{AuthType, CurlCommand};
+use serde_json::{json, Map, Value};
+
+pub struct AnsibleUri;
+
+impl Convert for AnsibleUri {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Ntlm)) {
+            warnings
+                .push("The uri module has no NTLM authentication, credentials omitted".to_string());
+        }
+
+        if curl_cmd.proxy.is_some() {
+            warnings.push(
+                "The uri module reads proxies from the environment, --proxy ignored".to_string(),
+            );
+        }
+
+        if curl_cmd.location && curl_cmd.max_redirs.is_some() {
+            warnings.push("The uri module has no redirect limit, --max-redirs ignored".to_string());
+        }
+
+        if curl_cmd.retry.is_some() {
+            warnings.push("Use the task's retries/until keywords instead of --retry".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for AnsibleUri {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let mut output = String::new();
+        for curl_cmd in curl_cmds {
+            output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        }
+
+        let tasks: Vec<Value> = curl_cmds
+            .iter()
+            .map(|curl_cmd| self.task(curl_cmd))
+            .collect();
+        output.push_str(&serde_yaml_ng::to_string(&tasks).unwrap_or_default());
+        output
+    }
+}
+
+impl AnsibleUri {
+    fn task(&self, curl_cmd: &CurlCommand) -> Value {
+        let mut uri = Map::new();
+        uri.insert("url".to_string(), json!(curl_cmd.url));
+        uri.insert("method".to_string(), json!(curl_cmd.method));
+
+        let headers: Map<String, Value> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .map(|(key, value)| (key, Value::String(value)))
+            .collect();
+        if !headers.is_empty() {
+            uri.insert("headers".to_string(), Value::Object(headers));
+        }
+
+        self.body(curl_cmd, &mut uri);
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            match curl_cmd.auth_type {
+                None | Some(AuthType::Basic) => {
+                    uri.insert("url_username".to_string(), json!(username));
+                    uri.insert("url_password".to_string(), json!(password));
+                    uri.insert("force_basic_auth".to_string(), json!(true));
+                }
+                Some(AuthType::Digest) => {
+                    uri.insert("url_username".to_string(), json!(username));
+                    uri.insert("url_password".to_string(), json!(password));
+                }
+                Some(AuthType::Negotiate) => {
+                    uri.insert("use_gssapi".to_string(), json!(true));
+                }
+                Some(AuthType::Ntlm) => {}
+            }
+        }
+
+        if curl_cmd.insecure {
+            uri.insert("validate_certs".to_string(), json!(false));
+        }
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            uri.insert("client_cert".to_string(), json!(cert));
+        }
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            uri.insert("client_key".to_string(), json!(key));
+        }
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            uri.insert("ca_path".to_string(), json!(cacert));
+        }
+
+        let follow_redirects = if curl_cmd.location { "all" } else { "none" };
+        uri.insert("follow_redirects".to_string(), json!(follow_redirects));
+
+        if let Some(timeout) = curl_cmd.max_time.or(curl_cmd.connect_timeout) {
+            uri.insert("timeout".to_string(), json!(timeout));
+        }
+
+        if let Some(output) = &curl_cmd.output {
+            uri.insert("dest".to_string(), json!(output));
+        }
+
+        json!({
+            "name": utils::request_name(curl_cmd),
+            "ansible.builtin.uri": uri,
+        })
+    }
+
+    fn body(&self, curl_cmd: &CurlCommand, uri: &mut Map<String, Value>) {
+        let content_type = curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.to_lowercase());
+
+        let (body_format, body) = if let Some(data) = &curl_cmd.data {
+            let is_json = curl_cmd.data_is_json
+                || content_type
+                    .as_deref()
+                    .is_some_and(|value| value.contains("json"));
+            let is_form = content_type
+                .as_deref()
+                .is_none_or(|value| value.starts_with("application/x-www-form-urlencoded"));
+
+            match serde_json::from_str::<Value>(data) {
+                Ok(value) if is_json => ("json", value),
+                _ if is_form && data.contains('=') => {
+                    let fields: Map<String, Value> = url::form_urlencoded::parse(data.as_bytes())
+                        .map(|(key, value)| (key.to_string(), Value::String(value.to_string())))
+                        .collect();
+                    ("form-urlencoded", Value::Object(fields))
+                }
+                _ => ("raw", Value::String(data.clone())),
+            }
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            uri.insert("src".to_string(), json!(binary_file));
+            return;
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut fields = Map::new();
+
+            let mut form: Vec<_> = curl_cmd.form.iter().collect();
+            form.sort();
+            for (key, value) in form {
+                fields.insert(key.clone(), json!(value));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let mut file = Map::new();
+                file.insert("filename".to_string(), json!(file_upload.path));
+                if let Some(content_type) = &file_upload.content_type {
+                    file.insert("mime_type".to_string(), json!(content_type));
+                }
+                fields.insert(key.clone(), Value::Object(file));
+            }
+
+            ("form-multipart", Value::Object(fields))
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let fields: Map<String, Value> = params
+                .into_iter()
+                .map(|(key, value)| (key.clone(), json!(value)))
+                .collect();
+            ("form-urlencoded", Value::Object(fields))
+        } else {
+            return;
+        };
+
+        uri.insert("body_format".to_string(), json!(body_format));
+        uri.insert("body".to_string(), body);
+    }
+}
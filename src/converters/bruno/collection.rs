@@ -0,0 +1,270 @@
+use crate::converters::{utils, Convert, ConvertTree};
+use crate::{AuthType, CurlCommand};
+use url::Url;
+
+const METHODS: [&str; 7] = ["GET", "POST", "PUT", "DELETE", "PATCH", "OPTIONS", "HEAD"];
+
+pub struct BrunoCollection;
+
+impl Convert for BrunoCollection {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.bru(curl_cmd, 1)
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if !METHODS.contains(&curl_cmd.method.as_str()) {
+            warnings.push(format!(
+                "Bruno does not support the {} method, exported as GET",
+                curl_cmd.method
+            ));
+        }
+
+        if curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Negotiate)) {
+            warnings.push("Bruno has no negotiate auth mode, credentials omitted".to_string());
+        }
+
+        if curl_cmd.insecure {
+            warnings.push(
+                "Bruno SSL verification is an app preference, --insecure ignored".to_string(),
+            );
+        }
+
+        if curl_cmd.proxy.is_some() {
+            warnings.push("Bruno proxies are configured in bruno.json".to_string());
+        }
+
+        if curl_cmd.ssl_options.cert.is_some() || curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push("Bruno client certificates are configured in bruno.json".to_string());
+        }
+
+        if curl_cmd.connect_timeout.is_some() || curl_cmd.max_time.is_some() {
+            warnings.push("Bruno request timeouts are an app preference".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl ConvertTree for BrunoCollection {
+    fn convert_tree(&self, curl_cmds: &[CurlCommand]) -> Vec<(String, String)> {
+        let collection = serde_json::json!({
+            "version": "1",
+            "name": env!("CARGO_PKG_NAME"),
+            "type": "collection",
+            "ignore": ["node_modules", ".git"],
+        });
+        let mut files = vec![(
+            "bruno.json".to_string(),
+            format!(
+                "{}\n",
+                serde_json::to_string_pretty(&collection).unwrap_or_default()
+            ),
+        )];
+
+        let mut hosts: Vec<(String, usize)> = Vec::new();
+        for curl_cmd in curl_cmds {
+            let host = utils::slug(&utils::host(curl_cmd), "requests");
+            let seq = match hosts.iter_mut().find(|(known, _)| *known == host) {
+                Some((_, count)) => {
+                    *count += 1;
+                    *count
+                }
+                None => {
+                    hosts.push((host.clone(), 1));
+                    1
+                }
+            };
+
+            let stem = self.file_stem(curl_cmd);
+            let mut path = format!("{}/{}.bru", host, stem);
+            let mut suffix = 2;
+            while files.iter().any(|(existing, _)| *existing == path) {
+                path = format!("{}/{}-{}.bru", host, stem, suffix);
+                suffix += 1;
+            }
+
+            files.push((path, self.bru(curl_cmd, seq)));
+        }
+
+        files
+    }
+}
+
+impl BrunoCollection {
+    fn bru(&self, curl_cmd: &CurlCommand, seq: usize) -> String {
+        let body = self.body(curl_cmd);
+        let auth = self.auth(curl_cmd);
+
+        let mut output = String::new();
+        output.push_str("meta {\n");
+        output.push_str(&format!("  name: {}\n", utils::request_name(curl_cmd)));
+        output.push_str("  type: http\n");
+        output.push_str(&format!("  seq: {}\n", seq));
+        output.push_str("}\n\n");
+
+        let method = if METHODS.contains(&curl_cmd.method.as_str()) {
+            curl_cmd.method.to_lowercase()
+        } else {
+            "get".to_string()
+        };
+        output.push_str(&format!("{} {{\n", method));
+        output.push_str(&format!("  url: {}\n", curl_cmd.url));
+        output.push_str(&format!(
+            "  body: {}\n",
+            body.as_ref().map_or("none", |(mode, _)| mode.as_str())
+        ));
+        output.push_str(&format!(
+            "  auth: {}\n",
+            auth.as_ref().map_or("none", |(mode, _)| mode.as_str())
+        ));
+        output.push_str("}\n");
+
+        if let Ok(url) = Url::parse(&curl_cmd.url) {
+            let params: Vec<(String, String)> = url
+                .query_pairs()
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect();
+            self.block(&mut output, "params:query", &params);
+        }
+
+        let headers: Vec<(String, String)> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .filter(|(key, _)| {
+                curl_cmd.oauth2_bearer.is_none() || !key.eq_ignore_ascii_case("authorization")
+            })
+            .collect();
+        self.block(&mut output, "headers", &headers);
+
+        if let Some((mode, entries)) = &auth {
+            self.block(&mut output, &format!("auth:{}", mode), entries);
+        }
+
+        if let Some((mode, content)) = &body {
+            output.push_str(&format!("\nbody:{} {{\n", mode));
+            for line in content.lines() {
+                if line.is_empty() {
+                    output.push('\n');
+                } else {
+                    output.push_str(&format!("  {}\n", line));
+                }
+            }
+            output.push_str("}\n");
+        }
+
+        output
+    }
+
+    fn block(&self, output: &mut String, name: &str, entries: &[(String, String)]) {
+        if entries.is_empty() {
+            return;
+        }
+
+        output.push_str(&format!("\n{} {{\n", name));
+        for (key, value) in entries {
+            output.push_str(&format!("  {}: {}\n", key, value));
+        }
+        output.push_str("}\n");
+    }
+
+    fn auth(&self, curl_cmd: &CurlCommand) -> Option<(String, Vec<(String, String)>)> {
+        if let Some(token) = &curl_cmd.oauth2_bearer {
+            return Some((
+                "bearer".to_string(),
+                vec![("token".to_string(), token.clone())],
+            ));
+        }
+
+        let (username, password) = curl_cmd.auth.as_ref()?;
+        let mode = match curl_cmd.auth_type {
+            None | Some(AuthType::Basic) => "basic",
+            Some(AuthType::Digest) => "digest",
+            Some(AuthType::Ntlm) => "ntlm",
+            Some(AuthType::Negotiate) => return None,
+        };
+
+        Some((
+            mode.to_string(),
+            vec![
+                ("username".to_string(), username.clone()),
+                ("password".to_string(), password.clone()),
+            ],
+        ))
+    }
+
+    fn body(&self, curl_cmd: &CurlCommand) -> Option<(String, String)> {
+        let content_type = curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.to_lowercase());
+
+        if let Some(data) = &curl_cmd.data {
+            let content_type = content_type.unwrap_or_default();
+            let is_form = content_type.is_empty()
+                || content_type.starts_with("application/x-www-form-urlencoded");
+            let mode = if curl_cmd.data_is_json || content_type.contains("json") {
+                "json"
+            } else if content_type.contains("xml") {
+                "xml"
+            } else if is_form && data.contains('=') {
+                let pairs: Vec<String> = url::form_urlencoded::parse(data.as_bytes())
+                    .map(|(key, value)| format!("{}: {}", key, value))
+                    .collect();
+                return Some(("form-urlencoded".to_string(), pairs.join("\n")));
+            } else {
+                "text"
+            };
+            Some((mode.to_string(), data.clone()))
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            let content_type =
+                content_type.unwrap_or_else(|| "application/octet-stream".to_string());
+            Some((
+                "file".to_string(),
+                format!(
+                    "file: @file({}) @contentType({})",
+                    binary_file, content_type
+                ),
+            ))
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            let mut lines: Vec<String> = fields
+                .into_iter()
+                .map(|(key, value)| format!("{}: {}", key, value))
+                .collect();
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                lines.push(format!("{}: @file({})", key, file_upload.path));
+            }
+
+            Some(("multipart-form".to_string(), lines.join("\n")))
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut pairs: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            pairs.sort();
+            let lines: Vec<String> = pairs
+                .into_iter()
+                .map(|(key, value)| format!("{}: {}", key, value))
+                .collect();
+
+            Some(("form-urlencoded".to_string(), lines.join("\n")))
+        } else {
+            None
+        }
+    }
+
+    fn file_stem(&self, curl_cmd: &CurlCommand) -> String {
+        let mut stem = String::new();
+        for c in utils::request_name(curl_cmd).to_lowercase().chars() {
+            if c.is_ascii_alphanumeric() {
+                stem.push(c);
+            } else if !stem.ends_with('-') {
+                stem.push('-');
+            }
+        }
+        utils::slug(&stem, "request")
+    }
+}
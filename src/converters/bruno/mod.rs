@@ -0,0 +1,3 @@
+pub mod collection;
+
+pub use collection::BrunoCollection;
@@ -0,0 +1,466 @@
+use crate::converters::c::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Libcurl;
+
+const WRITE_OUT_VARIABLES: [(&str, &str, &str, &str); 9] = [
+    ("http_code", "long", "CURLINFO_RESPONSE_CODE", "%ld"),
+    ("response_code", "long", "CURLINFO_RESPONSE_CODE", "%ld"),
+    ("num_redirects", "long", "CURLINFO_REDIRECT_COUNT", "%ld"),
+    ("time_total", "double", "CURLINFO_TOTAL_TIME", "%f"),
+    ("time_connect", "double", "CURLINFO_CONNECT_TIME", "%f"),
+    (
+        "size_download",
+        "curl_off_t",
+        "CURLINFO_SIZE_DOWNLOAD_T",
+        "%\" CURL_FORMAT_CURL_OFF_T \"",
+    ),
+    (
+        "size_upload",
+        "curl_off_t",
+        "CURLINFO_SIZE_UPLOAD_T",
+        "%\" CURL_FORMAT_CURL_OFF_T \"",
+    ),
+    ("content_type", "char *", "CURLINFO_CONTENT_TYPE", "%s"),
+    ("url_effective", "char *", "CURLINFO_EFFECTIVE_URL", "%s"),
+];
+
+impl Convert for Libcurl {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut declarations = vec!["CURLcode ret;".to_string(), "CURL *hnd;".to_string()];
+        let mut setup = String::new();
+        let mut options = String::new();
+        let mut cleanup = String::new();
+
+        let headers: Vec<String> = curl_cmd
+            .headers
+            .iter()
+            .filter(|(key, value)| {
+                let is_bearer = curl_cmd
+                    .oauth2_bearer
+                    .as_ref()
+                    .is_some_and(|token| *value == &format!("Bearer {}", token));
+                !(key.eq_ignore_ascii_case("authorization") && is_bearer)
+            })
+            .map(|(key, value)| format!("{}: {}", key, value))
+            .collect();
+        let mut headers = headers;
+        headers.sort();
+
+        if !headers.is_empty() {
+            declarations.push("struct curl_slist *slist1;".to_string());
+            setup.push_str("  slist1 = NULL;\n");
+            for header in &headers {
+                setup.push_str(&format!(
+                    "  slist1 = curl_slist_append(slist1, {});\n",
+                    string_literal(header)
+                ));
+            }
+            setup.push('\n');
+        }
+
+        self.setopt(&mut options, "CURLOPT_URL", &string_literal(&curl_cmd.url));
+
+        if let Some(output_file) = &curl_cmd.output {
+            declarations.push("FILE *out;".to_string());
+            setup.push_str(&format!(
+                "  out = fopen({}, \"wb\");\n",
+                string_literal(output_file)
+            ));
+            setup.push_str("  if(!out)\n    return 1;\n\n");
+            self.setopt(&mut options, "CURLOPT_WRITEDATA", "out");
+            cleanup.push_str("  fclose(out);\n");
+        }
+
+        let has_body = curl_cmd.data.is_some()
+            || curl_cmd.binary_file.is_some()
+            || !curl_cmd.form.is_empty()
+            || !curl_cmd.files.is_empty()
+            || utils::has_url_encoded_body(curl_cmd);
+
+        if let Some(data) = &curl_cmd.data {
+            self.setopt(&mut options, "CURLOPT_POSTFIELDS", &string_literal(data));
+            self.setopt(
+                &mut options,
+                "CURLOPT_POSTFIELDSIZE_LARGE",
+                &format!("(curl_off_t){}", data.len()),
+            );
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            declarations.push("FILE *in;".to_string());
+            declarations.push("long insize;".to_string());
+            setup.push_str(&format!(
+                "  in = fopen({}, \"rb\");\n",
+                string_literal(binary_file)
+            ));
+            setup.push_str("  if(!in)\n    return 1;\n");
+            setup.push_str("  fseek(in, 0L, SEEK_END);\n");
+            setup.push_str("  insize = ftell(in);\n");
+            setup.push_str("  rewind(in);\n\n");
+            self.setopt(&mut options, "CURLOPT_POST", "1L");
+            self.setopt(&mut options, "CURLOPT_READDATA", "in");
+            self.setopt(
+                &mut options,
+                "CURLOPT_POSTFIELDSIZE_LARGE",
+                "(curl_off_t)insize",
+            );
+            cleanup.push_str("  fclose(in);\n");
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            declarations.push("curl_mime *mime1;".to_string());
+            declarations.push("curl_mimepart *part1;".to_string());
+            options.push_str("  mime1 = curl_mime_init(hnd);\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                options.push_str("  part1 = curl_mime_addpart(mime1);\n");
+                options.push_str(&format!(
+                    "  curl_mime_data(part1, {}, CURL_ZERO_TERMINATED);\n",
+                    string_literal(value)
+                ));
+                options.push_str(&format!(
+                    "  curl_mime_name(part1, {});\n",
+                    string_literal(key)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                options.push_str("  part1 = curl_mime_addpart(mime1);\n");
+                options.push_str(&format!(
+                    "  curl_mime_filedata(part1, {});\n",
+                    string_literal(&file_upload.path)
+                ));
+                if let Some(filename) = &file_upload.filename {
+                    options.push_str(&format!(
+                        "  curl_mime_filename(part1, {});\n",
+                        string_literal(filename)
+                    ));
+                }
+                if let Some(content_type) = &file_upload.content_type {
+                    options.push_str(&format!(
+                        "  curl_mime_type(part1, {});\n",
+                        string_literal(content_type)
+                    ));
+                }
+                options.push_str(&format!(
+                    "  curl_mime_name(part1, {});\n",
+                    string_literal(key)
+                ));
+            }
+
+            self.setopt(&mut options, "CURLOPT_MIMEPOST", "mime1");
+            cleanup.push_str("  curl_mime_free(mime1);\n");
+            cleanup.push_str("  mime1 = NULL;\n");
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let body = utils::url_encoded_body(&curl_cmd.url_encoded_params);
+            self.setopt(&mut options, "CURLOPT_POSTFIELDS", &string_literal(&body));
+            self.setopt(
+                &mut options,
+                "CURLOPT_POSTFIELDSIZE_LARGE",
+                &format!("(curl_off_t){}", body.len()),
+            );
+        }
+
+        match curl_cmd.method.as_str() {
+            "GET" if !has_body => {}
+            "HEAD" => self.setopt(&mut options, "CURLOPT_NOBODY", "1L"),
+            "POST" if has_body => {}
+            method => self.setopt(
+                &mut options,
+                "CURLOPT_CUSTOMREQUEST",
+                &string_literal(method),
+            ),
+        }
+
+        if !headers.is_empty() {
+            self.setopt(&mut options, "CURLOPT_HTTPHEADER", "slist1");
+            cleanup.push_str("  curl_slist_free_all(slist1);\n");
+            cleanup.push_str("  slist1 = NULL;\n");
+        }
+
+        if let Some(user_agent) = &curl_cmd.user_agent {
+            self.setopt(
+                &mut options,
+                "CURLOPT_USERAGENT",
+                &string_literal(user_agent),
+            );
+        }
+
+        if !curl_cmd.cookies.is_empty() {
+            self.setopt(
+                &mut options,
+                "CURLOPT_COOKIE",
+                &string_literal(&utils::cookie_header(&curl_cmd.cookies)),
+            );
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            self.setopt(
+                &mut options,
+                "CURLOPT_USERPWD",
+                &string_literal(&format!("{}:{}", username, password)),
+            );
+            let auth_constant = match curl_cmd.auth_type {
+                Some(AuthType::Digest) => "CURLAUTH_DIGEST",
+                Some(AuthType::Ntlm) => "CURLAUTH_NTLM",
+                Some(AuthType::Negotiate) => "CURLAUTH_NEGOTIATE",
+                _ => "CURLAUTH_BASIC",
+            };
+            self.setopt(
+                &mut options,
+                "CURLOPT_HTTPAUTH",
+                &format!("(long){}", auth_constant),
+            );
+        } else if let Some(token) = &curl_cmd.oauth2_bearer {
+            self.setopt(
+                &mut options,
+                "CURLOPT_XOAUTH2_BEARER",
+                &string_literal(token),
+            );
+            self.setopt(&mut options, "CURLOPT_HTTPAUTH", "(long)CURLAUTH_BEARER");
+        }
+
+        if curl_cmd.insecure {
+            self.setopt(&mut options, "CURLOPT_SSL_VERIFYPEER", "0L");
+            self.setopt(&mut options, "CURLOPT_SSL_VERIFYHOST", "0L");
+        }
+
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            self.setopt(&mut options, "CURLOPT_CAINFO", &string_literal(cacert));
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            self.setopt(&mut options, "CURLOPT_SSLCERT", &string_literal(cert));
+        }
+
+        if let Some(cert_type) = &curl_cmd.ssl_options.cert_type {
+            self.setopt(
+                &mut options,
+                "CURLOPT_SSLCERTTYPE",
+                &string_literal(cert_type),
+            );
+        }
+
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            self.setopt(&mut options, "CURLOPT_SSLKEY", &string_literal(key));
+        }
+
+        if let Some(key_type) = &curl_cmd.ssl_options.key_type {
+            self.setopt(
+                &mut options,
+                "CURLOPT_SSLKEYTYPE",
+                &string_literal(key_type),
+            );
+        }
+
+        if curl_cmd.no_alpn {
+            self.setopt(&mut options, "CURLOPT_SSL_ENABLE_ALPN", "0L");
+        }
+
+        if curl_cmd.compressed {
+            self.setopt(&mut options, "CURLOPT_ACCEPT_ENCODING", "\"\"");
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            self.setopt(&mut options, "CURLOPT_PROXY", &string_literal(proxy));
+        }
+
+        if let Some((username, password)) = &curl_cmd.proxy_auth {
+            self.setopt(
+                &mut options,
+                "CURLOPT_PROXYUSERPWD",
+                &string_literal(&format!("{}:{}", username, password)),
+            );
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            self.setopt(
+                &mut options,
+                "CURLOPT_CONNECTTIMEOUT",
+                &format!("{}L", timeout),
+            );
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            self.setopt(&mut options, "CURLOPT_TIMEOUT", &format!("{}L", timeout));
+        }
+
+        if curl_cmd.location {
+            self.setopt(&mut options, "CURLOPT_FOLLOWLOCATION", "1L");
+        }
+
+        if let Some(max_redirs) = curl_cmd.max_redirs {
+            self.setopt(
+                &mut options,
+                "CURLOPT_MAXREDIRS",
+                &format!("{}L", max_redirs),
+            );
+        }
+
+        let mut output = String::new();
+        output.push_str(&self.warning_block(&self.warnings(curl_cmd)));
+        output.push_str("#include <stdio.h>\n");
+        output.push_str("#include <curl/curl.h>\n\n");
+        output.push_str("int main(void)\n{\n");
+
+        let write_out = curl_cmd
+            .write_out
+            .as_deref()
+            .map(|write_out| self.write_out(write_out));
+        if let Some((variables, _)) = &write_out {
+            for (name, c_type, _) in variables {
+                declarations.push(format!("{} {};", c_type, name).replace("* ", "*"));
+            }
+        }
+
+        for declaration in &declarations {
+            output.push_str(&format!("  {}\n", declaration));
+        }
+        output.push('\n');
+        output.push_str(&setup);
+        output.push_str("  hnd = curl_easy_init();\n");
+        output.push_str(&options);
+        output.push('\n');
+        output.push_str("  ret = curl_easy_perform(hnd);\n");
+
+        if let Some((variables, statement)) = &write_out {
+            output.push_str("  if(ret == CURLE_OK) {\n");
+            for (name, _, info) in variables {
+                output.push_str(&format!(
+                    "    curl_easy_getinfo(hnd, {}, &{});\n",
+                    info, name
+                ));
+            }
+            output.push_str(&format!("    {}\n", statement));
+            output.push_str("  }\n");
+        }
+
+        output.push('\n');
+        output.push_str("  curl_easy_cleanup(hnd);\n");
+        output.push_str("  hnd = NULL;\n");
+        output.push_str(&cleanup);
+        output.push('\n');
+        output.push_str("  return (int)ret;\n");
+        output.push_str("}\n");
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "--retry is implemented by the curl tool, call curl_easy_perform in a loop to retry {} times",
+                retry
+            ));
+        }
+
+        if let Some(write_out) = &curl_cmd.write_out {
+            for variable in self.unknown_write_out_variables(write_out) {
+                warnings.push(format!(
+                    "--write-out variable %{{{}}} is not translated",
+                    variable
+                ));
+            }
+        }
+
+        warnings
+    }
+}
+
+impl Libcurl {
+    fn setopt(&self, output: &mut String, option: &str, value: &str) {
+        output.push_str(&format!(
+            "  curl_easy_setopt(hnd, {}, {});\n",
+            option, value
+        ));
+    }
+
+    fn warning_block(&self, warnings: &[String]) -> String {
+        let mut output = String::new();
+        for warning in warnings {
+            output.push_str(&format!("/* Warning: {} */\n", warning));
+        }
+        if !warnings.is_empty() {
+            output.push('\n');
+        }
+        output
+    }
+
+    fn write_out(&self, write_out: &str) -> (Vec<(String, &'static str, &'static str)>, String) {
+        let mut variables: Vec<(String, &'static str, &'static str)> = Vec::new();
+        let mut format = String::new();
+        let mut arguments = Vec::new();
+        let mut rest = write_out;
+
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix("%{") {
+                if let Some(end) = after.find('}') {
+                    let name = &after[..end];
+                    if let Some((_, c_type, info, spec)) = WRITE_OUT_VARIABLES
+                        .iter()
+                        .find(|(known, ..)| *known == name)
+                    {
+                        if !variables.iter().any(|(variable, ..)| variable == name) {
+                            variables.push((name.to_string(), c_type, info));
+                        }
+                        format.push_str(spec);
+                        arguments.push(name.to_string());
+                    } else {
+                        format.push_str(&format!("%%{{{}}}", name));
+                    }
+                    rest = &after[end + 1..];
+                    continue;
+                }
+            }
+
+            if let Some(after) = rest.strip_prefix("\\n") {
+                format.push_str("\\n");
+                rest = after;
+                continue;
+            }
+
+            let c = rest.chars().next().unwrap();
+            match c {
+                '%' => format.push_str("%%"),
+                '"' => format.push_str("\\\""),
+                '\\' => format.push_str("\\\\"),
+                '\n' => format.push_str("\\n"),
+                _ => format.push(c),
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+
+        let mut statement = format!("printf(\"{}\"", format);
+        for argument in &arguments {
+            statement.push_str(&format!(", {}", argument));
+        }
+        statement.push_str(");");
+
+        (variables, statement)
+    }
+
+    fn unknown_write_out_variables(&self, write_out: &str) -> Vec<String> {
+        let mut unknown = Vec::new();
+        let mut rest = write_out;
+
+        while let Some(start) = rest.find("%{") {
+            let after = &rest[start + 2..];
+            match after.find('}') {
+                Some(end) => {
+                    let name = &after[..end];
+                    if !WRITE_OUT_VARIABLES.iter().any(|(known, ..)| *known == name) {
+                        unknown.push(name.to_string());
+                    }
+                    rest = &after[end + 1..];
+                }
+                None => break,
+            }
+        }
+
+        unknown
+    }
+}
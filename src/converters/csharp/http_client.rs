@@ -0,0 +1,322 @@
+use crate::converters::csharp::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use std::collections::BTreeSet;
+
+pub struct HttpClient;
+
+impl Convert for HttpClient {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut usings = BTreeSet::new();
+        usings.insert("System");
+        usings.insert("System.Net");
+        usings.insert("System.Net.Http");
+
+        let mut body = String::new();
+
+        body.push_str("var handler = new HttpClientHandler\n{\n");
+        let follows_redirects = curl_cmd.location && curl_cmd.max_redirs != Some(0);
+        body.push_str(&format!("    AllowAutoRedirect = {},\n", follows_redirects));
+
+        if follows_redirects {
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                body.push_str(&format!("    MaxAutomaticRedirections = {},\n", max_redirs));
+            }
+        }
+
+        if curl_cmd.compressed {
+            body.push_str("    AutomaticDecompression = DecompressionMethods.GZip | DecompressionMethods.Deflate | DecompressionMethods.Brotli,\n");
+        }
+
+        if curl_cmd.insecure {
+            body.push_str("    ServerCertificateCustomValidationCallback = HttpClientHandler.DangerousAcceptAnyServerCertificateValidator,\n");
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            body.push_str(&format!(
+                "    Proxy = new WebProxy({})",
+                string_literal(proxy)
+            ));
+            if let Some((username, password)) = &curl_cmd.proxy_auth {
+                body.push_str(&format!(
+                    "\n    {{\n        Credentials = new NetworkCredential({}, {}),\n    }}",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+            body.push_str(",\n");
+            body.push_str("    UseProxy = true,\n");
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            ) {
+                body.push_str(&format!(
+                    "    Credentials = new NetworkCredential({}, {}),\n",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+
+        if !curl_cmd.cookies.is_empty() {
+            body.push_str("    UseCookies = false,\n");
+        }
+
+        body.push_str("};\n");
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            usings.insert("System.Security.Cryptography.X509Certificates");
+            let is_p12 = curl_cmd
+                .ssl_options
+                .cert_type
+                .as_deref()
+                .is_some_and(|cert_type| cert_type.eq_ignore_ascii_case("p12"));
+
+            if is_p12 {
+                body.push_str(&format!(
+                    "handler.ClientCertificates.Add(new X509Certificate2({}));\n",
+                    string_literal(cert)
+                ));
+            } else if let Some(key) = &curl_cmd.ssl_options.key {
+                body.push_str(&format!(
+                    "handler.ClientCertificates.Add(X509Certificate2.CreateFromPemFile({}, {}));\n",
+                    string_literal(cert),
+                    string_literal(key)
+                ));
+            } else {
+                body.push_str(&format!(
+                    "handler.ClientCertificates.Add(X509Certificate2.CreateFromPemFile({}));\n",
+                    string_literal(cert)
+                ));
+            }
+        }
+
+        body.push('\n');
+        body.push_str("using var client = new HttpClient(handler);\n");
+
+        if let Some(timeout) = curl_cmd.max_time {
+            body.push_str(&format!(
+                "client.Timeout = TimeSpan.FromSeconds({});\n",
+                timeout
+            ));
+        }
+
+        body.push('\n');
+        body.push_str(&format!(
+            "using var request = new HttpRequestMessage({}, {});\n",
+            self.http_method(curl_cmd),
+            string_literal(&curl_cmd.url)
+        ));
+
+        let headers = utils::request_headers(curl_cmd);
+        let content_type = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone());
+
+        for (key, value) in headers
+            .iter()
+            .filter(|(key, _)| !key.eq_ignore_ascii_case("content-type"))
+        {
+            body.push_str(&format!(
+                "request.Headers.TryAddWithoutValidation({}, {});\n",
+                string_literal(key),
+                string_literal(value)
+            ));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                usings.insert("System.Net.Http.Headers");
+                usings.insert("System.Text");
+                body.push_str(&format!(
+                    "request.Headers.Authorization = new AuthenticationHeaderValue(\"Basic\", Convert.ToBase64String(Encoding.UTF8.GetBytes({})));\n",
+                    string_literal(&format!("{}:{}", username, password))
+                ));
+            }
+        }
+
+        self.content(&mut body, &mut usings, curl_cmd, content_type);
+
+        body.push('\n');
+        body.push_str("using var response = await client.SendAsync(request);\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            usings.insert("System.IO");
+            body.push_str(&format!(
+                "await File.WriteAllBytesAsync({}, await response.Content.ReadAsByteArrayAsync());\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                body.push_str("Console.WriteLine((int)response.StatusCode);\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    body.push_str(
+                        "Console.WriteLine(await response.Content.ReadAsStringAsync());\n",
+                    );
+                }
+            }
+        }
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("//", &self.warnings(curl_cmd)));
+        for using in &usings {
+            output.push_str(&format!("using {};\n", using));
+        }
+        output.push('\n');
+        output.push_str(&body);
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.connect_timeout.is_some() {
+            warnings.push(
+                "HttpClientHandler has no connect timeout, use SocketsHttpHandler.ConnectTimeout"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push(
+                "custom CA certificates (--cacert) require a ServerCertificateCustomValidationCallback"
+                    .to_string(),
+            );
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "HttpClient has no retry policy, wrap SendAsync to retry {} times",
+                retry
+            ));
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("HttpClient does not expose ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl HttpClient {
+    fn http_method(&self, curl_cmd: &CurlCommand) -> String {
+        match curl_cmd.method.as_str() {
+            "GET" => "HttpMethod.Get".to_string(),
+            "POST" => "HttpMethod.Post".to_string(),
+            "PUT" => "HttpMethod.Put".to_string(),
+            "DELETE" => "HttpMethod.Delete".to_string(),
+            "PATCH" => "HttpMethod.Patch".to_string(),
+            "HEAD" => "HttpMethod.Head".to_string(),
+            "OPTIONS" => "HttpMethod.Options".to_string(),
+            "TRACE" => "HttpMethod.Trace".to_string(),
+            method => format!("new HttpMethod({})", string_literal(method)),
+        }
+    }
+
+    fn content(
+        &self,
+        output: &mut String,
+        usings: &mut BTreeSet<&'static str>,
+        curl_cmd: &CurlCommand,
+        content_type: Option<String>,
+    ) {
+        if let Some(data) = &curl_cmd.data {
+            usings.insert("System.Net.Http.Headers");
+            let content_type =
+                content_type.unwrap_or_else(|| "application/x-www-form-urlencoded".to_string());
+            output.push_str(&format!(
+                "request.Content = new StringContent({});\n",
+                string_literal(data)
+            ));
+            output.push_str(&format!(
+                "request.Content.Headers.ContentType = MediaTypeHeaderValue.Parse({});\n",
+                string_literal(&content_type)
+            ));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            usings.insert("System.IO");
+            usings.insert("System.Net.Http.Headers");
+            let content_type =
+                content_type.unwrap_or_else(|| "application/octet-stream".to_string());
+            output.push_str(&format!(
+                "request.Content = new ByteArrayContent(File.ReadAllBytes({}));\n",
+                string_literal(binary_file)
+            ));
+            output.push_str(&format!(
+                "request.Content.Headers.ContentType = MediaTypeHeaderValue.Parse({});\n",
+                string_literal(&content_type)
+            ));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            output.push('\n');
+            output.push_str("var content = new MultipartFormDataContent();\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                output.push_str(&format!(
+                    "content.Add(new StringContent({}), {});\n",
+                    string_literal(value),
+                    string_literal(key)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (index, (key, file_upload)) in files.into_iter().enumerate() {
+                usings.insert("System.IO");
+                let variable = format!("fileContent{}", index + 1);
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                output.push_str(&format!(
+                    "var {} = new ByteArrayContent(File.ReadAllBytes({}));\n",
+                    variable,
+                    string_literal(&file_upload.path)
+                ));
+                if let Some(file_content_type) = &file_upload.content_type {
+                    usings.insert("System.Net.Http.Headers");
+                    output.push_str(&format!(
+                        "{}.Headers.ContentType = MediaTypeHeaderValue.Parse({});\n",
+                        variable,
+                        string_literal(file_content_type)
+                    ));
+                }
+                output.push_str(&format!(
+                    "content.Add({}, {}, {});\n",
+                    variable,
+                    string_literal(key),
+                    string_literal(filename)
+                ));
+            }
+
+            output.push_str("request.Content = content;\n");
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            usings.insert("System.Collections.Generic");
+            output.push_str(
+                "request.Content = new FormUrlEncodedContent(new Dictionary<string, string>\n{\n",
+            );
+
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            for (key, value) in params {
+                output.push_str(&format!(
+                    "    [{}] = {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            output.push_str("});\n");
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod http_client;
+
+pub use http_client::HttpClient;
+
+use crate::converters::utils;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("\"{}\"", utils::escape_string_literal(s))
+}
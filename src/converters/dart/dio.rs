@@ -0,0 +1,233 @@
+use crate::converters::dart::{
+    basic_auth_value, io_client_warnings, io_http_client, needs_io_client, string_literal,
+};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use std::collections::BTreeSet;
+
+pub struct Dio;
+
+impl Convert for Dio {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut imports = BTreeSet::new();
+        imports.insert("import 'package:dio/dio.dart';");
+
+        let mut body = String::new();
+
+        let mut base_options = Vec::new();
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            base_options.push(format!(
+                "connectTimeout: const Duration(seconds: {})",
+                timeout
+            ));
+        }
+        if let Some(timeout) = curl_cmd.max_time {
+            base_options.push(format!(
+                "receiveTimeout: const Duration(seconds: {})",
+                timeout
+            ));
+        }
+
+        if base_options.is_empty() {
+            body.push_str("  final dio = Dio();\n");
+        } else {
+            body.push_str("  final dio = Dio(BaseOptions(\n");
+            for option in &base_options {
+                body.push_str(&format!("    {},\n", option));
+            }
+            body.push_str("  ));\n");
+        }
+
+        if needs_io_client(curl_cmd) {
+            imports.insert("import 'dart:io';");
+            imports.insert("import 'package:dio/io.dart';");
+            body.push_str("  dio.httpClientAdapter = IOHttpClientAdapter(\n");
+            body.push_str("    createHttpClient: () {\n");
+            body.push_str(&io_http_client(curl_cmd, "      "));
+            body.push_str("      return httpClient;\n");
+            body.push_str("    },\n");
+            body.push_str("  );\n");
+        }
+        body.push('\n');
+
+        let mut headers: Vec<(String, String)> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .map(|(key, value)| (string_literal(&key), string_literal(&value)))
+            .collect();
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                imports.insert("import 'dart:convert';");
+                headers.push((
+                    string_literal("Authorization"),
+                    basic_auth_value(username, password),
+                ));
+            }
+        }
+
+        let has_content_type = utils::request_headers(curl_cmd)
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+        let mut content_type = None;
+
+        let data = if let Some(data) = &curl_cmd.data {
+            if !has_content_type {
+                content_type = Some("Headers.formUrlEncodedContentType");
+            }
+            Some(string_literal(data))
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            imports.insert("import 'dart:io';");
+            body.push_str(&format!(
+                "  final bytes = await File({}).readAsBytes();\n\n",
+                string_literal(binary_file)
+            ));
+            headers.push((
+                "Headers.contentLengthHeader".to_string(),
+                "bytes.length".to_string(),
+            ));
+            if !has_content_type {
+                content_type = Some("'application/octet-stream'");
+            }
+            Some("Stream.fromIterable([bytes])".to_string())
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            body.push_str("  final formData = FormData.fromMap({\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                body.push_str(&format!(
+                    "    {}: {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let mut arguments = vec![
+                    string_literal(&file_upload.path),
+                    format!("filename: {}", string_literal(filename)),
+                ];
+                if let Some(file_content_type) = &file_upload.content_type {
+                    arguments.push(format!(
+                        "contentType: DioMediaType.parse({})",
+                        string_literal(file_content_type)
+                    ));
+                }
+                body.push_str(&format!(
+                    "    {}: await MultipartFile.fromFile({}),\n",
+                    string_literal(key),
+                    arguments.join(", ")
+                ));
+            }
+
+            body.push_str("  });\n\n");
+            Some("formData".to_string())
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            content_type = Some("Headers.formUrlEncodedContentType");
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| format!("{}: {}", string_literal(key), string_literal(value)))
+                .collect();
+            Some(format!("{{{}}}", pairs.join(", ")))
+        } else {
+            None
+        };
+
+        body.push_str("  final response = await dio.request(\n");
+        body.push_str(&format!("    {},\n", string_literal(&curl_cmd.url)));
+        if let Some(data) = &data {
+            body.push_str(&format!("    data: {},\n", data));
+        }
+        body.push_str("    options: Options(\n");
+        body.push_str(&format!(
+            "      method: {},\n",
+            string_literal(&curl_cmd.method)
+        ));
+
+        if !headers.is_empty() {
+            body.push_str("      headers: {\n");
+            for (key, value) in &headers {
+                body.push_str(&format!("        {}: {},\n", key, value));
+            }
+            body.push_str("      },\n");
+        }
+
+        if let Some(content_type) = content_type {
+            body.push_str(&format!("      contentType: {},\n", content_type));
+        }
+
+        body.push_str(&format!("      followRedirects: {},\n", curl_cmd.location));
+        if curl_cmd.location {
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                body.push_str(&format!("      maxRedirects: {},\n", max_redirs));
+            }
+        }
+
+        if curl_cmd.output.is_some() {
+            body.push_str("      responseType: ResponseType.bytes,\n");
+        } else {
+            body.push_str("      responseType: ResponseType.plain,\n");
+        }
+        body.push_str("      validateStatus: (status) => true,\n");
+        body.push_str("    ),\n");
+        body.push_str("  );\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            imports.insert("import 'dart:io';");
+            body.push_str(&format!(
+                "  await File({}).writeAsBytes(response.data);\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                body.push_str("  print(response.statusCode);\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    body.push_str("  print(response.data);\n");
+                }
+            }
+        }
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("//", &self.warnings(curl_cmd)));
+        for import in &imports {
+            output.push_str(&format!("{}\n", import));
+        }
+        output.push('\n');
+        output.push_str("Future<void> main() async {\n");
+        output.push_str(&body);
+        output.push_str("}\n");
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = io_client_warnings(curl_cmd);
+
+        if curl_cmd.max_time.is_some() {
+            warnings.push(
+                "Dio has no total request timeout, --max-time mapped to receiveTimeout".to_string(),
+            );
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "Dio has no retry option, add the dio_smart_retry interceptor to retry {} times",
+                retry
+            ));
+        }
+
+        warnings
+    }
+}
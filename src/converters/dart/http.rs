@@ -0,0 +1,199 @@
+use crate::converters::dart::{
+    basic_auth_value, io_client_warnings, io_http_client, needs_io_client, string_literal,
+};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use std::collections::BTreeSet;
+
+pub struct Http;
+
+impl Convert for Http {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut imports = BTreeSet::new();
+        imports.insert("import 'package:http/http.dart' as http;");
+
+        let mut body = String::new();
+
+        if needs_io_client(curl_cmd) || curl_cmd.connect_timeout.is_some() {
+            imports.insert("import 'dart:io';");
+            imports.insert("import 'package:http/io_client.dart';");
+            body.push_str(&io_http_client(curl_cmd, "  "));
+            if let Some(timeout) = curl_cmd.connect_timeout {
+                body.push_str(&format!(
+                    "  httpClient.connectionTimeout = const Duration(seconds: {});\n",
+                    timeout
+                ));
+            }
+            body.push_str("  final client = IOClient(httpClient);\n\n");
+        } else {
+            body.push_str("  final client = http.Client();\n\n");
+        }
+
+        let has_multipart = !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty();
+        let has_body_override = curl_cmd.data.is_some() || curl_cmd.binary_file.is_some();
+        let request_class = if has_multipart && !has_body_override {
+            "http.MultipartRequest"
+        } else {
+            "http.Request"
+        };
+
+        body.push_str(&format!(
+            "  final request = {}({}, Uri.parse({}));\n",
+            request_class,
+            string_literal(&curl_cmd.method),
+            string_literal(&curl_cmd.url)
+        ));
+
+        if !curl_cmd.location {
+            body.push_str("  request.followRedirects = false;\n");
+        } else if let Some(max_redirs) = curl_cmd.max_redirs {
+            body.push_str(&format!("  request.maxRedirects = {};\n", max_redirs));
+        }
+
+        let mut headers = utils::request_headers(curl_cmd);
+        let has_content_type = headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+        if curl_cmd.data.is_some() && !has_content_type {
+            headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+
+        if !headers.is_empty() {
+            body.push_str("  request.headers.addAll({\n");
+            for (key, value) in &headers {
+                body.push_str(&format!(
+                    "    {}: {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+            body.push_str("  });\n");
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                imports.insert("import 'dart:convert';");
+                body.push_str(&format!(
+                    "  request.headers['Authorization'] = {};\n",
+                    basic_auth_value(username, password)
+                ));
+            }
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            body.push_str(&format!("  request.body = {};\n", string_literal(data)));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            imports.insert("import 'dart:io';");
+            body.push_str(&format!(
+                "  request.bodyBytes = await File({}).readAsBytes();\n",
+                string_literal(binary_file)
+            ));
+        } else if has_multipart {
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                body.push_str(&format!(
+                    "  request.fields[{}] = {};\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let mut arguments = vec![
+                    string_literal(key),
+                    string_literal(&file_upload.path),
+                    format!("filename: {}", string_literal(filename)),
+                ];
+                if let Some(content_type) = &file_upload.content_type {
+                    imports.insert("import 'package:http_parser/http_parser.dart';");
+                    arguments.push(format!(
+                        "contentType: MediaType.parse({})",
+                        string_literal(content_type)
+                    ));
+                }
+                body.push_str(&format!(
+                    "  request.files.add(await http.MultipartFile.fromPath({}));\n",
+                    arguments.join(", ")
+                ));
+            }
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            body.push_str("  request.bodyFields = {\n");
+            for (key, value) in params {
+                body.push_str(&format!(
+                    "    {}: {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+            body.push_str("  };\n");
+        }
+
+        body.push('\n');
+        match curl_cmd.max_time {
+            Some(timeout) => body.push_str(&format!(
+                "  final streamedResponse = await client.send(request).timeout(const Duration(seconds: {}));\n",
+                timeout
+            )),
+            None => body.push_str("  final streamedResponse = await client.send(request);\n"),
+        }
+        body.push_str("  final response = await http.Response.fromStream(streamedResponse);\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            imports.insert("import 'dart:io';");
+            body.push_str(&format!(
+                "  await File({}).writeAsBytes(response.bodyBytes);\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                body.push_str("  print(response.statusCode);\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    body.push_str("  print(response.body);\n");
+                }
+            }
+        }
+
+        body.push_str("  client.close();\n");
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("//", &self.warnings(curl_cmd)));
+        for import in &imports {
+            output.push_str(&format!("{}\n", import));
+        }
+        output.push('\n');
+        output.push_str("Future<void> main() async {\n");
+        output.push_str(&body);
+        output.push_str("}\n");
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = io_client_warnings(curl_cmd);
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "package:http has no retry option, wrap the client in RetryClient from package:http/retry.dart to retry {} times",
+                retry
+            ));
+        }
+
+        warnings
+    }
+}
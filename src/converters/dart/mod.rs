@@ -0,0 +1,144 @@
+pub mod dio;
+pub mod http;
+
+pub use dio::Dio;
+pub use http::Http;
+
+use crate::converters::utils;
+use crate::{AuthType, CurlCommand};
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!(
+        "'{}'",
+        s.replace('\\', "\\\\")
+            .replace('\'', "\\'")
+            .replace('$', "\\$")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r")
+            .replace('\t', "\\t")
+    )
+}
+
+pub(crate) fn basic_auth_value(username: &str, password: &str) -> String {
+    format!(
+        "'Basic ${{base64Encode(utf8.encode({}))}}'",
+        string_literal(&format!("{}:{}", username, password))
+    )
+}
+
+pub(crate) fn needs_io_client(curl_cmd: &CurlCommand) -> bool {
+    curl_cmd.insecure
+        || curl_cmd.proxy.is_some()
+        || curl_cmd.ssl_options.cacert.is_some()
+        || curl_cmd.ssl_options.cert.is_some()
+        || curl_cmd.ssl_options.key.is_some()
+        || (curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Digest)))
+}
+
+pub(crate) fn io_http_client(curl_cmd: &CurlCommand, indent: &str) -> String {
+    let mut output = String::new();
+    let ssl = &curl_cmd.ssl_options;
+
+    if ssl.cacert.is_some() || ssl.cert.is_some() || ssl.key.is_some() {
+        output.push_str(&format!(
+            "{}final context = SecurityContext(withTrustedRoots: true);\n",
+            indent
+        ));
+        if let Some(cacert) = &ssl.cacert {
+            output.push_str(&format!(
+                "{}context.setTrustedCertificates({});\n",
+                indent,
+                string_literal(cacert)
+            ));
+        }
+        if let Some(cert) = &ssl.cert {
+            output.push_str(&format!(
+                "{}context.useCertificateChain({});\n",
+                indent,
+                string_literal(cert)
+            ));
+        }
+        if let Some(key) = &ssl.key {
+            output.push_str(&format!(
+                "{}context.usePrivateKey({});\n",
+                indent,
+                string_literal(key)
+            ));
+        }
+        output.push_str(&format!(
+            "{}final httpClient = HttpClient(context: context);\n",
+            indent
+        ));
+    } else {
+        output.push_str(&format!("{}final httpClient = HttpClient();\n", indent));
+    }
+
+    if curl_cmd.insecure {
+        output.push_str(&format!(
+            "{}httpClient.badCertificateCallback = (cert, host, port) => true;\n",
+            indent
+        ));
+    }
+
+    if let Some(proxy) = &curl_cmd.proxy {
+        let (_, host, port) = utils::split_proxy(proxy);
+        output.push_str(&format!(
+            "{}httpClient.findProxy = (uri) => {};\n",
+            indent,
+            string_literal(&format!("PROXY {}:{}", host, port))
+        ));
+        if let Some((username, password)) = &curl_cmd.proxy_auth {
+            output.push_str(&format!(
+                "{}httpClient.addProxyCredentials({}, {}, '', HttpClientBasicCredentials({}, {}));\n",
+                indent,
+                string_literal(&host),
+                port,
+                string_literal(username),
+                string_literal(password)
+            ));
+        }
+    }
+
+    if let Some((username, password)) = &curl_cmd.auth {
+        if matches!(curl_cmd.auth_type, Some(AuthType::Digest)) {
+            output.push_str(&format!(
+                "{}httpClient.addCredentials(Uri.parse({}), '', HttpClientDigestCredentials({}, {}));\n",
+                indent,
+                string_literal(&curl_cmd.url),
+                string_literal(username),
+                string_literal(password)
+            ));
+        }
+    }
+
+    output
+}
+
+pub(crate) fn io_client_warnings(curl_cmd: &CurlCommand) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if curl_cmd.auth.is_some()
+        && matches!(
+            curl_cmd.auth_type,
+            Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+        )
+    {
+        warnings.push("NTLM and Negotiate authentication are not supported by dart:io".to_string());
+    }
+
+    if let Some(proxy) = &curl_cmd.proxy {
+        let (scheme, _, _) = utils::split_proxy(proxy);
+        if scheme != "http" && scheme != "https" {
+            warnings.push(format!(
+                "dart:io HttpClient only supports HTTP proxies, {} proxy used as HTTP",
+                scheme
+            ));
+        }
+    }
+
+    if curl_cmd.no_alpn {
+        warnings.push("dart:io HttpClient does not expose ALPN, --no-alpn ignored".to_string());
+    }
+
+    warnings
+}
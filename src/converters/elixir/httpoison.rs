@@ -0,0 +1,200 @@
+use crate::converters::elixir::{atom_literal, charlist_literal, string_literal};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct HTTPoison;
+
+impl Convert for HTTPoison {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        output.push_str(&format!("url = {}\n", string_literal(&curl_cmd.url)));
+
+        let mut request_headers = utils::request_headers(curl_cmd);
+        let has_content_type = request_headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+        if curl_cmd.data.is_some() && !has_content_type {
+            request_headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+
+        let headers: Vec<String> = request_headers
+            .iter()
+            .map(|(key, value)| format!("{{{}, {}}}", string_literal(key), string_literal(value)))
+            .collect();
+        output.push_str(&format!("headers = [{}]\n", headers.join(", ")));
+
+        let body = if let Some(data) = &curl_cmd.data {
+            string_literal(data)
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            format!("{{:file, {}}}", string_literal(binary_file))
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut parts = Vec::new();
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                parts.push(format!(
+                    "{{{}, {}}}",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let part_headers = match &file_upload.content_type {
+                    Some(content_type) => {
+                        format!("[{{\"Content-Type\", {}}}]", string_literal(content_type))
+                    }
+                    None => "[]".to_string(),
+                };
+                parts.push(format!(
+                    "{{:file, {}, {{\"form-data\", [{{\"name\", {}}}, {{\"filename\", {}}}]}}, {}}}",
+                    string_literal(&file_upload.path),
+                    string_literal(key),
+                    string_literal(filename),
+                    part_headers
+                ));
+            }
+
+            format!("{{:multipart, [{}]}}", parts.join(", "))
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| {
+                    format!("{{{}, {}}}", string_literal(key), string_literal(value))
+                })
+                .collect();
+            format!("{{:form, [{}]}}", pairs.join(", "))
+        } else {
+            "\"\"".to_string()
+        };
+        output.push_str(&format!("body = {}\n", body));
+
+        let options = self.options(curl_cmd);
+        output.push_str(&format!("options = [{}]\n\n", options.join(", ")));
+
+        output.push_str(&format!(
+            "{{:ok, response}} = HTTPoison.request({}, url, body, headers, options)\n\n",
+            atom_literal(&curl_cmd.method.to_lowercase())
+        ));
+
+        if let Some(output_file) = &curl_cmd.output {
+            output.push_str(&format!(
+                "File.write!({}, response.body)\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("IO.puts(response.status_code)\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("IO.puts(response.body)\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push("hackney only supports Basic authentication".to_string());
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "HTTPoison has no retry option, wrap the request to retry {} times",
+                retry
+            ));
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("HTTPoison does not expose ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl HTTPoison {
+    fn options(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut options = Vec::new();
+
+        let mut ssl_options = Vec::new();
+        if curl_cmd.insecure {
+            ssl_options.push("verify: :verify_none".to_string());
+        } else if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            ssl_options.push(format!("cacertfile: {}", charlist_literal(cacert)));
+        }
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            ssl_options.push(format!("certfile: {}", charlist_literal(cert)));
+        }
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            ssl_options.push(format!("keyfile: {}", charlist_literal(key)));
+        }
+        if !ssl_options.is_empty() {
+            options.push(format!("ssl: [{}]", ssl_options.join(", ")));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                options.push(format!(
+                    "hackney: [basic_auth: {{{}, {}}}]",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            options.push(format!("proxy: {}", string_literal(proxy)));
+            if let Some((username, password)) = &curl_cmd.proxy_auth {
+                options.push(format!(
+                    "proxy_auth: {{{}, {}}}",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            options.push(format!("timeout: {}", timeout * 1000));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            options.push(format!("recv_timeout: {}", timeout * 1000));
+        }
+
+        if curl_cmd.location {
+            options.push("follow_redirect: true".to_string());
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                options.push(format!("max_redirect: {}", max_redirs));
+            }
+        }
+
+        options
+    }
+}
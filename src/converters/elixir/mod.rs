@@ -0,0 +1,62 @@
+pub mod httpoison;
+pub mod req;
+
+pub use httpoison::HTTPoison;
+pub use req::Req;
+
+use crate::converters::utils;
+use serde_json::Value;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        utils::escape_string_literal(s).replace("#{", "\\#{")
+    )
+}
+
+pub(crate) fn charlist_literal(s: &str) -> String {
+    format!("~c{}", string_literal(s))
+}
+
+pub(crate) fn atom_literal(s: &str) -> String {
+    if is_identifier(s) {
+        format!(":{}", s)
+    } else {
+        format!(":{}", string_literal(s))
+    }
+}
+
+pub(crate) fn keyword_key(s: &str) -> String {
+    if is_identifier(s) {
+        format!("{}:", s)
+    } else {
+        format!("{}:", string_literal(s))
+    }
+}
+
+pub(crate) fn value_literal(value: &Value) -> String {
+    match value {
+        Value::Null => "nil".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => string_literal(s),
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(value_literal).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Object(map) => {
+            let pairs: Vec<String> = map
+                .iter()
+                .map(|(key, item)| format!("{} => {}", string_literal(key), value_literal(item)))
+                .collect();
+            format!("%{{{}}}", pairs.join(", "))
+        }
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let name = s.strip_suffix(['?', '!']).unwrap_or(s);
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -0,0 +1,233 @@
+use crate::converters::elixir::{
+    atom_literal, charlist_literal, keyword_key, string_literal, value_literal,
+};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Req;
+
+impl Convert for Req {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut options = Vec::new();
+
+        options.push(format!(
+            "method: {}",
+            atom_literal(&curl_cmd.method.to_lowercase())
+        ));
+        options.push(format!("url: {}", string_literal(&curl_cmd.url)));
+
+        let json = curl_cmd.data.as_deref().and_then(|data| {
+            serde_json::from_str::<serde_json::Value>(data)
+                .ok()
+                .filter(|json| curl_cmd.data_is_json || json.is_object() || json.is_array())
+        });
+
+        let mut request_headers = utils::request_headers(curl_cmd);
+        let has_content_type = request_headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+        if curl_cmd.data.is_some() && json.is_none() && !has_content_type {
+            request_headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+
+        let headers: Vec<String> = request_headers
+            .iter()
+            .map(|(key, value)| {
+                format!(
+                    "{{{}, {}}}",
+                    string_literal(&key.to_lowercase()),
+                    string_literal(value)
+                )
+            })
+            .collect();
+        if !headers.is_empty() {
+            options.push(format!("headers: [{}]", headers.join(", ")));
+        }
+
+        if let Some(json) = &json {
+            options.push(format!("json: {}", value_literal(json)));
+        } else if let Some(data) = &curl_cmd.data {
+            options.push(format!("body: {}", string_literal(data)));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            options.push(format!("body: File.read!({})", string_literal(binary_file)));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut parts = Vec::new();
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                parts.push(format!("{} {}", keyword_key(key), string_literal(value)));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let mut part = format!(
+                    "{{File.stream!({}), filename: {}",
+                    string_literal(&file_upload.path),
+                    string_literal(filename)
+                );
+                if let Some(content_type) = &file_upload.content_type {
+                    part.push_str(&format!(", content_type: {}", string_literal(content_type)));
+                }
+                part.push('}');
+                parts.push(format!("{} {}", keyword_key(key), part));
+            }
+
+            options.push(format!("form_multipart: [{}]", parts.join(", ")));
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| format!("{} {}", keyword_key(key), string_literal(value)))
+                .collect();
+            options.push(format!("form: [{}]", pairs.join(", ")));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                options.push(format!(
+                    "auth: {{:basic, {}}}",
+                    string_literal(&format!("{}:{}", username, password))
+                ));
+            }
+        }
+
+        let connect_options = self.connect_options(curl_cmd);
+        if !connect_options.is_empty() {
+            options.push(format!("connect_options: [{}]", connect_options.join(", ")));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            options.push(format!("receive_timeout: {}", timeout * 1000));
+        }
+
+        options.push(format!("redirect: {}", curl_cmd.location));
+        if curl_cmd.location {
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                options.push(format!("max_redirects: {}", max_redirs));
+            }
+        }
+
+        match curl_cmd.retry {
+            Some(retry) => {
+                options.push("retry: :transient".to_string());
+                options.push(format!("max_retries: {}", retry));
+            }
+            None => options.push("retry: false".to_string()),
+        }
+
+        if let Some(output_file) = &curl_cmd.output {
+            options.push(format!(
+                "into: File.stream!({})",
+                string_literal(output_file)
+            ));
+        }
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        output.push_str("{:ok, response} =\n");
+        output.push_str("  Req.request(\n");
+        output.push_str(&format!("    {}\n", options.join(",\n    ")));
+        output.push_str("  )\n\n");
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("IO.puts(response.status)\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("IO.puts(response.body)\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push("Req only supports Basic and Bearer authentication".to_string());
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, _, _) = utils::split_proxy(proxy);
+            if scheme != "http" && scheme != "https" {
+                warnings.push(format!("Mint does not support {} proxies", scheme));
+            }
+        }
+
+        if curl_cmd.max_time.is_some() {
+            warnings.push(
+                "Req has no total request timeout, --max-time mapped to receive_timeout"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("Req does not expose ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl Req {
+    fn connect_options(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut connect_options = Vec::new();
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            connect_options.push(format!("timeout: {}", timeout * 1000));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, host, port) = utils::split_proxy(proxy);
+            let scheme = if scheme == "https" { "https" } else { "http" };
+            connect_options.push(format!(
+                "proxy: {{:{}, {}, {}, []}}",
+                scheme,
+                string_literal(&host),
+                port
+            ));
+            if let Some((username, password)) = &curl_cmd.proxy_auth {
+                connect_options.push(format!(
+                    "proxy_headers: [{{\"proxy-authorization\", \"Basic \" <> Base.encode64({})}}]",
+                    string_literal(&format!("{}:{}", username, password))
+                ));
+            }
+        }
+
+        let mut transport_opts = Vec::new();
+        if curl_cmd.insecure {
+            transport_opts.push("verify: :verify_none".to_string());
+        } else if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            transport_opts.push(format!("cacertfile: {}", charlist_literal(cacert)));
+        }
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            transport_opts.push(format!("certfile: {}", charlist_literal(cert)));
+        }
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            transport_opts.push(format!("keyfile: {}", charlist_literal(key)));
+        }
+        if !transport_opts.is_empty() {
+            connect_options.push(format!("transport_opts: [{}]", transport_opts.join(", ")));
+        }
+
+        connect_options
+    }
+}
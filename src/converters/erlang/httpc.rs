@@ -0,0 +1,302 @@
+use crate::converters::erlang::{binary_literal, string_literal};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Httpc;
+
+const HTTPC_METHODS: [&str; 8] = [
+    "GET", "HEAD", "POST", "PUT", "DELETE", "PATCH", "OPTIONS", "TRACE",
+];
+
+impl Convert for Httpc {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut statements = Vec::new();
+
+        statements.push("{ok, _} = application:ensure_all_started(inets)".to_string());
+        statements.push("{ok, _} = application:ensure_all_started(ssl)".to_string());
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (_, host, port) = utils::split_proxy(proxy);
+            let proxy_tuple = format!("{{{{{}, {}}}, []}}", string_literal(&host), port);
+            statements.push(format!(
+                "ok = httpc:set_options([{{proxy, {}}}, {{https_proxy, {}}}])",
+                proxy_tuple, proxy_tuple
+            ));
+        }
+
+        statements.push(format!("URL = {}", string_literal(&curl_cmd.url)));
+
+        let mut headers = Vec::new();
+        let mut content_type = None;
+        for (key, value) in utils::request_headers(curl_cmd) {
+            if key.eq_ignore_ascii_case("content-type") {
+                content_type = Some(value);
+            } else {
+                headers.push(format!(
+                    "{{{}, {}}}",
+                    string_literal(&key.to_lowercase()),
+                    string_literal(&value)
+                ));
+            }
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                headers.push(format!(
+                    "{{\"authorization\", \"Basic \" ++ base64:encode_to_string({})}}",
+                    string_literal(&format!("{}:{}", username, password))
+                ));
+            }
+        }
+
+        statements.push(format!("Headers = [{}]", headers.join(", ")));
+
+        let has_body = if let Some(data) = &curl_cmd.data {
+            statements.push(format!("Body = {}", binary_literal(data)));
+            true
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            statements.push(format!(
+                "{{ok, Body}} = file:read_file({})",
+                string_literal(binary_file)
+            ));
+            content_type.get_or_insert_with(|| "application/octet-stream".to_string());
+            true
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            self.multipart_body(&mut statements, curl_cmd);
+            content_type = None;
+            true
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| {
+                    format!("{{{}, {}}}", string_literal(key), string_literal(value))
+                })
+                .collect();
+            statements.push(format!(
+                "Body = uri_string:compose_query([{}])",
+                pairs.join(", ")
+            ));
+            content_type = Some("application/x-www-form-urlencoded".to_string());
+            true
+        } else {
+            false
+        };
+
+        let needs_body_tuple =
+            has_body || matches!(curl_cmd.method.as_str(), "POST" | "PUT" | "PATCH");
+        let request = if needs_body_tuple {
+            let content_type = if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+                "\"multipart/form-data; boundary=\" ++ Boundary".to_string()
+            } else {
+                string_literal(
+                    content_type
+                        .as_deref()
+                        .unwrap_or("application/x-www-form-urlencoded"),
+                )
+            };
+            if !has_body {
+                statements.push("Body = <<>>".to_string());
+            }
+            format!("{{URL, Headers, {}, Body}}", content_type)
+        } else {
+            "{URL, Headers}".to_string()
+        };
+
+        statements.push(format!(
+            "HttpOptions = [{}]",
+            self.http_options(curl_cmd).join(", ")
+        ));
+
+        let options = match &curl_cmd.output {
+            Some(output_file) => format!("[{{stream, {}}}]", string_literal(output_file)),
+            None => "[{body_format, binary}]".to_string(),
+        };
+        statements.push(format!("Options = {}", options));
+
+        let method = curl_cmd.method.to_lowercase();
+        let method = if method.chars().all(|c| c.is_ascii_lowercase()) {
+            method
+        } else {
+            format!("'{}'", method.replace('\\', "\\\\").replace('\'', "\\'"))
+        };
+
+        if curl_cmd.output.is_some() {
+            statements.push(format!(
+                "{{ok, saved_to_file}} = httpc:request({}, {}, HttpOptions, Options)",
+                method, request
+            ));
+        } else {
+            let wants_status_code = curl_cmd
+                .write_out
+                .as_deref()
+                .is_some_and(|write_out| write_out.contains("%{http_code}"));
+            let (status, body) = if wants_status_code {
+                ("Status", "_ResponseBody")
+            } else {
+                ("_Status", "ResponseBody")
+            };
+            statements.push(format!(
+                "{{ok, {{{{_Version, {}, _Reason}}, _ResponseHeaders, {}}}}} =\n        httpc:request({}, {}, HttpOptions, Options)",
+                status, body, method, request
+            ));
+            if wants_status_code {
+                statements.push("io:format(\"~p~n\", [Status])".to_string());
+            } else {
+                statements.push("io:format(\"~s~n\", [ResponseBody])".to_string());
+            }
+        }
+
+        let mut output = String::new();
+        output.push_str("#!/usr/bin/env escript\n");
+        let warnings = self.warnings(curl_cmd);
+        output.push_str(&utils::warning_comments("%%", &warnings));
+        if warnings.is_empty() {
+            output.push('\n');
+        }
+        output.push_str("main(_) ->\n");
+        output.push_str(&format!("    {}.\n", statements.join(",\n    ")));
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if !HTTPC_METHODS.contains(&curl_cmd.method.as_str()) {
+            warnings.push(format!(
+                "httpc only supports standard HTTP methods, {} will be rejected",
+                curl_cmd.method
+            ));
+        }
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push("httpc only supports Basic authentication".to_string());
+        }
+
+        if curl_cmd.max_redirs.is_some() {
+            warnings.push("httpc has no redirect limit, --max-redirs ignored".to_string());
+        }
+
+        if curl_cmd.compressed {
+            warnings.push("httpc does not decompress responses, --compressed ignored".to_string());
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "httpc has no retry option, call httpc:request/4 in a loop to retry {} times",
+                retry
+            ));
+        }
+
+        if curl_cmd.write_out.is_some() && curl_cmd.output.is_some() {
+            warnings.push("--write-out is not available when streaming to a file".to_string());
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("httpc does not negotiate ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl Httpc {
+    fn http_options(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut http_options = Vec::new();
+
+        let mut ssl_options = Vec::new();
+        if curl_cmd.insecure {
+            ssl_options.push("{verify, verify_none}".to_string());
+        } else if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            ssl_options.push("{verify, verify_peer}".to_string());
+            ssl_options.push(format!("{{cacertfile, {}}}", string_literal(cacert)));
+        }
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            ssl_options.push(format!("{{certfile, {}}}", string_literal(cert)));
+        }
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            ssl_options.push(format!("{{keyfile, {}}}", string_literal(key)));
+        }
+        if !ssl_options.is_empty() {
+            http_options.push(format!("{{ssl, [{}]}}", ssl_options.join(", ")));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            http_options.push(format!("{{timeout, {}}}", timeout * 1000));
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            http_options.push(format!("{{connect_timeout, {}}}", timeout * 1000));
+        }
+
+        if let Some((username, password)) = &curl_cmd.proxy_auth {
+            http_options.push(format!(
+                "{{proxy_auth, {{{}, {}}}}}",
+                string_literal(username),
+                string_literal(password)
+            ));
+        }
+
+        http_options.push(format!("{{autoredirect, {}}}", curl_cmd.location));
+
+        http_options
+    }
+
+    fn multipart_body(&self, statements: &mut Vec<String>, curl_cmd: &CurlCommand) {
+        statements.push("Boundary = \"----CurlParserBoundary\"".to_string());
+
+        let mut parts = Vec::new();
+
+        let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+        fields.sort();
+        for (key, value) in fields {
+            parts.push(format!(
+                "\"--\", Boundary, \"\\r\\n\",\n        {},\n        {}, \"\\r\\n\"",
+                string_literal(&format!(
+                    "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                    key
+                )),
+                binary_literal(value)
+            ));
+        }
+
+        let mut files: Vec<_> = curl_cmd.files.iter().collect();
+        files.sort_by(|a, b| a.0.cmp(b.0));
+        for (index, (key, file_upload)) in files.into_iter().enumerate() {
+            let variable = format!("File{}", index + 1);
+            let filename = file_upload
+                .filename
+                .as_deref()
+                .unwrap_or_else(|| utils::file_name(&file_upload.path));
+            let content_type = file_upload
+                .content_type
+                .as_deref()
+                .unwrap_or("application/octet-stream");
+            statements.push(format!(
+                "{{ok, {}}} = file:read_file({})",
+                variable,
+                string_literal(&file_upload.path)
+            ));
+            parts.push(format!(
+                "\"--\", Boundary, \"\\r\\n\",\n        {},\n        {}, \"\\r\\n\"",
+                string_literal(&format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                    key, filename, content_type
+                )),
+                variable
+            ));
+        }
+
+        statements.push(format!(
+            "Body = iolist_to_binary([\n        {},\n        \"--\", Boundary, \"--\\r\\n\"\n    ])",
+            parts.join(",\n        ")
+        ));
+    }
+}
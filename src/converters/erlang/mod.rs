@@ -0,0 +1,13 @@
+pub mod httpc;
+
+pub use httpc::Httpc;
+
+use crate::converters::utils;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("\"{}\"", utils::escape_string_literal(s))
+}
+
+pub(crate) fn binary_literal(s: &str) -> String {
+    format!("<<{}/utf8>>", string_literal(s))
+}
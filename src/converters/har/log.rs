@@ -0,0 +1,225 @@
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::har::{
+    Cache, Cookie, Creator, Entry, Har, Log, NameValue, Param, PostData, Request, Response, Timings,
+};
+use crate::{AuthType, CurlCommand};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use url::Url;
+
+pub struct HarLog;
+
+impl Convert for HarLog {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if let Some(binary_file) = &curl_cmd.binary_file {
+            warnings.push(format!(
+                "HAR postData cannot reference files, the contents of {} are not included",
+                binary_file
+            ));
+        }
+
+        if !curl_cmd.files.is_empty() {
+            warnings.push(
+                "HAR postData params only carry file names, uploaded file contents are not included"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push(
+                "HAR requests only carry headers, digest/NTLM/negotiate credentials are not included"
+                    .to_string(),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for HarLog {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let har = Har {
+            log: Log {
+                version: "1.2".to_string(),
+                creator: Creator {
+                    name: env!("CARGO_PKG_NAME").to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                entries: curl_cmds
+                    .iter()
+                    .map(|curl_cmd| Entry {
+                        started_date_time: utils::timestamp(),
+                        time: 0.0,
+                        request: self.request(curl_cmd),
+                        response: Response::default(),
+                        cache: Cache::default(),
+                        timings: Timings::default(),
+                    })
+                    .collect(),
+            },
+        };
+
+        serde_json::to_string_pretty(&har).unwrap_or_default()
+    }
+}
+
+impl HarLog {
+    fn request(&self, curl_cmd: &CurlCommand) -> Request {
+        let mut headers: Vec<NameValue> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .map(|(name, value)| NameValue { name, value })
+            .collect();
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                headers.push(NameValue {
+                    name: "Authorization".to_string(),
+                    value: format!(
+                        "Basic {}",
+                        STANDARD.encode(format!("{}:{}", username, password))
+                    ),
+                });
+            }
+        }
+
+        let mut cookies: Vec<Cookie> = curl_cmd
+            .cookies
+            .iter()
+            .map(|(name, value)| Cookie {
+                name: name.clone(),
+                value: value.clone(),
+            })
+            .collect();
+        cookies.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let query_string = Url::parse(&curl_cmd.url)
+            .map(|url| {
+                url.query_pairs()
+                    .map(|(name, value)| NameValue {
+                        name: name.into_owned(),
+                        value: value.into_owned(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let post_data = self.post_data(curl_cmd);
+        let body_size = match &post_data {
+            Some(PostData {
+                text: Some(text), ..
+            }) => text.len() as i64,
+            Some(_) => -1,
+            None => 0,
+        };
+
+        Request {
+            method: curl_cmd.method.clone(),
+            url: curl_cmd.url.clone(),
+            http_version: "HTTP/1.1".to_string(),
+            cookies,
+            headers,
+            query_string,
+            post_data,
+            headers_size: -1,
+            body_size,
+        }
+    }
+
+    fn post_data(&self, curl_cmd: &CurlCommand) -> Option<PostData> {
+        let content_type = curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone());
+
+        if let Some(data) = &curl_cmd.data {
+            let mime_type = content_type.unwrap_or_else(|| {
+                if curl_cmd.data_is_json {
+                    "application/json".to_string()
+                } else {
+                    "application/x-www-form-urlencoded".to_string()
+                }
+            });
+            Some(PostData {
+                mime_type,
+                text: Some(data.clone()),
+                params: None,
+                comment: None,
+            })
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            Some(PostData {
+                mime_type: content_type.unwrap_or_else(|| "application/octet-stream".to_string()),
+                text: None,
+                params: None,
+                comment: Some(format!("Body read from {}", binary_file)),
+            })
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            let mut params: Vec<Param> = fields
+                .into_iter()
+                .map(|(name, value)| Param {
+                    name: name.clone(),
+                    value: Some(value.clone()),
+                    file_name: None,
+                    content_type: None,
+                })
+                .collect();
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (name, file_upload) in files {
+                let file_name = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                params.push(Param {
+                    name: name.clone(),
+                    value: None,
+                    file_name: Some(file_name.to_string()),
+                    content_type: file_upload.content_type.clone(),
+                });
+            }
+
+            Some(PostData {
+                mime_type: content_type.unwrap_or_else(|| "multipart/form-data".to_string()),
+                text: None,
+                params: Some(params),
+                comment: None,
+            })
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut pairs: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            pairs.sort();
+            Some(PostData {
+                mime_type: content_type
+                    .unwrap_or_else(|| "application/x-www-form-urlencoded".to_string()),
+                text: None,
+                params: Some(
+                    pairs
+                        .into_iter()
+                        .map(|(name, value)| Param {
+                            name: name.clone(),
+                            value: Some(value.clone()),
+                            file_name: None,
+                            content_type: None,
+                        })
+                        .collect(),
+                ),
+                comment: None,
+            })
+        } else {
+            None
+        }
+    }
+}
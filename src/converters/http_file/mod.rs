@@ -0,0 +1,3 @@
+pub mod rest_client;
+
+pub use rest_client::RestClient;
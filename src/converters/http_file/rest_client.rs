@@ -0,0 +1,183 @@
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::{AuthType, CurlCommand};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+const BOUNDARY: &str = "WebAppBoundary";
+
+pub struct RestClient;
+
+impl Convert for RestClient {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push(
+                ".http files only support basic and digest auth, credentials omitted".to_string(),
+            );
+        }
+
+        if curl_cmd.insecure {
+            warnings.push(
+                ".http files have no certificate validation setting, --insecure ignored"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.proxy.is_some() {
+            warnings.push(".http files have no proxy setting, --proxy ignored".to_string());
+        }
+
+        if curl_cmd.ssl_options.cert.is_some() || curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push(
+                ".http files have no client certificate setting, --cert/--cacert ignored"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.connect_timeout.is_some() || curl_cmd.max_time.is_some() {
+            warnings.push(".http files have no timeout setting, timeouts ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for RestClient {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let requests: Vec<String> = curl_cmds
+            .iter()
+            .map(|curl_cmd| self.request(curl_cmd))
+            .collect();
+        requests.join("\n")
+    }
+}
+
+impl RestClient {
+    fn request(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+        output.push_str(&format!("### {}\n", utils::request_name(curl_cmd)));
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        if !curl_cmd.location {
+            output.push_str("# @no-redirect\n");
+        }
+        output.push_str(&format!("{} {} HTTP/1.1\n", curl_cmd.method, curl_cmd.url));
+
+        let mut headers = utils::request_headers(curl_cmd);
+        let has_content_type = headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            match curl_cmd.auth_type {
+                None | Some(AuthType::Basic) => headers.push((
+                    "Authorization".to_string(),
+                    format!(
+                        "Basic {}",
+                        STANDARD.encode(format!("{}:{}", username, password))
+                    ),
+                )),
+                Some(AuthType::Digest) => headers.push((
+                    "Authorization".to_string(),
+                    format!("Digest {} {}", username, password),
+                )),
+                _ => {}
+            }
+        }
+
+        let body = if let Some(data) = &curl_cmd.data {
+            if !has_content_type {
+                headers.push((
+                    "Content-Type".to_string(),
+                    "application/x-www-form-urlencoded".to_string(),
+                ));
+            }
+            Some(data.clone())
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            Some(format!("< {}", self.file_reference(binary_file)))
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            headers.retain(|(key, _)| !key.eq_ignore_ascii_case("content-type"));
+            headers.push((
+                "Content-Type".to_string(),
+                format!("multipart/form-data; boundary={}", BOUNDARY),
+            ));
+            Some(self.multipart_body(curl_cmd))
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            if !has_content_type {
+                headers.push((
+                    "Content-Type".to_string(),
+                    "application/x-www-form-urlencoded".to_string(),
+                ));
+            }
+            Some(utils::url_encoded_body(&curl_cmd.url_encoded_params))
+        } else {
+            None
+        };
+
+        for (key, value) in &headers {
+            output.push_str(&format!("{}: {}\n", key, value));
+        }
+
+        if let Some(body) = body {
+            output.push('\n');
+            output.push_str(&body);
+            output.push('\n');
+        }
+
+        output
+    }
+
+    fn multipart_body(&self, curl_cmd: &CurlCommand) -> String {
+        let mut body = String::new();
+
+        let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+        fields.sort();
+        for (key, value) in fields {
+            body.push_str(&format!("--{}\n", BOUNDARY));
+            body.push_str(&format!(
+                "Content-Disposition: form-data; name=\"{}\"\n\n",
+                key
+            ));
+            body.push_str(&format!("{}\n", value));
+        }
+
+        let mut files: Vec<_> = curl_cmd.files.iter().collect();
+        files.sort_by(|a, b| a.0.cmp(b.0));
+        for (key, file_upload) in files {
+            let filename = file_upload
+                .filename
+                .as_deref()
+                .unwrap_or_else(|| utils::file_name(&file_upload.path));
+            body.push_str(&format!("--{}\n", BOUNDARY));
+            body.push_str(&format!(
+                "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\n",
+                key, filename
+            ));
+            if let Some(content_type) = &file_upload.content_type {
+                body.push_str(&format!("Content-Type: {}\n", content_type));
+            }
+            body.push('\n');
+            body.push_str(&format!("< {}\n", self.file_reference(&file_upload.path)));
+        }
+
+        body.push_str(&format!("--{}--", BOUNDARY));
+        body
+    }
+
+    fn file_reference(&self, path: &str) -> String {
+        if path.starts_with('/') || path.starts_with('.') || path.starts_with('~') {
+            path.to_string()
+        } else {
+            format!("./{}", path)
+        }
+    }
+}
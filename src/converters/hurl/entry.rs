@@ -0,0 +1,228 @@
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::{AuthType, CurlCommand};
+
+pub struct HurlEntry;
+
+impl Convert for HurlEntry {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push("Hurl only supports basic auth, credentials omitted".to_string());
+        }
+
+        if curl_cmd.max_time.is_some() {
+            warnings.push("Hurl has no per-request --max-time option, ignored".to_string());
+        }
+
+        if curl_cmd.output.is_some() {
+            warnings.push(
+                "Hurl entries do not write responses to a file, --output ignored".to_string(),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for HurlEntry {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let entries: Vec<String> = curl_cmds
+            .iter()
+            .map(|curl_cmd| self.entry(curl_cmd))
+            .collect();
+        entries.join("\n")
+    }
+}
+
+impl HurlEntry {
+    fn entry(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = utils::warning_comments("#", &self.warnings(curl_cmd));
+        output.push_str(&format!(
+            "{} {}\n",
+            curl_cmd.method,
+            escape_value(&curl_cmd.url)
+        ));
+
+        let mut headers = utils::request_headers(curl_cmd);
+        headers.retain(|(key, _)| !key.eq_ignore_ascii_case("cookie"));
+        let content_type = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.to_lowercase());
+        if curl_cmd.data.is_some() && content_type.is_none() {
+            headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+        for (key, value) in &headers {
+            output.push_str(&format!("{}: {}\n", key, escape_value(value)));
+        }
+
+        output.push_str(&self.options(curl_cmd));
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if matches!(curl_cmd.auth_type, None | Some(AuthType::Basic)) {
+                output.push_str("[BasicAuth]\n");
+                output.push_str(&format!(
+                    "{}: {}\n",
+                    escape_value(username),
+                    escape_value(password)
+                ));
+            }
+        }
+
+        if !curl_cmd.cookies.is_empty() {
+            output.push_str("[Cookies]\n");
+            let mut cookies: Vec<_> = curl_cmd.cookies.iter().collect();
+            cookies.sort();
+            for (name, value) in cookies {
+                output.push_str(&format!("{}: {}\n", name, escape_value(value)));
+            }
+        }
+
+        if curl_cmd.data.is_none()
+            && curl_cmd.binary_file.is_none()
+            && (!curl_cmd.form.is_empty() || !curl_cmd.files.is_empty())
+        {
+            output.push_str(&self.multipart_form_data(curl_cmd));
+        } else if curl_cmd.data.is_none()
+            && curl_cmd.binary_file.is_none()
+            && utils::has_url_encoded_body(curl_cmd)
+        {
+            output.push_str("[FormParams]\n");
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            for (key, value) in params {
+                output.push_str(&format!("{}: {}\n", key, escape_value(value)));
+            }
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            let is_json = (curl_cmd.data_is_json
+                || content_type.is_some_and(|value| value.contains("json")))
+                && serde_json::from_str::<serde_json::Value>(data)
+                    .is_ok_and(|value| value.is_object() || value.is_array());
+            if is_json {
+                output.push_str(&format!("{}\n", escape_templates(data, "\\u007b")));
+            } else if data.contains('\n') {
+                let data = escape_templates(&data.replace('\\', "\\\\"), "\\u{7b}");
+                output.push_str(&format!("```\n{}\n```\n", data));
+            } else {
+                let data = data.replace('\\', "\\\\").replace('`', "\\`");
+                output.push_str(&format!("`{}`\n", escape_templates(&data, "\\u{7b}")));
+            }
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            output.push_str(&format!("file,{};\n", binary_file));
+        }
+
+        output.push('\n');
+        output.push_str(&self.response(curl_cmd));
+        output
+    }
+
+    fn options(&self, curl_cmd: &CurlCommand) -> String {
+        let mut options = Vec::new();
+
+        if curl_cmd.insecure {
+            options.push("insecure: true".to_string());
+        }
+        if curl_cmd.location {
+            options.push("location: true".to_string());
+        }
+        if let Some(max_redirs) = curl_cmd.max_redirs {
+            options.push(format!("max-redirs: {}", max_redirs));
+        }
+        if curl_cmd.compressed {
+            options.push("compressed: true".to_string());
+        }
+        if let Some(connect_timeout) = curl_cmd.connect_timeout {
+            options.push(format!("connect-timeout: {}s", connect_timeout));
+        }
+        if let Some(retry) = curl_cmd.retry {
+            options.push(format!("retry: {}", retry));
+        }
+        if let Some(proxy) = &curl_cmd.proxy {
+            options.push(format!("proxy: {}", proxy));
+        }
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            options.push(format!("cacert: {}", cacert));
+        }
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            options.push(format!("cert: {}", cert));
+        }
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            options.push(format!("key: {}", key));
+        }
+
+        if options.is_empty() {
+            return String::new();
+        }
+        format!("[Options]\n{}\n", options.join("\n"))
+    }
+
+    fn multipart_form_data(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = "[MultipartFormData]\n".to_string();
+
+        let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+        fields.sort();
+        for (key, value) in fields {
+            output.push_str(&format!("{}: {}\n", key, escape_value(value)));
+        }
+
+        let mut files: Vec<_> = curl_cmd.files.iter().collect();
+        files.sort_by(|a, b| a.0.cmp(b.0));
+        for (key, file_upload) in files {
+            output.push_str(&format!("{}: file,{};", key, file_upload.path));
+            if let Some(content_type) = &file_upload.content_type {
+                output.push_str(&format!(" {}", content_type));
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    fn response(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = "HTTP *\n".to_string();
+
+        let captures_status = curl_cmd
+            .write_out
+            .as_deref()
+            .is_some_and(|write_out| write_out.contains("%{http_code}"));
+        if captures_status {
+            output.push_str("[Captures]\nhttp_code: status\n");
+            output.push_str("[Asserts]\nstatus < 400\n");
+        }
+
+        output
+    }
+}
+
+fn escape_value(value: &str) -> String {
+    let value = value.replace('\\', "\\\\").replace('#', "\\#");
+    escape_templates(&value, "\\u{7b}")
+}
+
+fn escape_templates(value: &str, escaped_brace: &str) -> String {
+    let mut output = String::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '{' && chars.peek() == Some(&'{') {
+            output.push_str(escaped_brace);
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
@@ -0,0 +1,3 @@
+pub mod entry;
+
+pub use entry::HurlEntry;
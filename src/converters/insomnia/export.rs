@@ -0,0 +1,204 @@
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::{AuthType, CurlCommand};
+use serde_json::{json, Value};
+
+pub struct InsomniaExport;
+
+impl Convert for InsomniaExport {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Negotiate)) {
+            warnings
+                .push("Insomnia has no negotiate authentication, credentials omitted".to_string());
+        }
+
+        if curl_cmd.insecure {
+            warnings.push(
+                "Insomnia certificate validation is an app preference, --insecure ignored"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.proxy.is_some() {
+            warnings.push("Insomnia proxies are configured in the app preferences".to_string());
+        }
+
+        if curl_cmd.ssl_options.cert.is_some() || curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push(
+                "Insomnia certificates are configured in the collection settings".to_string(),
+            );
+        }
+
+        if curl_cmd.connect_timeout.is_some() || curl_cmd.max_time.is_some() {
+            warnings.push("Insomnia request timeouts are an app preference".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for InsomniaExport {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let mut resources = vec![json!({
+            "_id": "wrk_1",
+            "_type": "workspace",
+            "parentId": null,
+            "name": env!("CARGO_PKG_NAME"),
+            "description": "",
+            "scope": "collection",
+        })];
+
+        let mut hosts: Vec<String> = Vec::new();
+        for (index, curl_cmd) in curl_cmds.iter().enumerate() {
+            let parent_id = if curl_cmds.len() > 1 {
+                let host = utils::host(curl_cmd);
+                let position = match hosts.iter().position(|known| *known == host) {
+                    Some(position) => position,
+                    None => {
+                        hosts.push(host.clone());
+                        resources.push(json!({
+                            "_id": format!("fld_{}", hosts.len()),
+                            "_type": "request_group",
+                            "parentId": "wrk_1",
+                            "name": host,
+                        }));
+                        hosts.len() - 1
+                    }
+                };
+                format!("fld_{}", position + 1)
+            } else {
+                "wrk_1".to_string()
+            };
+
+            resources.push(self.request(curl_cmd, &format!("req_{}", index + 1), &parent_id));
+        }
+
+        let export = json!({
+            "_type": "export",
+            "__export_format": 4,
+            "__export_date": utils::timestamp(),
+            "__export_source": concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+            "resources": resources,
+        });
+
+        serde_json::to_string_pretty(&export).unwrap_or_default()
+    }
+}
+
+impl InsomniaExport {
+    fn request(&self, curl_cmd: &CurlCommand, id: &str, parent_id: &str) -> Value {
+        let body = self.body(curl_cmd);
+
+        let mut headers: Vec<Value> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .filter(|(key, _)| {
+                curl_cmd.oauth2_bearer.is_none() || !key.eq_ignore_ascii_case("authorization")
+            })
+            .map(|(name, value)| json!({"name": name, "value": value}))
+            .collect();
+
+        let has_content_type = curl_cmd
+            .headers
+            .keys()
+            .any(|key| key.eq_ignore_ascii_case("content-type"));
+        if let Some(mime_type) = body.get("mimeType").and_then(Value::as_str) {
+            if !has_content_type && !mime_type.is_empty() {
+                headers.push(json!({"name": "Content-Type", "value": mime_type}));
+            }
+        }
+
+        json!({
+            "_id": id,
+            "_type": "request",
+            "parentId": parent_id,
+            "name": utils::request_name(curl_cmd),
+            "method": curl_cmd.method,
+            "url": curl_cmd.url,
+            "headers": headers,
+            "parameters": [],
+            "body": body,
+            "authentication": self.authentication(curl_cmd),
+            "settingFollowRedirects": if curl_cmd.location { "on" } else { "off" },
+        })
+    }
+
+    fn authentication(&self, curl_cmd: &CurlCommand) -> Value {
+        if let Some(token) = &curl_cmd.oauth2_bearer {
+            return json!({"type": "bearer", "token": token, "prefix": ""});
+        }
+
+        let Some((username, password)) = &curl_cmd.auth else {
+            return json!({});
+        };
+        let auth_type = match curl_cmd.auth_type {
+            None | Some(AuthType::Basic) => "basic",
+            Some(AuthType::Digest) => "digest",
+            Some(AuthType::Ntlm) => "ntlm",
+            Some(AuthType::Negotiate) => return json!({}),
+        };
+
+        json!({"type": auth_type, "username": username, "password": password})
+    }
+
+    fn body(&self, curl_cmd: &CurlCommand) -> Value {
+        let content_type = curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone());
+
+        if let Some(data) = &curl_cmd.data {
+            let mime_type = content_type.unwrap_or_else(|| {
+                if curl_cmd.data_is_json {
+                    "application/json".to_string()
+                } else {
+                    "application/x-www-form-urlencoded".to_string()
+                }
+            });
+            if mime_type.starts_with("application/x-www-form-urlencoded") && data.contains('=') {
+                let params: Vec<Value> = url::form_urlencoded::parse(data.as_bytes())
+                    .map(|(name, value)| json!({"name": name, "value": value}))
+                    .collect();
+                json!({"mimeType": mime_type, "params": params})
+            } else {
+                json!({"mimeType": mime_type, "text": data})
+            }
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            json!({
+                "mimeType": content_type.unwrap_or_else(|| "application/octet-stream".to_string()),
+                "fileName": binary_file,
+            })
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            let mut params: Vec<Value> = fields
+                .into_iter()
+                .map(|(name, value)| json!({"name": name, "value": value}))
+                .collect();
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (name, file_upload) in files {
+                params.push(json!({"name": name, "type": "file", "fileName": file_upload.path}));
+            }
+
+            json!({"mimeType": "multipart/form-data", "params": params})
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut pairs: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            pairs.sort();
+            let params: Vec<Value> = pairs
+                .into_iter()
+                .map(|(name, value)| json!({"name": name, "value": value}))
+                .collect();
+
+            json!({"mimeType": "application/x-www-form-urlencoded", "params": params})
+        } else {
+            json!({})
+        }
+    }
+}
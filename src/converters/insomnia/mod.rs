@@ -0,0 +1,3 @@
+pub mod export;
+
+pub use export::InsomniaExport;
@@ -0,0 +1,378 @@
+use crate::converters::java::{string_literal, trust_all_manager};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use std::collections::BTreeSet;
+
+pub struct HttpClient;
+
+const RESTRICTED_HEADERS: [&str; 5] = ["connection", "content-length", "expect", "host", "upgrade"];
+
+impl Convert for HttpClient {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut imports = BTreeSet::new();
+        imports.insert("java.net.URI");
+        imports.insert("java.net.http.HttpClient");
+        imports.insert("java.net.http.HttpRequest");
+        imports.insert("java.net.http.HttpResponse");
+
+        let mut body = String::new();
+        let indent = "        ";
+
+        if curl_cmd.insecure {
+            imports.insert("java.security.SecureRandom");
+            imports.insert("java.security.cert.X509Certificate");
+            imports.insert("javax.net.ssl.SSLContext");
+            imports.insert("javax.net.ssl.TrustManager");
+            imports.insert("javax.net.ssl.X509TrustManager");
+            trust_all_manager(&mut body, indent);
+        }
+
+        if curl_cmd.location {
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                body.push_str(&format!(
+                    "{}System.setProperty(\"jdk.httpclient.redirects.retrylimit\", \"{}\");\n\n",
+                    indent, max_redirs
+                ));
+            }
+        }
+
+        body.push_str(&format!(
+            "{}HttpClient client = HttpClient.newBuilder()\n",
+            indent
+        ));
+
+        if curl_cmd.no_alpn {
+            body.push_str(&format!(
+                "{}    .version(HttpClient.Version.HTTP_1_1)\n",
+                indent
+            ));
+        }
+
+        if curl_cmd.location {
+            body.push_str(&format!(
+                "{}    .followRedirects(HttpClient.Redirect.NORMAL)\n",
+                indent
+            ));
+        } else {
+            body.push_str(&format!(
+                "{}    .followRedirects(HttpClient.Redirect.NEVER)\n",
+                indent
+            ));
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            imports.insert("java.time.Duration");
+            body.push_str(&format!(
+                "{}    .connectTimeout(Duration.ofSeconds({}))\n",
+                indent, timeout
+            ));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (_, host, port) = utils::split_proxy(proxy);
+            imports.insert("java.net.InetSocketAddress");
+            imports.insert("java.net.ProxySelector");
+            body.push_str(&format!(
+                "{}    .proxy(ProxySelector.of(new InetSocketAddress({}, {})))\n",
+                indent,
+                string_literal(&host),
+                port
+            ));
+        }
+
+        if curl_cmd.insecure {
+            body.push_str(&format!("{}    .sslContext(sslContext)\n", indent));
+        }
+
+        if curl_cmd.auth.is_some() || curl_cmd.proxy_auth.is_some() {
+            imports.insert("java.net.Authenticator");
+            imports.insert("java.net.PasswordAuthentication");
+            self.authenticator(&mut body, curl_cmd, indent);
+        }
+
+        body.push_str(&format!("{}    .build();\n\n", indent));
+
+        let publisher = self.body_publisher(&mut body, &mut imports, curl_cmd, indent);
+
+        body.push_str(&format!(
+            "{}HttpRequest request = HttpRequest.newBuilder()\n",
+            indent
+        ));
+        body.push_str(&format!(
+            "{}    .uri(URI.create({}))\n",
+            indent,
+            string_literal(&curl_cmd.url)
+        ));
+
+        if let Some(timeout) = curl_cmd.max_time {
+            imports.insert("java.time.Duration");
+            body.push_str(&format!(
+                "{}    .timeout(Duration.ofSeconds({}))\n",
+                indent, timeout
+            ));
+        }
+
+        for (key, value) in utils::request_headers(curl_cmd) {
+            if RESTRICTED_HEADERS.contains(&key.to_lowercase().as_str()) {
+                body.push_str(&format!(
+                    "{}    // Header '{}' is restricted by HttpClient and set automatically\n",
+                    indent, key
+                ));
+                continue;
+            }
+            body.push_str(&format!(
+                "{}    .header({}, {})\n",
+                indent,
+                string_literal(&key),
+                string_literal(&value)
+            ));
+        }
+
+        if (!curl_cmd.form.is_empty() || !curl_cmd.files.is_empty()) && curl_cmd.data.is_none() {
+            body.push_str(&format!(
+                "{}    .header(\"Content-Type\", \"multipart/form-data; boundary=\" + boundary)\n",
+                indent
+            ));
+        } else if utils::has_url_encoded_body(curl_cmd)
+            && curl_cmd.data.is_none()
+            && !curl_cmd
+                .headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case("content-type"))
+        {
+            body.push_str(&format!(
+                "{}    .header(\"Content-Type\", \"application/x-www-form-urlencoded\")\n",
+                indent
+            ));
+        }
+
+        body.push_str(&format!(
+            "{}    .method({}, {})\n",
+            indent,
+            string_literal(&curl_cmd.method),
+            publisher
+        ));
+        body.push_str(&format!("{}    .build();\n\n", indent));
+
+        if curl_cmd.compressed {
+            body.push_str(&format!(
+                "{}// Note: HttpClient does not decompress responses; --compressed is ignored\n",
+                indent
+            ));
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            body.push_str(&format!(
+                "{}// Note: HttpClient has no retry policy; wrap client.send to retry {} times\n",
+                indent, retry
+            ));
+        }
+
+        if let Some(output_file) = &curl_cmd.output {
+            imports.insert("java.nio.file.Path");
+            body.push_str(&format!(
+                "{}HttpResponse<Path> response = client.send(request, HttpResponse.BodyHandlers.ofFile(Path.of({})));\n",
+                indent,
+                string_literal(output_file)
+            ));
+        } else {
+            body.push_str(&format!(
+                "{}HttpResponse<String> response = client.send(request, HttpResponse.BodyHandlers.ofString());\n",
+                indent
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                body.push_str(&format!(
+                    "{}System.out.println(response.statusCode());\n",
+                    indent
+                ));
+            }
+            _ => {
+                if curl_cmd.output.is_some() {
+                    body.push_str(&format!(
+                        "{}System.out.println(\"Response saved to \" + response.body());\n",
+                        indent
+                    ));
+                } else {
+                    body.push_str(&format!("{}System.out.println(response.body());\n", indent));
+                }
+            }
+        }
+
+        let mut output = String::new();
+        for import in &imports {
+            output.push_str(&format!("import {};\n", import));
+        }
+        output.push('\n');
+        output.push_str("public class Main {\n");
+        output.push_str("    public static void main(String[] args) throws Exception {\n");
+        output.push_str(&body);
+        output.push_str("    }\n");
+        output.push_str("}\n");
+
+        output
+    }
+}
+
+impl HttpClient {
+    fn authenticator(&self, output: &mut String, curl_cmd: &CurlCommand, indent: &str) {
+        if let Some(auth_type) = &curl_cmd.auth_type {
+            if !matches!(auth_type, AuthType::Basic) {
+                output.push_str(&format!(
+                    "{}    // Note: HttpClient's Authenticator only supports Basic authentication\n",
+                    indent
+                ));
+            }
+        }
+
+        output.push_str(&format!(
+            "{}    .authenticator(new Authenticator() {{\n",
+            indent
+        ));
+        output.push_str(&format!("{}        @Override\n", indent));
+        output.push_str(&format!(
+            "{}        protected PasswordAuthentication getPasswordAuthentication() {{\n",
+            indent
+        ));
+
+        let credentials = |username: &str, password: &str| {
+            format!(
+                "new PasswordAuthentication({}, {}.toCharArray())",
+                string_literal(username),
+                string_literal(password)
+            )
+        };
+
+        if let Some((username, password)) = &curl_cmd.proxy_auth {
+            output.push_str(&format!(
+                "{}            if (getRequestorType() == RequestorType.PROXY) {{\n",
+                indent
+            ));
+            output.push_str(&format!(
+                "{}                return {};\n",
+                indent,
+                credentials(username, password)
+            ));
+            output.push_str(&format!("{}            }}\n", indent));
+        }
+
+        match &curl_cmd.auth {
+            Some((username, password)) => {
+                output.push_str(&format!(
+                    "{}            return {};\n",
+                    indent,
+                    credentials(username, password)
+                ));
+            }
+            None => {
+                output.push_str(&format!("{}            return null;\n", indent));
+            }
+        }
+
+        output.push_str(&format!("{}        }}\n", indent));
+        output.push_str(&format!("{}    }})\n", indent));
+    }
+
+    fn body_publisher(
+        &self,
+        output: &mut String,
+        imports: &mut BTreeSet<&'static str>,
+        curl_cmd: &CurlCommand,
+        indent: &str,
+    ) -> String {
+        if let Some(data) = &curl_cmd.data {
+            return format!(
+                "HttpRequest.BodyPublishers.ofString({})",
+                string_literal(data)
+            );
+        }
+
+        if let Some(binary_file) = &curl_cmd.binary_file {
+            imports.insert("java.nio.file.Path");
+            return format!(
+                "HttpRequest.BodyPublishers.ofFile(Path.of({}))",
+                string_literal(binary_file)
+            );
+        }
+
+        if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            imports.insert("java.nio.charset.StandardCharsets");
+            imports.insert("java.nio.file.Files");
+            imports.insert("java.nio.file.Path");
+            imports.insert("java.util.ArrayList");
+            imports.insert("java.util.List");
+
+            output.push_str(&format!(
+                "{}String boundary = \"----CurlParserBoundary\";\n",
+                indent
+            ));
+            output.push_str(&format!(
+                "{}List<byte[]> parts = new ArrayList<>();\n",
+                indent
+            ));
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                let part = format!(
+                    "\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                    key, value
+                );
+                output.push_str(&format!(
+                    "{}parts.add((\"--\" + boundary + {}).getBytes(StandardCharsets.UTF_8));\n",
+                    indent,
+                    string_literal(&part)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let content_type = file_upload
+                    .content_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream");
+                let part = format!(
+                    "\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                    key, filename, content_type
+                );
+                output.push_str(&format!(
+                    "{}parts.add((\"--\" + boundary + {}).getBytes(StandardCharsets.UTF_8));\n",
+                    indent,
+                    string_literal(&part)
+                ));
+                output.push_str(&format!(
+                    "{}parts.add(Files.readAllBytes(Path.of({})));\n",
+                    indent,
+                    string_literal(&file_upload.path)
+                ));
+                output.push_str(&format!(
+                    "{}parts.add(\"\\r\\n\".getBytes(StandardCharsets.UTF_8));\n",
+                    indent
+                ));
+            }
+
+            output.push_str(&format!(
+                "{}parts.add((\"--\" + boundary + \"--\\r\\n\").getBytes(StandardCharsets.UTF_8));\n\n",
+                indent
+            ));
+
+            return "HttpRequest.BodyPublishers.ofByteArrays(parts)".to_string();
+        }
+
+        if utils::has_url_encoded_body(curl_cmd) {
+            return format!(
+                "HttpRequest.BodyPublishers.ofString({})",
+                string_literal(&utils::url_encoded_body(&curl_cmd.url_encoded_params))
+            );
+        }
+
+        "HttpRequest.BodyPublishers.noBody()".to_string()
+    }
+}
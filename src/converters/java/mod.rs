@@ -0,0 +1,47 @@
+pub mod http_client;
+pub mod okhttp;
+
+pub use http_client::HttpClient;
+pub use okhttp::OkHttp;
+
+use crate::converters::utils;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("\"{}\"", utils::escape_string_literal(s))
+}
+
+pub(crate) fn trust_all_manager(output: &mut String, indent: &str) {
+    output.push_str(&format!(
+        "{}X509TrustManager trustAllManager = new X509TrustManager() {{\n",
+        indent
+    ));
+    output.push_str(&format!("{}    @Override\n", indent));
+    output.push_str(&format!(
+        "{}    public void checkClientTrusted(X509Certificate[] chain, String authType) {{}}\n\n",
+        indent
+    ));
+    output.push_str(&format!("{}    @Override\n", indent));
+    output.push_str(&format!(
+        "{}    public void checkServerTrusted(X509Certificate[] chain, String authType) {{}}\n\n",
+        indent
+    ));
+    output.push_str(&format!("{}    @Override\n", indent));
+    output.push_str(&format!(
+        "{}    public X509Certificate[] getAcceptedIssuers() {{\n",
+        indent
+    ));
+    output.push_str(&format!(
+        "{}        return new X509Certificate[0];\n",
+        indent
+    ));
+    output.push_str(&format!("{}    }}\n", indent));
+    output.push_str(&format!("{}}};\n", indent));
+    output.push_str(&format!(
+        "{}SSLContext sslContext = SSLContext.getInstance(\"TLS\");\n",
+        indent
+    ));
+    output.push_str(&format!(
+        "{}sslContext.init(null, new TrustManager[] {{ trustAllManager }}, new SecureRandom());\n\n",
+        indent
+    ));
+}
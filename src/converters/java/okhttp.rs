@@ -0,0 +1,343 @@
+use crate::converters::java::{string_literal, trust_all_manager};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use std::collections::BTreeSet;
+
+pub struct OkHttp;
+
+impl Convert for OkHttp {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut imports = BTreeSet::new();
+        imports.insert("okhttp3.OkHttpClient");
+        imports.insert("okhttp3.Request");
+        imports.insert("okhttp3.Response");
+
+        let mut body = String::new();
+        let indent = "        ";
+
+        if curl_cmd.insecure {
+            imports.insert("java.security.SecureRandom");
+            imports.insert("java.security.cert.X509Certificate");
+            imports.insert("javax.net.ssl.SSLContext");
+            imports.insert("javax.net.ssl.TrustManager");
+            imports.insert("javax.net.ssl.X509TrustManager");
+            trust_all_manager(&mut body, indent);
+        }
+
+        body.push_str(&format!(
+            "{}OkHttpClient client = new OkHttpClient.Builder()\n",
+            indent
+        ));
+
+        body.push_str(&format!(
+            "{}    .followRedirects({})\n",
+            indent, curl_cmd.location
+        ));
+
+        if curl_cmd.location && curl_cmd.max_redirs.is_some() {
+            body.push_str(&format!(
+                "{}    // Note: OkHttp follows at most 20 redirects and cannot be configured\n",
+                indent
+            ));
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            imports.insert("java.util.concurrent.TimeUnit");
+            body.push_str(&format!(
+                "{}    .connectTimeout({}, TimeUnit.SECONDS)\n",
+                indent, timeout
+            ));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            imports.insert("java.util.concurrent.TimeUnit");
+            body.push_str(&format!(
+                "{}    .callTimeout({}, TimeUnit.SECONDS)\n",
+                indent, timeout
+            ));
+        }
+
+        if curl_cmd.retry.is_some() {
+            body.push_str(&format!("{}    .retryOnConnectionFailure(true)\n", indent));
+        }
+
+        if curl_cmd.no_alpn {
+            imports.insert("java.util.List");
+            imports.insert("okhttp3.Protocol");
+            body.push_str(&format!(
+                "{}    .protocols(List.of(Protocol.HTTP_1_1))\n",
+                indent
+            ));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, host, port) = utils::split_proxy(proxy);
+            let proxy_type = if scheme.starts_with("socks") {
+                "SOCKS"
+            } else {
+                "HTTP"
+            };
+            imports.insert("java.net.InetSocketAddress");
+            imports.insert("java.net.Proxy");
+            body.push_str(&format!(
+                "{}    .proxy(new Proxy(Proxy.Type.{}, new InetSocketAddress({}, {})))\n",
+                indent,
+                proxy_type,
+                string_literal(&host),
+                port
+            ));
+        }
+
+        if let Some((username, password)) = &curl_cmd.proxy_auth {
+            imports.insert("okhttp3.Credentials");
+            body.push_str(&format!(
+                "{}    .proxyAuthenticator((route, response) -> response.request().newBuilder()\n",
+                indent
+            ));
+            body.push_str(&format!(
+                "{}        .header(\"Proxy-Authorization\", Credentials.basic({}, {}))\n",
+                indent,
+                string_literal(username),
+                string_literal(password)
+            ));
+            body.push_str(&format!("{}        .build())\n", indent));
+        }
+
+        if curl_cmd.insecure {
+            body.push_str(&format!(
+                "{}    .sslSocketFactory(sslContext.getSocketFactory(), trustAllManager)\n",
+                indent
+            ));
+            body.push_str(&format!(
+                "{}    .hostnameVerifier((hostname, session) -> true)\n",
+                indent
+            ));
+        }
+
+        body.push_str(&format!("{}    .build();\n\n", indent));
+
+        let request_body = self.request_body(&mut body, &mut imports, curl_cmd, indent);
+
+        body.push_str(&format!(
+            "{}Request request = new Request.Builder()\n",
+            indent
+        ));
+        body.push_str(&format!(
+            "{}    .url({})\n",
+            indent,
+            string_literal(&curl_cmd.url)
+        ));
+
+        for (key, value) in utils::request_headers(curl_cmd) {
+            body.push_str(&format!(
+                "{}    .header({}, {})\n",
+                indent,
+                string_literal(&key),
+                string_literal(&value)
+            ));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if let Some(auth_type) = &curl_cmd.auth_type {
+                if !matches!(auth_type, AuthType::Basic) {
+                    body.push_str(&format!(
+                        "{}    // Note: {:?} authentication requires an OkHttp authenticator library\n",
+                        indent, auth_type
+                    ));
+                }
+            }
+            imports.insert("okhttp3.Credentials");
+            body.push_str(&format!(
+                "{}    .header(\"Authorization\", Credentials.basic({}, {}))\n",
+                indent,
+                string_literal(username),
+                string_literal(password)
+            ));
+        }
+
+        body.push_str(&format!(
+            "{}    .method({}, {})\n",
+            indent,
+            string_literal(&curl_cmd.method),
+            request_body
+        ));
+        body.push_str(&format!("{}    .build();\n\n", indent));
+
+        if curl_cmd.compressed {
+            body.push_str(&format!(
+                "{}// OkHttp transparently requests and decompresses gzip responses\n",
+                indent
+            ));
+        }
+
+        body.push_str(&format!(
+            "{}try (Response response = client.newCall(request).execute()) {{\n",
+            indent
+        ));
+
+        if let Some(output_file) = &curl_cmd.output {
+            imports.insert("java.nio.file.Files");
+            imports.insert("java.nio.file.Path");
+            body.push_str(&format!(
+                "{}    Files.write(Path.of({}), response.body().bytes());\n",
+                indent,
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                body.push_str(&format!(
+                    "{}    System.out.println(response.code());\n",
+                    indent
+                ));
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    body.push_str(&format!(
+                        "{}    System.out.println(response.body().string());\n",
+                        indent
+                    ));
+                }
+            }
+        }
+
+        body.push_str(&format!("{}}}\n", indent));
+
+        let mut output = String::new();
+        for import in &imports {
+            output.push_str(&format!("import {};\n", import));
+        }
+        output.push('\n');
+        output.push_str("public class Main {\n");
+        output.push_str("    public static void main(String[] args) throws Exception {\n");
+        output.push_str(&body);
+        output.push_str("    }\n");
+        output.push_str("}\n");
+
+        output
+    }
+}
+
+impl OkHttp {
+    fn content_type(&self, curl_cmd: &CurlCommand, default: &str) -> String {
+        curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone())
+            .unwrap_or_else(|| default.to_string())
+    }
+
+    fn request_body(
+        &self,
+        output: &mut String,
+        imports: &mut BTreeSet<&'static str>,
+        curl_cmd: &CurlCommand,
+        indent: &str,
+    ) -> String {
+        if let Some(data) = &curl_cmd.data {
+            imports.insert("okhttp3.MediaType");
+            imports.insert("okhttp3.RequestBody");
+            let content_type = self.content_type(curl_cmd, "application/x-www-form-urlencoded");
+            output.push_str(&format!(
+                "{}RequestBody body = RequestBody.create({}, MediaType.parse({}));\n\n",
+                indent,
+                string_literal(data),
+                string_literal(&content_type)
+            ));
+            return "body".to_string();
+        }
+
+        if let Some(binary_file) = &curl_cmd.binary_file {
+            imports.insert("java.io.File");
+            imports.insert("okhttp3.MediaType");
+            imports.insert("okhttp3.RequestBody");
+            let content_type = self.content_type(curl_cmd, "application/octet-stream");
+            output.push_str(&format!(
+                "{}RequestBody body = RequestBody.create(new File({}), MediaType.parse({}));\n\n",
+                indent,
+                string_literal(binary_file),
+                string_literal(&content_type)
+            ));
+            return "body".to_string();
+        }
+
+        if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            imports.insert("okhttp3.MultipartBody");
+            imports.insert("okhttp3.RequestBody");
+            output.push_str(&format!(
+                "{}RequestBody body = new MultipartBody.Builder()\n",
+                indent
+            ));
+            output.push_str(&format!("{}    .setType(MultipartBody.FORM)\n", indent));
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                output.push_str(&format!(
+                    "{}    .addFormDataPart({}, {})\n",
+                    indent,
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                imports.insert("java.io.File");
+                imports.insert("okhttp3.MediaType");
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let content_type = file_upload
+                    .content_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream");
+                output.push_str(&format!(
+                    "{}    .addFormDataPart({}, {}, RequestBody.create(new File({}), MediaType.parse({})))\n",
+                    indent,
+                    string_literal(key),
+                    string_literal(filename),
+                    string_literal(&file_upload.path),
+                    string_literal(content_type)
+                ));
+            }
+
+            output.push_str(&format!("{}    .build();\n\n", indent));
+            return "body".to_string();
+        }
+
+        if utils::has_url_encoded_body(curl_cmd) {
+            imports.insert("okhttp3.FormBody");
+            imports.insert("okhttp3.RequestBody");
+            output.push_str(&format!(
+                "{}RequestBody body = new FormBody.Builder()\n",
+                indent
+            ));
+
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            for (key, value) in params {
+                output.push_str(&format!(
+                    "{}    .add({}, {})\n",
+                    indent,
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            output.push_str(&format!("{}    .build();\n\n", indent));
+            return "body".to_string();
+        }
+
+        if matches!(curl_cmd.method.as_str(), "POST" | "PUT" | "PATCH") {
+            imports.insert("okhttp3.RequestBody");
+            return "RequestBody.create(new byte[0])".to_string();
+        }
+
+        "null".to_string()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::converters::{Convert, ConvertMany};
+use crate::CurlCommand;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize, JsonSchema)]
+#[schemars(
+    title = "CurlCommand",
+    description = "A parsed curl command. A single command is printed as one pretty-printed object; several commands are printed as newline-delimited JSON, one compact object per line."
+)]
+pub struct CommandAst {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub command: CurlCommand,
+}
+
+pub struct JsonAst;
+
+impl Convert for JsonAst {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        serde_json::to_string_pretty(&self.ast(curl_cmd)).unwrap_or_default()
+    }
+}
+
+impl ConvertMany for JsonAst {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        if let [curl_cmd] = curl_cmds {
+            return self.convert(curl_cmd);
+        }
+
+        curl_cmds
+            .iter()
+            .map(|curl_cmd| serde_json::to_string(&self.ast(curl_cmd)).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl JsonAst {
+    fn ast(&self, curl_cmd: &CurlCommand) -> CommandAst {
+        CommandAst {
+            schema_version: SCHEMA_VERSION,
+            command: curl_cmd.clone(),
+        }
+    }
+
+    pub fn schema() -> Value {
+        let mut schema =
+            serde_json::to_value(schemars::schema_for!(CommandAst)).unwrap_or_default();
+        schema["properties"]["schema_version"]["const"] = SCHEMA_VERSION.into();
+        schema
+    }
+}
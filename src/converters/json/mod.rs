@@ -0,0 +1,3 @@
+pub mod ast;
+
+pub use ast::{CommandAst, JsonAst, SCHEMA_VERSION};
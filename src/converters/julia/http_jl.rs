@@ -0,0 +1,230 @@
+use crate::converters::julia::{string_literal, value_literal};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use std::collections::BTreeSet;
+
+pub struct HttpJl;
+
+impl Convert for HttpJl {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut usings = BTreeSet::new();
+        usings.insert("HTTP");
+
+        let mut body = String::new();
+
+        let mut headers: Vec<String> = utils::request_headers(curl_cmd)
+            .iter()
+            .map(|(key, value)| format!("{} => {}", string_literal(key), string_literal(value)))
+            .collect();
+
+        let has_content_type = curl_cmd
+            .headers
+            .keys()
+            .any(|key| key.eq_ignore_ascii_case("content-type"));
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                usings.insert("Base64");
+                headers.push(format!(
+                    "\"Authorization\" => \"Basic \" * base64encode({})",
+                    string_literal(&format!("{}:{}", username, password))
+                ));
+            }
+        }
+
+        let request_body = if let Some(data) = &curl_cmd.data {
+            match serde_json::from_str::<serde_json::Value>(data) {
+                Ok(json) if curl_cmd.data_is_json || json.is_object() || json.is_array() => {
+                    usings.insert("JSON3");
+                    body.push_str(&format!("body = JSON3.write({})\n\n", value_literal(&json)));
+                }
+                _ => {
+                    if !has_content_type {
+                        headers.push(
+                            "\"Content-Type\" => \"application/x-www-form-urlencoded\"".to_string(),
+                        );
+                    }
+                    body.push_str(&format!("body = {}\n\n", string_literal(data)));
+                }
+            }
+            Some("body")
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            body.push_str(&format!("body = read({})\n\n", string_literal(binary_file)));
+            Some("body")
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            body.push_str("body = HTTP.Form([\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                body.push_str(&format!(
+                    "    {} => {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let content_type = file_upload
+                    .content_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream");
+                body.push_str(&format!(
+                    "    {} => HTTP.Multipart({}, open({}), {}),\n",
+                    string_literal(key),
+                    string_literal(filename),
+                    string_literal(&file_upload.path),
+                    string_literal(content_type)
+                ));
+            }
+
+            body.push_str("])\n\n");
+            Some("body")
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| format!("{} => {}", string_literal(key), string_literal(value)))
+                .collect();
+            if !has_content_type {
+                headers
+                    .push("\"Content-Type\" => \"application/x-www-form-urlencoded\"".to_string());
+            }
+            body.push_str(&format!(
+                "body = HTTP.escapeuri([{}])\n\n",
+                pairs.join(", ")
+            ));
+            Some("body")
+        } else {
+            None
+        };
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        for using in &usings {
+            output.push_str(&format!("using {}\n", using));
+        }
+        output.push('\n');
+
+        output.push_str("headers = [\n");
+        for header in &headers {
+            output.push_str(&format!("    {},\n", header));
+        }
+        output.push_str("]\n\n");
+        output.push_str(&body);
+
+        let mut keywords = Vec::new();
+
+        if curl_cmd.insecure {
+            keywords.push("require_ssl_verification = false".to_string());
+        }
+
+        keywords.push(format!("redirect = {}", curl_cmd.location));
+        if curl_cmd.location {
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                keywords.push(format!("redirect_limit = {}", max_redirs));
+            }
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            keywords.push(format!("connect_timeout = {}", timeout));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            keywords.push(format!("readtimeout = {}", timeout));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let proxy_url = match &curl_cmd.proxy_auth {
+                Some((username, password)) => {
+                    let (scheme, host, port) = utils::split_proxy(proxy);
+                    format!("{}://{}:{}@{}:{}", scheme, username, password, host, port)
+                }
+                None => proxy.clone(),
+            };
+            keywords.push(format!("proxy = {}", string_literal(&proxy_url)));
+        }
+
+        match curl_cmd.retry {
+            Some(retry) => keywords.push(format!("retries = {}", retry)),
+            None => keywords.push("retry = false".to_string()),
+        }
+
+        keywords.push("status_exception = false".to_string());
+
+        output.push_str(&format!(
+            "response = HTTP.request({}, {}, headers",
+            string_literal(&curl_cmd.method),
+            string_literal(&curl_cmd.url)
+        ));
+        if let Some(request_body) = request_body {
+            output.push_str(&format!(", {}", request_body));
+        }
+        output.push_str(";\n");
+        for keyword in &keywords {
+            output.push_str(&format!("    {},\n", keyword));
+        }
+        output.push_str(")\n\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            output.push_str(&format!(
+                "write({}, response.body)\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("println(response.status)\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("println(String(response.body))\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push("HTTP.jl only supports Basic authentication".to_string());
+        }
+
+        let ssl = &curl_cmd.ssl_options;
+        if ssl.cacert.is_some() || ssl.cert.is_some() || ssl.key.is_some() {
+            warnings.push(
+                "certificates (--cacert/--cert/--key) need a custom sslconfig in HTTP.jl"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.max_time.is_some() {
+            warnings.push(
+                "HTTP.jl has no total request timeout, --max-time mapped to readtimeout"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("HTTP.jl does not expose ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
@@ -0,0 +1,33 @@
+pub mod http_jl;
+
+pub use http_jl::HttpJl;
+
+use crate::converters::utils;
+use serde_json::Value;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        utils::escape_string_literal(s).replace('$', "\\$")
+    )
+}
+
+pub(crate) fn value_literal(value: &Value) -> String {
+    match value {
+        Value::Null => "nothing".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => string_literal(s),
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(value_literal).collect();
+            format!("Any[{}]", items.join(", "))
+        }
+        Value::Object(map) => {
+            let pairs: Vec<String> = map
+                .iter()
+                .map(|(key, item)| format!("{} => {}", string_literal(key), value_literal(item)))
+                .collect();
+            format!("Dict{{String, Any}}({})", pairs.join(", "))
+        }
+    }
+}
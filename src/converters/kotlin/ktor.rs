@@ -0,0 +1,372 @@
+use crate::converters::kotlin::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use std::collections::BTreeSet;
+
+pub struct Ktor;
+
+impl Convert for Ktor {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut imports = BTreeSet::new();
+        imports.insert("io.ktor.client.*");
+        imports.insert("io.ktor.client.engine.cio.*");
+        imports.insert("io.ktor.client.request.*");
+        imports.insert("io.ktor.client.statement.*");
+        imports.insert("io.ktor.http.*");
+        imports.insert("kotlinx.coroutines.runBlocking");
+
+        let mut body = String::new();
+
+        body.push_str("    val client = HttpClient(CIO) {\n");
+        body.push_str(&format!(
+            "        followRedirects = {}\n",
+            curl_cmd.location
+        ));
+        body.push_str("        expectSuccess = false\n");
+
+        if curl_cmd.connect_timeout.is_some() || curl_cmd.max_time.is_some() {
+            imports.insert("io.ktor.client.plugins.*");
+            body.push_str("        install(HttpTimeout) {\n");
+            if let Some(timeout) = curl_cmd.connect_timeout {
+                body.push_str(&format!(
+                    "            connectTimeoutMillis = {}\n",
+                    timeout as u64 * 1000
+                ));
+            }
+            if let Some(timeout) = curl_cmd.max_time {
+                body.push_str(&format!(
+                    "            requestTimeoutMillis = {}\n",
+                    timeout as u64 * 1000
+                ));
+            }
+            body.push_str("        }\n");
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            match curl_cmd.auth_type {
+                Some(AuthType::Digest) => {
+                    imports.insert("io.ktor.client.plugins.auth.*");
+                    imports.insert("io.ktor.client.plugins.auth.providers.*");
+                    body.push_str("        install(Auth) {\n");
+                    body.push_str("            digest {\n");
+                    body.push_str("                credentials {\n");
+                    body.push_str(&format!(
+                        "                    DigestAuthCredentials(username = {}, password = {})\n",
+                        string_literal(username),
+                        string_literal(password)
+                    ));
+                    body.push_str("                }\n");
+                    body.push_str("            }\n");
+                    body.push_str("        }\n");
+                }
+                Some(AuthType::Ntlm) | Some(AuthType::Negotiate) => {}
+                _ => {
+                    imports.insert("io.ktor.client.plugins.auth.*");
+                    imports.insert("io.ktor.client.plugins.auth.providers.*");
+                    body.push_str("        install(Auth) {\n");
+                    body.push_str("            basic {\n");
+                    body.push_str("                credentials {\n");
+                    body.push_str(&format!(
+                        "                    BasicAuthCredentials(username = {}, password = {})\n",
+                        string_literal(username),
+                        string_literal(password)
+                    ));
+                    body.push_str("                }\n");
+                    body.push_str("                sendWithoutRequest { true }\n");
+                    body.push_str("            }\n");
+                    body.push_str("        }\n");
+                }
+            }
+        }
+
+        if curl_cmd.compressed {
+            imports.insert("io.ktor.client.plugins.compression.*");
+            body.push_str("        install(ContentEncoding) {\n");
+            body.push_str("            gzip()\n");
+            body.push_str("            deflate()\n");
+            body.push_str("        }\n");
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            imports.insert("io.ktor.client.plugins.*");
+            body.push_str("        install(HttpRequestRetry) {\n");
+            body.push_str(&format!(
+                "            retryOnExceptionOrServerErrors(maxRetries = {})\n",
+                retry
+            ));
+            body.push_str("            exponentialDelay()\n");
+            body.push_str("        }\n");
+        }
+
+        let http_proxy = curl_cmd
+            .proxy
+            .as_ref()
+            .filter(|proxy| utils::split_proxy(proxy).0 == "http");
+
+        if http_proxy.is_some() || curl_cmd.insecure {
+            body.push_str("        engine {\n");
+
+            if let Some(proxy) = http_proxy {
+                imports.insert("io.ktor.client.engine.*");
+                let proxy_url = if proxy.contains("://") {
+                    proxy.clone()
+                } else {
+                    format!("http://{}", proxy)
+                };
+                body.push_str(&format!(
+                    "            proxy = ProxyBuilder.http({})\n",
+                    string_literal(&proxy_url)
+                ));
+            }
+
+            if curl_cmd.insecure {
+                imports.insert("java.security.cert.X509Certificate");
+                imports.insert("javax.net.ssl.X509TrustManager");
+                body.push_str("            https {\n");
+                body.push_str("                trustManager = object : X509TrustManager {\n");
+                body.push_str("                    override fun checkClientTrusted(chain: Array<X509Certificate>, authType: String) {}\n");
+                body.push_str("                    override fun checkServerTrusted(chain: Array<X509Certificate>, authType: String) {}\n");
+                body.push_str("                    override fun getAcceptedIssuers(): Array<X509Certificate> = arrayOf()\n");
+                body.push_str("                }\n");
+                body.push_str("            }\n");
+            }
+
+            body.push_str("        }\n");
+        }
+
+        body.push_str("    }\n\n");
+
+        let has_form = !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty();
+
+        if has_form {
+            imports.insert("io.ktor.client.request.forms.*");
+            body.push_str("    val response = client.submitFormWithBinaryData(\n");
+            body.push_str(&format!(
+                "        url = {},\n",
+                string_literal(&curl_cmd.url)
+            ));
+            body.push_str("        formData = formData {\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                body.push_str(&format!(
+                    "            append({}, {})\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                imports.insert("java.io.File");
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                body.push_str(&format!(
+                    "            append({}, File({}).readBytes(), Headers.build {{\n",
+                    string_literal(key),
+                    string_literal(&file_upload.path)
+                ));
+                if let Some(content_type) = &file_upload.content_type {
+                    body.push_str(&format!(
+                        "                append(HttpHeaders.ContentType, {})\n",
+                        string_literal(content_type)
+                    ));
+                }
+                body.push_str(&format!(
+                    "                append(HttpHeaders.ContentDisposition, {})\n",
+                    string_literal(&format!("filename=\"{}\"", filename))
+                ));
+                body.push_str("            })\n");
+            }
+
+            body.push_str("        }\n");
+            body.push_str("    ) {\n");
+            if curl_cmd.method != "POST" {
+                body.push_str(&format!(
+                    "        method = {}\n",
+                    self.http_method(curl_cmd)
+                ));
+            }
+        } else {
+            body.push_str(&format!(
+                "    val response = client.request({}) {{\n",
+                string_literal(&curl_cmd.url)
+            ));
+            body.push_str(&format!(
+                "        method = {}\n",
+                self.http_method(curl_cmd)
+            ));
+        }
+
+        let headers = utils::request_headers(curl_cmd);
+        let content_type = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone());
+
+        let other_headers: Vec<_> = headers
+            .iter()
+            .filter(|(key, _)| !key.eq_ignore_ascii_case("content-type"))
+            .collect();
+
+        if !other_headers.is_empty() {
+            body.push_str("        headers {\n");
+            for (key, value) in other_headers {
+                body.push_str(&format!(
+                    "            append({}, {})\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+            body.push_str("        }\n");
+        }
+
+        if !has_form {
+            if let Some(data) = &curl_cmd.data {
+                let content_type =
+                    content_type.unwrap_or_else(|| "application/x-www-form-urlencoded".to_string());
+                body.push_str(&format!(
+                    "        contentType(ContentType.parse({}))\n",
+                    string_literal(&content_type)
+                ));
+                body.push_str(&format!("        setBody({})\n", string_literal(data)));
+            } else if let Some(binary_file) = &curl_cmd.binary_file {
+                imports.insert("java.io.File");
+                let content_type =
+                    content_type.unwrap_or_else(|| "application/octet-stream".to_string());
+                body.push_str(&format!(
+                    "        contentType(ContentType.parse({}))\n",
+                    string_literal(&content_type)
+                ));
+                body.push_str(&format!(
+                    "        setBody(File({}).readBytes())\n",
+                    string_literal(binary_file)
+                ));
+            } else if utils::has_url_encoded_body(curl_cmd) {
+                imports.insert("io.ktor.client.request.forms.*");
+                body.push_str("        setBody(FormDataContent(Parameters.build {\n");
+                let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+                params.sort();
+                for (key, value) in params {
+                    body.push_str(&format!(
+                        "            append({}, {})\n",
+                        string_literal(key),
+                        string_literal(value)
+                    ));
+                }
+                body.push_str("        }))\n");
+            }
+        }
+
+        body.push_str("    }\n\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            imports.insert("java.io.File");
+            body.push_str(&format!(
+                "    File({}).writeBytes(response.readBytes())\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                body.push_str("    println(response.status.value)\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    body.push_str("    println(response.bodyAsText())\n");
+                }
+            }
+        }
+
+        body.push_str("    client.close()\n");
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("//", &self.warnings(curl_cmd)));
+        for import in &imports {
+            output.push_str(&format!("import {}\n", import));
+        }
+        output.push('\n');
+        output.push_str("fun main() = runBlocking {\n");
+        output.push_str(&body);
+        output.push_str("}\n");
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() {
+            match curl_cmd.auth_type {
+                Some(AuthType::Ntlm) => {
+                    warnings.push("NTLM authentication is not supported by Ktor".to_string())
+                }
+                Some(AuthType::Negotiate) => {
+                    warnings.push("Negotiate authentication is not supported by Ktor".to_string())
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, _, _) = utils::split_proxy(proxy);
+            if scheme != "http" {
+                warnings.push(format!(
+                    "the CIO engine only supports HTTP proxies, {} proxy ignored",
+                    scheme
+                ));
+            }
+        }
+
+        if curl_cmd.proxy_auth.is_some() {
+            warnings.push(
+                "proxy authentication (--proxy-user) is not supported by the CIO engine"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.location && curl_cmd.max_redirs.is_some() {
+            warnings.push("Ktor does not support limiting redirects (--max-redirs)".to_string());
+        }
+
+        if curl_cmd.ssl_options.cert.is_some() || curl_cmd.ssl_options.key.is_some() {
+            warnings.push(
+                "client certificates (--cert/--key) require a custom KeyManager in the CIO engine"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push(
+                "custom CA certificates (--cacert) require a custom TrustManager in the CIO engine"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("the CIO engine does not negotiate ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl Ktor {
+    fn http_method(&self, curl_cmd: &CurlCommand) -> String {
+        match curl_cmd.method.as_str() {
+            "GET" => "HttpMethod.Get".to_string(),
+            "POST" => "HttpMethod.Post".to_string(),
+            "PUT" => "HttpMethod.Put".to_string(),
+            "DELETE" => "HttpMethod.Delete".to_string(),
+            "PATCH" => "HttpMethod.Patch".to_string(),
+            "HEAD" => "HttpMethod.Head".to_string(),
+            "OPTIONS" => "HttpMethod.Options".to_string(),
+            method => format!("HttpMethod({})", string_literal(method)),
+        }
+    }
+}
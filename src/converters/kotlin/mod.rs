@@ -0,0 +1,12 @@
+pub mod ktor;
+
+pub use ktor::Ktor;
+
+use crate::converters::utils;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        utils::escape_string_literal(s).replace('$', "\\$")
+    )
+}
@@ -0,0 +1,115 @@
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::{json, Map, Value};
+use url::Url;
+
+pub struct KubernetesProbe;
+
+impl Convert for KubernetesProbe {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = utils::warning_comments("#", &self.warnings(curl_cmd));
+
+        let Ok(url) = Url::parse(&curl_cmd.url) else {
+            return output;
+        };
+
+        let mut http_get = Map::new();
+        http_get.insert("scheme".to_string(), json!(url.scheme().to_uppercase()));
+        if let Some(host) = url.host_str() {
+            http_get.insert("host".to_string(), json!(host));
+        }
+        if let Some(port) = url.port_or_known_default() {
+            http_get.insert("port".to_string(), json!(port));
+        }
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        http_get.insert("path".to_string(), json!(path));
+
+        let mut headers: Vec<Value> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .map(|(name, value)| json!({"name": name, "value": value}))
+            .collect();
+        if let Some((username, password)) = &curl_cmd.auth {
+            if matches!(curl_cmd.auth_type, None | Some(AuthType::Basic)) {
+                let credentials = STANDARD.encode(format!("{}:{}", username, password));
+                headers.push(json!({
+                    "name": "Authorization",
+                    "value": format!("Basic {}", credentials),
+                }));
+            }
+        }
+        if !headers.is_empty() {
+            http_get.insert("httpHeaders".to_string(), Value::Array(headers));
+        }
+
+        let mut probe = Map::new();
+        probe.insert("httpGet".to_string(), Value::Object(http_get));
+        if let Some(timeout) = curl_cmd.max_time.or(curl_cmd.connect_timeout) {
+            probe.insert("timeoutSeconds".to_string(), json!(timeout));
+        }
+        if let Some(retry) = curl_cmd.retry {
+            probe.insert("failureThreshold".to_string(), json!(retry + 1));
+        }
+
+        let probes = json!({
+            "livenessProbe": probe,
+            "readinessProbe": probe,
+        });
+        output.push_str(&serde_yaml_ng::to_string(&probes).unwrap_or_default());
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if Url::parse(&curl_cmd.url).is_err() {
+            warnings.push(format!("Cannot split {} into a probe target", curl_cmd.url));
+        }
+
+        if curl_cmd.method != "GET" {
+            warnings.push(format!(
+                "Probes always send GET, method {} ignored",
+                curl_cmd.method
+            ));
+        }
+
+        if curl_cmd.data.is_some()
+            || curl_cmd.binary_file.is_some()
+            || !curl_cmd.form.is_empty()
+            || !curl_cmd.files.is_empty()
+            || utils::has_url_encoded_body(curl_cmd)
+        {
+            warnings.push("Probes cannot send a request body, body ignored".to_string());
+        }
+
+        if curl_cmd.auth.is_some() && !matches!(curl_cmd.auth_type, None | Some(AuthType::Basic)) {
+            warnings.push(
+                "Probes only support basic auth through headers, credentials omitted".to_string(),
+            );
+        }
+
+        if curl_cmd.ssl_options.cert.is_some()
+            || curl_cmd.ssl_options.key.is_some()
+            || curl_cmd.ssl_options.cacert.is_some()
+        {
+            warnings.push(
+                "Probes skip certificate verification and cannot present client certificates, --cert/--key/--cacert ignored"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.proxy.is_some() {
+            warnings.push("Probes cannot use a proxy, --proxy ignored".to_string());
+        }
+
+        if curl_cmd.output.is_some() {
+            warnings.push("Probes discard the response body, -o ignored".to_string());
+        }
+
+        warnings
+    }
+}
@@ -0,0 +1,9 @@
+pub mod resty_http;
+
+pub use resty_http::RestyHttp;
+
+use crate::converters::utils;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("\"{}\"", utils::escape_string_literal(s))
+}
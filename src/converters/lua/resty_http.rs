@@ -0,0 +1,294 @@
+use crate::converters::lua::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct RestyHttp;
+
+impl Convert for RestyHttp {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let has_form = !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty();
+        let reads_files = curl_cmd.binary_file.is_some() || !curl_cmd.files.is_empty();
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("--", &self.warnings(curl_cmd)));
+        output.push_str("local http = require(\"resty.http\")\n\n");
+
+        if reads_files {
+            output.push_str("local function read_file(path)\n");
+            output.push_str("  local file = assert(io.open(path, \"rb\"))\n");
+            output.push_str("  local content = file:read(\"*a\")\n");
+            output.push_str("  file:close()\n");
+            output.push_str("  return content\n");
+            output.push_str("end\n\n");
+        }
+
+        output.push_str("local httpc = http.new()\n");
+
+        match (curl_cmd.connect_timeout, curl_cmd.max_time) {
+            (Some(connect), Some(total)) => output.push_str(&format!(
+                "httpc:set_timeouts({}, {}, {})\n",
+                connect * 1000,
+                total * 1000,
+                total * 1000
+            )),
+            (Some(connect), None) => output.push_str(&format!(
+                "httpc:set_timeouts({}, 60000, 60000)\n",
+                connect * 1000
+            )),
+            (None, Some(total)) => {
+                output.push_str(&format!("httpc:set_timeout({})\n", total * 1000))
+            }
+            (None, None) => {}
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, host, port) = utils::split_proxy(proxy);
+            let proxy_url = format!("{}://{}:{}", scheme, host, port);
+            output.push_str("httpc:set_proxy_options({\n");
+            output.push_str(&format!("  http_proxy = {},\n", string_literal(&proxy_url)));
+            output.push_str(&format!(
+                "  https_proxy = {},\n",
+                string_literal(&proxy_url)
+            ));
+            if let Some((username, password)) = &curl_cmd.proxy_auth {
+                let authorization = format!(
+                    "\"Basic \" .. ngx.encode_base64({})",
+                    string_literal(&format!("{}:{}", username, password))
+                );
+                output.push_str(&format!(
+                    "  http_proxy_authorization = {},\n",
+                    authorization
+                ));
+                output.push_str(&format!(
+                    "  https_proxy_authorization = {},\n",
+                    authorization
+                ));
+            }
+            output.push_str("})\n");
+        }
+        output.push('\n');
+
+        let mut headers: Vec<(String, String)> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .map(|(key, value)| (key, string_literal(&value)))
+            .collect();
+        let has_content_type = headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                headers.push((
+                    "Authorization".to_string(),
+                    format!(
+                        "\"Basic \" .. ngx.encode_base64({})",
+                        string_literal(&format!("{}:{}", username, password))
+                    ),
+                ));
+            }
+        }
+
+        let body = if let Some(data) = &curl_cmd.data {
+            if !has_content_type {
+                headers.push((
+                    "Content-Type".to_string(),
+                    string_literal("application/x-www-form-urlencoded"),
+                ));
+            }
+            Some(string_literal(data))
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            Some(format!("read_file({})", string_literal(binary_file)))
+        } else if has_form {
+            output.push_str(&self.multipart_body(curl_cmd));
+            headers.push((
+                "Content-Type".to_string(),
+                "\"multipart/form-data; boundary=\" .. boundary".to_string(),
+            ));
+            Some("body".to_string())
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| {
+                    format!("[{}] = {}", string_literal(key), string_literal(value))
+                })
+                .collect();
+            if !has_content_type {
+                headers.push((
+                    "Content-Type".to_string(),
+                    string_literal("application/x-www-form-urlencoded"),
+                ));
+            }
+            Some(format!("ngx.encode_args({{ {} }})", pairs.join(", ")))
+        } else {
+            None
+        };
+
+        output.push_str(&format!(
+            "local res, err = httpc:request_uri({}, {{\n",
+            string_literal(&curl_cmd.url)
+        ));
+        output.push_str(&format!(
+            "  method = {},\n",
+            string_literal(&curl_cmd.method)
+        ));
+
+        if let Some(body) = &body {
+            output.push_str(&format!("  body = {},\n", body));
+        }
+
+        if !headers.is_empty() {
+            output.push_str("  headers = {\n");
+            for (key, value) in &headers {
+                output.push_str(&format!("    [{}] = {},\n", string_literal(key), value));
+            }
+            output.push_str("  },\n");
+        }
+
+        if curl_cmd.insecure {
+            output.push_str("  ssl_verify = false,\n");
+        }
+
+        output.push_str("})\n\n");
+
+        output.push_str("if not res then\n");
+        output.push_str("  ngx.log(ngx.ERR, \"request failed: \", err)\n");
+        output.push_str("  return\n");
+        output.push_str("end\n\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            output.push_str(&format!(
+                "local out = assert(io.open({}, \"wb\"))\n",
+                string_literal(output_file)
+            ));
+            output.push_str("out:write(res.body)\n");
+            output.push_str("out:close()\n");
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("ngx.say(res.status)\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("ngx.say(res.body)\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push("resty.http only supports Basic authentication".to_string());
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, _, _) = utils::split_proxy(proxy);
+            if scheme != "http" {
+                warnings.push(format!(
+                    "resty.http only supports HTTP proxies, not {}",
+                    scheme
+                ));
+            }
+        }
+
+        if curl_cmd.location {
+            warnings.push(
+                "resty.http does not follow redirects, handle 3xx responses manually".to_string(),
+            );
+        }
+
+        if curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push(
+                "custom CA certificates (--cacert) are set with the lua_ssl_trusted_certificate directive"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.ssl_options.cert.is_some() || curl_cmd.ssl_options.key.is_some() {
+            warnings.push(
+                "client certificates need ngx.ssl.parse_pem_cert and ssl_client_cert".to_string(),
+            );
+        }
+
+        if curl_cmd.compressed {
+            warnings
+                .push("resty.http does not decompress responses, --compressed ignored".to_string());
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "resty.http has no retry option, call request_uri in a loop to retry {} times",
+                retry
+            ));
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("resty.http does not negotiate ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl RestyHttp {
+    fn multipart_body(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+        let mut lines = Vec::new();
+
+        let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+        fields.sort();
+        for (key, value) in fields {
+            lines.push("\"--\" .. boundary".to_string());
+            lines.push(string_literal(&format!(
+                "Content-Disposition: form-data; name=\"{}\"",
+                key
+            )));
+            lines.push("\"\"".to_string());
+            lines.push(string_literal(value));
+        }
+
+        let mut files: Vec<_> = curl_cmd.files.iter().collect();
+        files.sort_by(|a, b| a.0.cmp(b.0));
+        for (key, file_upload) in files {
+            let filename = file_upload
+                .filename
+                .as_deref()
+                .unwrap_or_else(|| utils::file_name(&file_upload.path));
+            let content_type = file_upload
+                .content_type
+                .as_deref()
+                .unwrap_or("application/octet-stream");
+            lines.push("\"--\" .. boundary".to_string());
+            lines.push(string_literal(&format!(
+                "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"",
+                key, filename
+            )));
+            lines.push(string_literal(&format!("Content-Type: {}", content_type)));
+            lines.push("\"\"".to_string());
+            lines.push(format!("read_file({})", string_literal(&file_upload.path)));
+        }
+
+        lines.push("\"--\" .. boundary .. \"--\"".to_string());
+        lines.push("\"\"".to_string());
+
+        output.push_str("local boundary = \"----CurlParserBoundary\"\n");
+        output.push_str("local body = table.concat({\n");
+        for line in &lines {
+            output.push_str(&format!("  {},\n", line));
+        }
+        output.push_str("}, \"\\r\\n\")\n\n");
+
+        output
+    }
+}
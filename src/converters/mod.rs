@@ -1,15 +1,58 @@
 use crate::CurlCommand;
 
+pub mod ansible;
+pub mod bruno;
+pub mod c;
+pub mod csharp;
+pub mod dart;
+pub mod elixir;
+pub mod erlang;
+pub mod har;
+pub mod http_file;
+pub mod hurl;
+pub mod insomnia;
+pub mod java;
+pub mod json;
+pub mod julia;
+pub mod kotlin;
+pub mod kubernetes;
+pub mod lua;
 pub mod nodejs;
+pub mod openapi;
+pub mod perl;
+pub mod php;
+pub mod postman;
+pub mod powershell;
 pub mod python;
+pub mod r;
+pub mod ruby;
+pub mod shell;
+pub mod swift;
+pub mod terraform;
 
 
 pub trait Convert {
     fn convert(&self, curl_cmd: &CurlCommand) -> String;
+
+    fn warnings(&self, _curl_cmd: &CurlCommand) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+pub trait ConvertMany: Convert {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String;
+}
+
+pub trait ConvertTree: Convert {
+    fn convert_tree(&self, curl_cmds: &[CurlCommand]) -> Vec<(String, String)>;
 }
 
 
 pub(crate) mod utils {
+    use crate::CurlCommand;
+    use std::collections::HashMap;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
     
     pub fn escape_quotes(s: &str) -> String {
         s.replace("\"", "\\\"")
@@ -19,4 +62,168 @@ pub(crate) mod utils {
     pub fn escape_single_quotes(s: &str) -> String {
         s.replace("'", "\\'")
     }
+
+    pub fn escape_string_literal(s: &str) -> String {
+        let mut escaped = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                '\\' => escaped.push_str("\\\\"),
+                '"' => escaped.push_str("\\\""),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                _ => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    pub fn warning_comments(prefix: &str, warnings: &[String]) -> String {
+        let mut output = String::new();
+        for warning in warnings {
+            output.push_str(&format!("{} Warning: {}\n", prefix, warning));
+        }
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output
+    }
+
+    pub fn file_name(path: &str) -> &str {
+        path.rsplit(['/', '\\']).next().unwrap_or(path)
+    }
+
+    pub fn url_encoded_body(params: &HashMap<String, String>) -> String {
+        let mut pairs: Vec<_> = params.iter().collect();
+        pairs.sort();
+
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        for (key, value) in pairs {
+            serializer.append_pair(key, value);
+        }
+        serializer.finish()
+    }
+
+    pub fn split_proxy(proxy: &str) -> (String, String, u16) {
+        let (scheme, rest) = match proxy.split_once("://") {
+            Some((scheme, rest)) => (scheme.to_lowercase(), rest),
+            None => ("http".to_string(), proxy),
+        };
+        let host_port = rest.split('/').next().unwrap_or(rest);
+        let host_port = host_port.rsplit('@').next().unwrap_or(host_port);
+        match host_port.rsplit_once(':') {
+            Some((host, port)) if port.parse::<u16>().is_ok() => {
+                (scheme, host.to_string(), port.parse().unwrap())
+            }
+            _ => (scheme, host_port.to_string(), 1080),
+        }
+    }
+
+    pub fn proxy_with_credentials(proxy: &str, username: &str, password: &str) -> String {
+        let (scheme, host, port) = split_proxy(proxy);
+        let proxy_url = format!("{}://{}:{}", scheme, host, port);
+        let Ok(mut url) = url::Url::parse(&proxy_url) else {
+            return proxy_url;
+        };
+        if url.set_username(username).is_err() || url.set_password(Some(password)).is_err() {
+            return proxy_url;
+        }
+        url.as_str().trim_end_matches('/').to_string()
+    }
+
+    pub fn cookie_header(cookies: &HashMap<String, String>) -> String {
+        let mut pairs: Vec<String> = cookies
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        pairs.sort();
+        pairs.join("; ")
+    }
+
+    pub fn request_headers(curl_cmd: &CurlCommand) -> Vec<(String, String)> {
+        let mut headers: Vec<(String, String)> = curl_cmd
+            .headers
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        headers.sort();
+
+        if let Some(user_agent) = &curl_cmd.user_agent {
+            headers.push(("User-Agent".to_string(), user_agent.clone()));
+        }
+
+        if !curl_cmd.cookies.is_empty() {
+            headers.push(("Cookie".to_string(), cookie_header(&curl_cmd.cookies)));
+        }
+
+        headers
+    }
+
+    pub fn has_url_encoded_body(curl_cmd: &CurlCommand) -> bool {
+        !curl_cmd.url_encoded_params.is_empty() && curl_cmd.method != "GET"
+    }
+
+    pub fn request_name(curl_cmd: &CurlCommand) -> String {
+        match url::Url::parse(&curl_cmd.url) {
+            Ok(url) => format!("{} {}", curl_cmd.method, url.path()),
+            Err(_) => format!("{} {}", curl_cmd.method, curl_cmd.url),
+        }
+    }
+
+    pub fn host(curl_cmd: &CurlCommand) -> String {
+        url::Url::parse(&curl_cmd.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| "requests".to_string())
+    }
+
+    pub fn slug(value: &str, fallback: &str) -> String {
+        let mut slug = String::new();
+        for c in value.chars() {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                slug.push(c);
+            } else if !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+
+        match slug.trim_matches('-') {
+            "" | "." | ".." => fallback.to_string(),
+            slug => slug.to_string(),
+        }
+    }
+
+    pub fn timestamp() -> String {
+        let elapsed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let seconds = elapsed.as_secs();
+        let (hour, minute, second) = (seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+
+        let days = (seconds / 86400) as i64 + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            elapsed.subsec_millis()
+        )
+    }
 }
@@ -14,7 +14,7 @@ impl Convert for Axios {
         }
 
         output.push_str(&imports);
-        output.push_str("\n");
+        output.push('\n');
 
         if let Some(binary_file) = &curl_cmd.binary_file {
             output.push_str(&format!(
@@ -145,7 +145,7 @@ impl Convert for Axios {
                 if let Some(max_redirs) = curl_cmd.max_redirs {
                     output.push_str(&format!("{}", max_redirs));
                 } else {
-                    output.push_str("5"); 
+                    output.push('5'); 
                 }
 
                 output.push_str(",\n");
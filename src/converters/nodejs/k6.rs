@@ -0,0 +1,259 @@
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::{AuthType, CurlCommand};
+use url::Url;
+
+pub struct K6;
+
+impl Convert for K6 {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Negotiate)) {
+            warnings.push("k6 has no negotiate authentication, credentials omitted".to_string());
+        }
+
+        if curl_cmd.proxy.is_some() {
+            warnings
+                .push("k6 reads proxies from HTTP_PROXY/HTTPS_PROXY, --proxy ignored".to_string());
+        }
+
+        if curl_cmd.ssl_options.cert.is_some() || curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push(
+                "k6 client certificates go in options.tlsAuth, --cert/--cacert ignored".to_string(),
+            );
+        }
+
+        if curl_cmd.retry.is_some() {
+            warnings.push("k6 does not retry requests, --retry ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for K6 {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let mut output = String::new();
+        for curl_cmd in curl_cmds {
+            output.push_str(&utils::warning_comments("//", &self.warnings(curl_cmd)));
+        }
+
+        output.push_str("import http from \"k6/http\";\n");
+        output.push_str("import { check, sleep } from \"k6\";\n\n");
+
+        let mut files: Vec<String> = Vec::new();
+        let requests: Vec<String> = curl_cmds
+            .iter()
+            .enumerate()
+            .map(|(index, curl_cmd)| {
+                let name = if curl_cmds.len() == 1 {
+                    "res".to_string()
+                } else {
+                    format!("res{}", index + 1)
+                };
+                self.request(curl_cmd, &name, &mut files)
+            })
+            .collect();
+
+        for (index, path) in files.iter().enumerate() {
+            output.push_str(&format!(
+                "const file{} = open(\"{}\", \"b\");\n",
+                index + 1,
+                utils::escape_string_literal(path)
+            ));
+        }
+        if !files.is_empty() {
+            output.push('\n');
+        }
+
+        output.push_str("export const options = {\n");
+        output.push_str("  vus: 10,\n");
+        output.push_str("  duration: \"30s\",\n");
+        if curl_cmds.iter().any(|curl_cmd| curl_cmd.insecure) {
+            output.push_str("  insecureSkipTLSVerify: true,\n");
+        }
+        output.push_str("};\n\n");
+
+        output.push_str("export default function () {\n");
+        output.push_str(&requests.join("\n"));
+        output.push_str("\n  sleep(1);\n");
+        output.push_str("}\n");
+        output
+    }
+}
+
+impl K6 {
+    fn request(&self, curl_cmd: &CurlCommand, name: &str, files: &mut Vec<String>) -> String {
+        let body = self.body(curl_cmd, files);
+        let mut params = String::new();
+
+        let mut headers: Vec<(String, String)> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .filter(|(key, _)| !key.eq_ignore_ascii_case("cookie"))
+            .collect();
+        let has_content_type = headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+        let is_form_body = curl_cmd.data.is_some()
+            || (curl_cmd.form.is_empty()
+                && curl_cmd.files.is_empty()
+                && curl_cmd.binary_file.is_none()
+                && utils::has_url_encoded_body(curl_cmd));
+        if is_form_body && !has_content_type {
+            headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+        if !headers.is_empty() {
+            params.push_str("    headers: {\n");
+            for (key, value) in &headers {
+                params.push_str(&format!(
+                    "      \"{}\": \"{}\",\n",
+                    utils::escape_string_literal(key),
+                    utils::escape_string_literal(value)
+                ));
+            }
+            params.push_str("    },\n");
+        }
+
+        if !curl_cmd.cookies.is_empty() {
+            params.push_str("    cookies: {\n");
+            let mut cookies: Vec<_> = curl_cmd.cookies.iter().collect();
+            cookies.sort();
+            for (key, value) in cookies {
+                params.push_str(&format!(
+                    "      \"{}\": \"{}\",\n",
+                    utils::escape_string_literal(key),
+                    utils::escape_string_literal(value)
+                ));
+            }
+            params.push_str("    },\n");
+        }
+
+        if let Some(timeout) = curl_cmd.max_time.or(curl_cmd.connect_timeout) {
+            params.push_str(&format!("    timeout: \"{}s\",\n", timeout));
+        }
+
+        if !curl_cmd.location {
+            params.push_str("    redirects: 0,\n");
+        } else if let Some(max_redirs) = curl_cmd.max_redirs {
+            params.push_str(&format!("    redirects: {},\n", max_redirs));
+        }
+
+        if curl_cmd.auth.is_some() {
+            match curl_cmd.auth_type {
+                Some(AuthType::Digest) => params.push_str("    auth: \"digest\",\n"),
+                Some(AuthType::Ntlm) => params.push_str("    auth: \"ntlm\",\n"),
+                _ => {}
+            }
+        }
+
+        let params = if params.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{}  }}", params)
+        };
+
+        let mut output = format!(
+            "  const {} = http.request(\"{}\", \"{}\", {}, {});\n",
+            name,
+            curl_cmd.method,
+            utils::escape_string_literal(&self.url(curl_cmd)),
+            body,
+            params
+        );
+        output.push_str(&format!(
+            "  check({}, {{\n    \"{} status is 2xx\": (r) => r.status >= 200 && r.status < 300,\n  }});\n",
+            name,
+            utils::escape_string_literal(&utils::request_name(curl_cmd))
+        ));
+        output
+    }
+
+    fn url(&self, curl_cmd: &CurlCommand) -> String {
+        let Some((username, password)) = &curl_cmd.auth else {
+            return curl_cmd.url.clone();
+        };
+        if matches!(curl_cmd.auth_type, Some(AuthType::Negotiate)) {
+            return curl_cmd.url.clone();
+        }
+
+        match Url::parse(&curl_cmd.url) {
+            Ok(mut url) => {
+                if url.set_username(username).is_err() || url.set_password(Some(password)).is_err()
+                {
+                    return curl_cmd.url.clone();
+                }
+                url.to_string()
+            }
+            Err(_) => curl_cmd.url.clone(),
+        }
+    }
+
+    fn body(&self, curl_cmd: &CurlCommand, files: &mut Vec<String>) -> String {
+        if let Some(data) = &curl_cmd.data {
+            if curl_cmd.data_is_json && serde_json::from_str::<serde_json::Value>(data).is_ok() {
+                format!("JSON.stringify({})", data)
+            } else {
+                format!("\"{}\"", utils::escape_string_literal(data))
+            }
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            files.push(binary_file.clone());
+            format!("file{}", files.len())
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut fields: Vec<String> = Vec::new();
+
+            let mut form: Vec<_> = curl_cmd.form.iter().collect();
+            form.sort();
+            for (key, value) in form {
+                fields.push(format!(
+                    "    \"{}\": \"{}\",\n",
+                    utils::escape_string_literal(key),
+                    utils::escape_string_literal(value)
+                ));
+            }
+
+            let mut uploads: Vec<_> = curl_cmd.files.iter().collect();
+            uploads.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in uploads {
+                files.push(file_upload.path.clone());
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let mut arguments = format!(
+                    "file{}, \"{}\"",
+                    files.len(),
+                    utils::escape_string_literal(filename)
+                );
+                if let Some(content_type) = &file_upload.content_type {
+                    arguments.push_str(&format!(
+                        ", \"{}\"",
+                        utils::escape_string_literal(content_type)
+                    ));
+                }
+                fields.push(format!(
+                    "    \"{}\": http.file({}),\n",
+                    utils::escape_string_literal(key),
+                    arguments
+                ));
+            }
+
+            format!("{{\n{}  }}", fields.concat())
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            format!(
+                "\"{}\"",
+                utils::escape_string_literal(&utils::url_encoded_body(
+                    &curl_cmd.url_encoded_params
+                ))
+            )
+        } else {
+            "null".to_string()
+        }
+    }
+}
@@ -1,3 +1,5 @@
 pub mod axios;
+pub mod k6;
 
 pub use axios::Axios;
+pub use k6::K6;
@@ -0,0 +1,10 @@
+pub mod schema;
+pub mod spec;
+
+pub use spec::OpenApiSpec;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpecFormat {
+    Json,
+    Yaml,
+}
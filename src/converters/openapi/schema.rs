@@ -0,0 +1,117 @@
+use serde_json::{json, Map, Value};
+
+pub fn infer(value: &Value) -> Value {
+    match value {
+        Value::Null => json!({"type": "null"}),
+        Value::Bool(_) => json!({"type": "boolean"}),
+        Value::Number(number) if number.is_f64() => json!({"type": "number"}),
+        Value::Number(_) => json!({"type": "integer"}),
+        Value::String(_) => json!({"type": "string"}),
+        Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(infer)
+                .reduce(|merged, schema| merge(&merged, &schema))
+                .unwrap_or_else(|| json!({}));
+            json!({"type": "array", "items": items})
+        }
+        Value::Object(object) => {
+            let properties: Map<String, Value> = object
+                .iter()
+                .map(|(key, value)| (key.clone(), infer(value)))
+                .collect();
+            let required: Vec<&String> = object.keys().collect();
+            json!({"type": "object", "properties": properties, "required": required})
+        }
+    }
+}
+
+pub fn scalar(value: &str) -> Value {
+    if let Ok(integer) = value.parse::<i64>() {
+        return Value::from(integer);
+    }
+    if let Some(number) = value
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+    {
+        return Value::Number(number);
+    }
+    match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::String(value.to_string()),
+    }
+}
+
+pub fn infer_scalar(value: &str) -> Value {
+    infer(&scalar(value))
+}
+
+pub fn merge(left: &Value, right: &Value) -> Value {
+    if left == right {
+        return left.clone();
+    }
+
+    match (schema_type(left), schema_type(right)) {
+        (Some("object"), Some("object")) => {
+            let mut properties = left["properties"].as_object().cloned().unwrap_or_default();
+            if let Some(right_properties) = right["properties"].as_object() {
+                for (key, schema) in right_properties {
+                    let merged = match properties.get(key) {
+                        Some(existing) => merge(existing, schema),
+                        None => schema.clone(),
+                    };
+                    properties.insert(key.clone(), merged);
+                }
+            }
+
+            let right_required = required(right);
+            let required: Vec<String> = required(left)
+                .into_iter()
+                .filter(|key| right_required.contains(key))
+                .collect();
+
+            json!({"type": "object", "properties": properties, "required": required})
+        }
+        (Some("array"), Some("array")) => {
+            json!({"type": "array", "items": merge(&left["items"], &right["items"])})
+        }
+        (Some("integer"), Some("number")) | (Some("number"), Some("integer")) => {
+            json!({"type": "number"})
+        }
+        _ => {
+            let mut types: Vec<Value> = Vec::new();
+            for schema in [left, right] {
+                match &schema["type"] {
+                    Value::Array(values) => types.extend(values.iter().cloned()),
+                    Value::String(_) => types.push(schema["type"].clone()),
+                    _ => return json!({}),
+                }
+            }
+            let mut unique: Vec<Value> = Vec::new();
+            for value in types {
+                if !unique.contains(&value) {
+                    unique.push(value);
+                }
+            }
+            json!({"type": unique})
+        }
+    }
+}
+
+fn schema_type(schema: &Value) -> Option<&str> {
+    schema.get("type").and_then(Value::as_str)
+}
+
+fn required(schema: &Value) -> Vec<String> {
+    schema["required"]
+        .as_array()
+        .map(|keys| {
+            keys.iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
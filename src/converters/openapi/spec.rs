@@ -0,0 +1,522 @@
+use crate::converters::openapi::{schema, SpecFormat};
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::{AuthType, CurlCommand};
+use serde_json::{json, Map, Value};
+use url::Url;
+
+const RESERVED_HEADERS: [&str; 4] = ["accept", "authorization", "content-type", "cookie"];
+
+pub struct OpenApiSpec {
+    pub format: SpecFormat,
+}
+
+struct Observed<'a> {
+    curl_cmd: &'a CurlCommand,
+    url: Url,
+    segments: Vec<String>,
+    variable: Vec<bool>,
+}
+
+impl Convert for OpenApiSpec {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if Url::parse(&curl_cmd.url).is_err() {
+            warnings.push(format!(
+                "{} is not an absolute URL, request left out of the OpenAPI document",
+                curl_cmd.url
+            ));
+        }
+
+        let is_json = self
+            .media_type(curl_cmd)
+            .is_some_and(|media_type| media_type.contains("json"));
+        if let Some(data) = curl_cmd.data.as_deref().filter(|_| is_json) {
+            if serde_json::from_str::<Value>(data).is_err() {
+                warnings.push(
+                    "JSON request body could not be parsed, described as a string schema"
+                        .to_string(),
+                );
+            }
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for OpenApiSpec {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let document = self.document(curl_cmds);
+        match self.format {
+            SpecFormat::Json => serde_json::to_string_pretty(&document).unwrap_or_default(),
+            SpecFormat::Yaml => serde_yaml_ng::to_string(&document).unwrap_or_default(),
+        }
+    }
+}
+
+impl OpenApiSpec {
+    pub fn document(&self, curl_cmds: &[CurlCommand]) -> Value {
+        let mut observed: Vec<Observed> = curl_cmds
+            .iter()
+            .filter_map(|curl_cmd| {
+                let url = Url::parse(&curl_cmd.url).ok()?;
+                let segments: Vec<String> = url
+                    .path_segments()
+                    .map(|segments| segments.map(str::to_string).collect())
+                    .unwrap_or_default();
+                let variable = segments.iter().map(|s| is_identifier(s)).collect();
+                Some(Observed {
+                    curl_cmd,
+                    url,
+                    segments,
+                    variable,
+                })
+            })
+            .collect();
+        self.template_varying_segments(&mut observed);
+
+        let mut servers: Vec<String> = Vec::new();
+        let mut operations: Vec<(String, String, Vec<&Observed>)> = Vec::new();
+        for entry in &observed {
+            let server = entry.url.origin().ascii_serialization();
+            if !servers.contains(&server) {
+                servers.push(server);
+            }
+
+            let path = self.path_template(entry);
+            let method = entry.curl_cmd.method.to_lowercase();
+            match operations.iter_mut().find(|(known_path, known_method, _)| {
+                *known_path == path && *known_method == method
+            }) {
+                Some((_, _, entries)) => entries.push(entry),
+                None => operations.push((path, method, vec![entry])),
+            }
+        }
+
+        let mut security_schemes = Map::new();
+        let mut paths = Map::new();
+        for (path, method, entries) in &operations {
+            let operation = self.operation(path, method, entries, &mut security_schemes);
+            let item = paths
+                .entry(path.clone())
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Value::Object(item) = item {
+                item.insert(method.clone(), operation);
+            }
+        }
+
+        let title = match curl_cmds.first() {
+            Some(curl_cmd) => format!("{} API", utils::host(curl_cmd)),
+            None => "Inferred API".to_string(),
+        };
+        let servers: Vec<Value> = servers.iter().map(|url| json!({"url": url})).collect();
+
+        let mut document = json!({
+            "openapi": "3.1.0",
+            "info": {"title": title, "version": "1.0.0"},
+            "servers": servers,
+            "paths": paths,
+        });
+        if !security_schemes.is_empty() {
+            document["components"] = json!({"securitySchemes": security_schemes});
+        }
+        document
+    }
+
+    fn template_varying_segments(&self, observed: &mut [Observed]) {
+        loop {
+            let mut changed = false;
+            for a in 0..observed.len() {
+                for b in a + 1..observed.len() {
+                    let (left, right) = (&observed[a], &observed[b]);
+                    if left.segments.len() != right.segments.len() || left.segments.len() < 2 {
+                        continue;
+                    }
+
+                    let differing: Vec<usize> = (0..left.segments.len())
+                        .filter(|&i| {
+                            !(left.variable[i] && right.variable[i])
+                                && left.segments[i] != right.segments[i]
+                        })
+                        .collect();
+                    if let [position] = differing[..] {
+                        observed[a].variable[position] = true;
+                        observed[b].variable[position] = true;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    fn path_parameters(&self, entry: &Observed) -> Vec<(usize, String)> {
+        let mut names: Vec<(usize, String)> = Vec::new();
+        for index in 0..entry.segments.len() {
+            if !entry.variable[index] {
+                continue;
+            }
+
+            let previous = index
+                .checked_sub(1)
+                .filter(|&previous| !entry.variable[previous])
+                .map(|previous| entry.segments[previous].as_str());
+            let mut name = match (names.is_empty(), previous) {
+                (true, _) => "id".to_string(),
+                (false, Some(previous)) if !previous.is_empty() => {
+                    let singular = previous.strip_suffix('s').unwrap_or(previous);
+                    format!("{}Id", camel_case(singular, false))
+                }
+                _ => format!("id{}", names.len() + 1),
+            };
+            if names.iter().any(|(_, known)| *known == name) {
+                name = format!("id{}", names.len() + 1);
+            }
+            names.push((index, name));
+        }
+        names
+    }
+
+    fn path_template(&self, entry: &Observed) -> String {
+        let parameters = self.path_parameters(entry);
+        let segments: Vec<String> = entry
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                match parameters.iter().find(|(position, _)| *position == index) {
+                    Some((_, name)) => format!("{{{}}}", name),
+                    None => segment.clone(),
+                }
+            })
+            .collect();
+        format!("/{}", segments.join("/"))
+    }
+
+    fn operation(
+        &self,
+        path: &str,
+        method: &str,
+        entries: &[&Observed],
+        security_schemes: &mut Map<String, Value>,
+    ) -> Value {
+        let mut operation = json!({
+            "operationId": operation_id(method, path),
+            "summary": format!("{} {}", method.to_uppercase(), path),
+        });
+
+        let parameters = self.parameters(entries);
+        if !parameters.is_empty() {
+            operation["parameters"] = Value::Array(parameters);
+        }
+
+        let mut security: Vec<Value> = Vec::new();
+        for entry in entries {
+            if let Some((name, scheme)) = self.security_scheme(entry.curl_cmd) {
+                security_schemes.insert(name.to_string(), scheme);
+                let requirement = json!({ name: [] });
+                if !security.contains(&requirement) {
+                    security.push(requirement);
+                }
+            }
+        }
+        if !security.is_empty() {
+            operation["security"] = Value::Array(security);
+        }
+
+        if let Some(request_body) = self.request_body(entries) {
+            operation["requestBody"] = request_body;
+        }
+
+        operation["responses"] = json!({
+            "default": {"description": "Response not captured by the curl command"}
+        });
+        operation
+    }
+
+    fn parameters(&self, entries: &[&Observed]) -> Vec<Value> {
+        let mut parameters: Vec<(String, &str, Value, String, usize)> = Vec::new();
+        let mut observe = |name: &str, location: &'static str, value: &str| {
+            let existing = parameters.iter_mut().find(|(known, known_location, ..)| {
+                *known_location == location
+                    && (known == name || (location == "header" && known.eq_ignore_ascii_case(name)))
+            });
+            match existing {
+                Some((_, _, schema, _, count)) => {
+                    *schema = schema::merge(schema, &schema::infer_scalar(value));
+                    *count += 1;
+                }
+                None => parameters.push((
+                    name.to_string(),
+                    location,
+                    schema::infer_scalar(value),
+                    value.to_string(),
+                    1,
+                )),
+            }
+        };
+
+        for entry in entries {
+            for (index, name) in self.path_parameters(entry) {
+                observe(&name, "path", &entry.segments[index]);
+            }
+
+            let mut query: Vec<String> = Vec::new();
+            for (name, value) in entry.url.query_pairs() {
+                if !query.contains(&name.to_string()) {
+                    observe(&name, "query", &value);
+                    query.push(name.to_string());
+                }
+            }
+
+            let mut headers: Vec<_> = entry.curl_cmd.headers.iter().collect();
+            headers.sort();
+            for (name, value) in headers {
+                if !RESERVED_HEADERS.contains(&name.to_lowercase().as_str()) {
+                    observe(name, "header", value);
+                }
+            }
+
+            let mut cookies: Vec<_> = entry.curl_cmd.cookies.iter().collect();
+            cookies.sort();
+            for (name, value) in cookies {
+                observe(name, "cookie", value);
+            }
+        }
+
+        parameters
+            .into_iter()
+            .map(|(name, location, schema, example, count)| {
+                json!({
+                    "name": name,
+                    "in": location,
+                    "required": location == "path" || count == entries.len(),
+                    "schema": schema,
+                    "example": schema::scalar(&example),
+                })
+            })
+            .collect()
+    }
+
+    fn security_scheme(&self, curl_cmd: &CurlCommand) -> Option<(&'static str, Value)> {
+        let authorization = curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("authorization"))
+            .map(|(_, value)| value.to_lowercase());
+        let is_bearer = curl_cmd.oauth2_bearer.is_some()
+            || authorization
+                .as_deref()
+                .is_some_and(|value| value.starts_with("bearer "));
+
+        let (name, scheme) = if is_bearer {
+            ("bearerAuth", "bearer")
+        } else if curl_cmd.auth.is_some() {
+            match curl_cmd.auth_type {
+                None | Some(AuthType::Basic) => ("basicAuth", "basic"),
+                Some(AuthType::Digest) => ("digestAuth", "digest"),
+                Some(AuthType::Ntlm) => ("ntlmAuth", "ntlm"),
+                Some(AuthType::Negotiate) => ("negotiateAuth", "negotiate"),
+            }
+        } else if authorization
+            .as_deref()
+            .is_some_and(|value| value.starts_with("basic "))
+        {
+            ("basicAuth", "basic")
+        } else {
+            return None;
+        };
+
+        Some((name, json!({"type": "http", "scheme": scheme})))
+    }
+
+    fn request_body(&self, entries: &[&Observed]) -> Option<Value> {
+        let mut content = Map::new();
+        let mut with_body = 0;
+        for entry in entries {
+            let Some((media_type, body_schema, example)) = self.body_schema(entry.curl_cmd) else {
+                continue;
+            };
+            with_body += 1;
+
+            match content.get_mut(&media_type) {
+                Some(media) => media["schema"] = schema::merge(&media["schema"], &body_schema),
+                None => {
+                    let mut media = json!({"schema": body_schema});
+                    if let Some(example) = example {
+                        media["example"] = example;
+                    }
+                    content.insert(media_type, media);
+                }
+            }
+        }
+
+        if content.is_empty() {
+            return None;
+        }
+        Some(json!({"required": with_body == entries.len(), "content": content}))
+    }
+
+    fn body_schema(&self, curl_cmd: &CurlCommand) -> Option<(String, Value, Option<Value>)> {
+        let media_type = self.media_type(curl_cmd);
+
+        if let Some(data) = &curl_cmd.data {
+            let media_type = media_type.unwrap_or_else(|| {
+                if curl_cmd.data_is_json {
+                    "application/json".to_string()
+                } else {
+                    "application/x-www-form-urlencoded".to_string()
+                }
+            });
+
+            if media_type.contains("json") {
+                if let Ok(value) = serde_json::from_str::<Value>(data) {
+                    return Some((media_type, schema::infer(&value), Some(value)));
+                }
+            } else if media_type == "application/x-www-form-urlencoded" {
+                let pairs: Vec<(String, String)> = url::form_urlencoded::parse(data.as_bytes())
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect();
+                return Some((media_type, form_schema(&pairs), None));
+            }
+
+            Some((
+                media_type,
+                json!({"type": "string"}),
+                Some(Value::String(data.clone())),
+            ))
+        } else if curl_cmd.binary_file.is_some() {
+            let media_type = media_type.unwrap_or_else(|| "application/octet-stream".to_string());
+            let body_schema = json!({"type": "string", "contentMediaType": media_type});
+            Some((media_type, body_schema, None))
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut properties = Map::new();
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (name, _) in fields {
+                properties.insert(name.clone(), json!({"type": "string"}));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (name, file_upload) in files {
+                let content_type = file_upload
+                    .content_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream");
+                properties.insert(
+                    name.clone(),
+                    json!({"type": "string", "contentMediaType": content_type}),
+                );
+            }
+
+            let required: Vec<String> = properties.keys().cloned().collect();
+            Some((
+                "multipart/form-data".to_string(),
+                json!({"type": "object", "properties": properties, "required": required}),
+                None,
+            ))
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut pairs: Vec<(String, String)> = curl_cmd
+                .url_encoded_params
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            pairs.sort();
+            Some((
+                "application/x-www-form-urlencoded".to_string(),
+                form_schema(&pairs),
+                None,
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn media_type(&self, curl_cmd: &CurlCommand) -> Option<String> {
+        curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| {
+                value
+                    .split(';')
+                    .next()
+                    .unwrap_or(value)
+                    .trim()
+                    .to_lowercase()
+            })
+    }
+}
+
+fn form_schema(pairs: &[(String, String)]) -> Value {
+    let mut properties = Map::new();
+    for (key, value) in pairs {
+        let property = match properties.get(key) {
+            Some(existing) => schema::merge(existing, &schema::infer_scalar(value)),
+            None => schema::infer_scalar(value),
+        };
+        properties.insert(key.clone(), property);
+    }
+    let required: Vec<String> = properties.keys().cloned().collect();
+    json!({"type": "object", "properties": properties, "required": required})
+}
+
+fn is_identifier(segment: &str) -> bool {
+    let has_digit = segment.chars().any(|c| c.is_ascii_digit());
+    let is_uuid = segment.len() == 36
+        && segment.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        });
+    let is_token = segment.len() >= 12
+        && has_digit
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    (!segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit())) || is_uuid || is_token
+}
+
+fn camel_case(s: &str, capitalize_first: bool) -> String {
+    let mut output = String::new();
+    let mut capitalize = capitalize_first;
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            if capitalize {
+                output.push(c.to_ascii_uppercase());
+            } else if output.is_empty() {
+                output.push(c.to_ascii_lowercase());
+            } else {
+                output.push(c);
+            }
+            capitalize = false;
+        } else {
+            capitalize = true;
+        }
+    }
+    output
+}
+
+fn operation_id(method: &str, path: &str) -> String {
+    let mut operation_id = method.to_lowercase();
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        match segment
+            .strip_prefix('{')
+            .and_then(|segment| segment.strip_suffix('}'))
+        {
+            Some(name) => {
+                operation_id.push_str("By");
+                operation_id.push_str(&camel_case(name, true));
+            }
+            None => operation_id.push_str(&camel_case(segment, true)),
+        }
+    }
+    operation_id
+}
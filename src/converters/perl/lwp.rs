@@ -0,0 +1,302 @@
+use crate::converters::perl::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+use url::Url;
+
+pub struct Lwp;
+
+impl Convert for Lwp {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let has_form = !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty();
+        let uses_common = curl_cmd.data.is_none()
+            && curl_cmd.binary_file.is_none()
+            && (has_form || utils::has_url_encoded_body(curl_cmd));
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        output.push_str("use strict;\n");
+        output.push_str("use warnings;\n");
+        output.push_str("use LWP::UserAgent;\n");
+        if uses_common {
+            output.push_str("use HTTP::Request::Common qw(POST);\n");
+        } else {
+            output.push_str("use HTTP::Request;\n");
+        }
+        output.push('\n');
+
+        let user_agent_options = self.user_agent_options(curl_cmd);
+        if user_agent_options.is_empty() {
+            output.push_str("my $ua = LWP::UserAgent->new;\n");
+        } else {
+            output.push_str("my $ua = LWP::UserAgent->new(\n");
+            for option in &user_agent_options {
+                output.push_str(&format!("    {},\n", option));
+            }
+            output.push_str(");\n");
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let proxy_url = match &curl_cmd.proxy_auth {
+                Some((username, password)) => {
+                    let (scheme, host, port) = utils::split_proxy(proxy);
+                    format!("{}://{}:{}@{}:{}", scheme, username, password, host, port)
+                }
+                None => proxy.clone(),
+            };
+            output.push_str(&format!(
+                "$ua->proxy(['http', 'https'], {});\n",
+                string_literal(&proxy_url)
+            ));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Digest) | Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            ) {
+                output.push_str(&format!(
+                    "$ua->credentials({}, '', {}, {});\n",
+                    string_literal(&self.netloc(&curl_cmd.url)),
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+        output.push('\n');
+
+        if uses_common {
+            output.push_str(&format!(
+                "my $req = POST {},\n",
+                string_literal(&curl_cmd.url)
+            ));
+            if has_form {
+                output.push_str("    Content_Type => 'form-data',\n");
+            }
+            output.push_str("    Content => [\n");
+
+            if has_form {
+                let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+                fields.sort();
+                for (key, value) in fields {
+                    output.push_str(&format!(
+                        "        {} => {},\n",
+                        string_literal(key),
+                        string_literal(value)
+                    ));
+                }
+
+                let mut files: Vec<_> = curl_cmd.files.iter().collect();
+                files.sort_by(|a, b| a.0.cmp(b.0));
+                for (key, file_upload) in files {
+                    let filename = file_upload
+                        .filename
+                        .as_deref()
+                        .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                    let mut parts =
+                        vec![string_literal(&file_upload.path), string_literal(filename)];
+                    if let Some(content_type) = &file_upload.content_type {
+                        parts.push(format!(
+                            "'Content-Type' => {}",
+                            string_literal(content_type)
+                        ));
+                    }
+                    output.push_str(&format!(
+                        "        {} => [{}],\n",
+                        string_literal(key),
+                        parts.join(", ")
+                    ));
+                }
+            } else {
+                let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+                params.sort();
+                for (key, value) in params {
+                    output.push_str(&format!(
+                        "        {} => {},\n",
+                        string_literal(key),
+                        string_literal(value)
+                    ));
+                }
+            }
+
+            output.push_str("    ];\n");
+            if curl_cmd.method != "POST" {
+                output.push_str(&format!(
+                    "$req->method({});\n",
+                    string_literal(&curl_cmd.method)
+                ));
+            }
+        } else {
+            output.push_str(&format!(
+                "my $req = HTTP::Request->new({} => {});\n",
+                string_literal(&curl_cmd.method),
+                string_literal(&curl_cmd.url)
+            ));
+        }
+
+        let mut headers = utils::request_headers(curl_cmd);
+        headers.retain(|(key, _)| !(curl_cmd.user_agent.is_some() && key == "User-Agent"));
+        let has_content_type = headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+        if curl_cmd.data.is_some() && !has_content_type {
+            headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+        for (key, value) in &headers {
+            output.push_str(&format!(
+                "$req->header({} => {});\n",
+                string_literal(key),
+                string_literal(value)
+            ));
+        }
+
+        if curl_cmd.compressed {
+            output.push_str(
+                "$req->header('Accept-Encoding' => scalar HTTP::Message::decodable());\n",
+            );
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                output.push_str(&format!(
+                    "$req->authorization_basic({}, {});\n",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            output.push_str(&format!("$req->content({});\n", string_literal(data)));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            output.push_str(&format!(
+                "$req->content(do {{ local $/; open my $fh, '<:raw', {} or die $!; <$fh> }});\n",
+                string_literal(binary_file)
+            ));
+        }
+
+        output.push('\n');
+        match &curl_cmd.output {
+            Some(output_file) => output.push_str(&format!(
+                "my $res = $ua->request($req, {});\n",
+                string_literal(output_file)
+            )),
+            None => output.push_str("my $res = $ua->request($req);\n"),
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("print $res->code, \"\\n\";\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("print $res->decoded_content;\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() {
+            match curl_cmd.auth_type {
+                Some(AuthType::Digest) => warnings.push(
+                    "LWP matches Digest credentials by realm, replace '' with the server's realm"
+                        .to_string(),
+                ),
+                Some(AuthType::Ntlm) => warnings
+                    .push("NTLM authentication requires the LWP::Authen::Ntlm module".to_string()),
+                Some(AuthType::Negotiate) => warnings.push(
+                    "Negotiate authentication requires the LWP::Authen::Negotiate module"
+                        .to_string(),
+                ),
+                _ => {}
+            }
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, _, _) = utils::split_proxy(proxy);
+            if scheme.starts_with("socks") {
+                warnings.push("SOCKS proxies require the LWP::Protocol::socks module".to_string());
+            }
+        }
+
+        if curl_cmd.connect_timeout.is_some() || curl_cmd.max_time.is_some() {
+            warnings.push(
+                "LWP::UserAgent has a single inactivity timeout, curl timeouts mapped to timeout"
+                    .to_string(),
+            );
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "LWP::UserAgent has no retry option, call $ua->request in a loop to retry {} times",
+                retry
+            ));
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("LWP::UserAgent does not negotiate ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl Lwp {
+    fn user_agent_options(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut options = Vec::new();
+
+        if let Some(user_agent) = &curl_cmd.user_agent {
+            options.push(format!("agent => {}", string_literal(user_agent)));
+        }
+
+        let mut ssl_opts = Vec::new();
+        if curl_cmd.insecure {
+            ssl_opts.push("verify_hostname => 0".to_string());
+            ssl_opts.push("SSL_verify_mode => 0".to_string());
+        }
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            ssl_opts.push(format!("SSL_ca_file => {}", string_literal(cacert)));
+        }
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            ssl_opts.push(format!("SSL_cert_file => {}", string_literal(cert)));
+        }
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            ssl_opts.push(format!("SSL_key_file => {}", string_literal(key)));
+        }
+        if !ssl_opts.is_empty() {
+            options.push(format!("ssl_opts => {{ {} }}", ssl_opts.join(", ")));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time.or(curl_cmd.connect_timeout) {
+            options.push(format!("timeout => {}", timeout));
+        }
+
+        if curl_cmd.location {
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                options.push(format!("max_redirect => {}", max_redirs));
+            }
+        } else {
+            options.push("max_redirect => 0".to_string());
+        }
+
+        options
+    }
+
+    fn netloc(&self, url: &str) -> String {
+        match Url::parse(url) {
+            Ok(url) => format!(
+                "{}:{}",
+                url.host_str().unwrap_or_default(),
+                url.port_or_known_default().unwrap_or(80)
+            ),
+            Err(_) => url.to_string(),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod lwp;
+
+pub use lwp::Lwp;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
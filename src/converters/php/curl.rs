@@ -0,0 +1,240 @@
+use crate::converters::php::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Curl;
+
+impl Convert for Curl {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+        let mut options = Vec::new();
+
+        output.push_str("<?php\n\n");
+        output.push_str(&utils::warning_comments("//", &self.warnings(curl_cmd)));
+        output.push_str("$ch = curl_init();\n\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            output.push_str(&format!(
+                "$fp = fopen({}, 'wb');\n\n",
+                string_literal(output_file)
+            ));
+        }
+
+        options.push(("CURLOPT_URL", string_literal(&curl_cmd.url)));
+
+        if curl_cmd.output.is_some() {
+            options.push(("CURLOPT_FILE", "$fp".to_string()));
+        } else {
+            options.push(("CURLOPT_RETURNTRANSFER", "true".to_string()));
+        }
+
+        let has_body = curl_cmd.data.is_some()
+            || curl_cmd.binary_file.is_some()
+            || !curl_cmd.form.is_empty()
+            || !curl_cmd.files.is_empty()
+            || utils::has_url_encoded_body(curl_cmd);
+
+        match curl_cmd.method.as_str() {
+            "GET" => {}
+            "HEAD" => options.push(("CURLOPT_NOBODY", "true".to_string())),
+            "POST" if has_body => {}
+            "POST" => options.push(("CURLOPT_POST", "true".to_string())),
+            method => options.push(("CURLOPT_CUSTOMREQUEST", string_literal(method))),
+        }
+
+        let headers: Vec<_> = curl_cmd
+            .headers
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key, value))
+            .collect();
+        if !headers.is_empty() {
+            let mut headers = headers;
+            headers.sort();
+            let mut header_array = String::from("[\n");
+            for header in headers {
+                header_array.push_str(&format!("        {},\n", string_literal(&header)));
+            }
+            header_array.push_str("    ]");
+            options.push(("CURLOPT_HTTPHEADER", header_array));
+        }
+
+        if let Some(user_agent) = &curl_cmd.user_agent {
+            options.push(("CURLOPT_USERAGENT", string_literal(user_agent)));
+        }
+
+        if !curl_cmd.cookies.is_empty() {
+            options.push((
+                "CURLOPT_COOKIE",
+                string_literal(&utils::cookie_header(&curl_cmd.cookies)),
+            ));
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            options.push(("CURLOPT_POSTFIELDS", string_literal(data)));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            options.push((
+                "CURLOPT_POSTFIELDS",
+                format!("file_get_contents({})", string_literal(binary_file)),
+            ));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut fields_array = String::from("[\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                fields_array.push_str(&format!(
+                    "        {} => {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let content_type = file_upload
+                    .content_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream");
+                fields_array.push_str(&format!(
+                    "        {} => new CURLFile({}, {}, {}),\n",
+                    string_literal(key),
+                    string_literal(&file_upload.path),
+                    string_literal(content_type),
+                    string_literal(filename)
+                ));
+            }
+
+            fields_array.push_str("    ]");
+            options.push(("CURLOPT_POSTFIELDS", fields_array));
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            options.push((
+                "CURLOPT_POSTFIELDS",
+                string_literal(&utils::url_encoded_body(&curl_cmd.url_encoded_params)),
+            ));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            options.push((
+                "CURLOPT_USERPWD",
+                string_literal(&format!("{}:{}", username, password)),
+            ));
+
+            let auth_constant = match curl_cmd.auth_type {
+                Some(AuthType::Digest) => "CURLAUTH_DIGEST",
+                Some(AuthType::Ntlm) => "CURLAUTH_NTLM",
+                Some(AuthType::Negotiate) => "CURLAUTH_NEGOTIATE",
+                _ => "CURLAUTH_BASIC",
+            };
+            options.push(("CURLOPT_HTTPAUTH", auth_constant.to_string()));
+        }
+
+        if curl_cmd.insecure {
+            options.push(("CURLOPT_SSL_VERIFYPEER", "false".to_string()));
+            options.push(("CURLOPT_SSL_VERIFYHOST", "0".to_string()));
+        }
+
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            options.push(("CURLOPT_CAINFO", string_literal(cacert)));
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            options.push(("CURLOPT_SSLCERT", string_literal(cert)));
+        }
+
+        if let Some(cert_type) = &curl_cmd.ssl_options.cert_type {
+            options.push(("CURLOPT_SSLCERTTYPE", string_literal(cert_type)));
+        }
+
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            options.push(("CURLOPT_SSLKEY", string_literal(key)));
+        }
+
+        if let Some(key_type) = &curl_cmd.ssl_options.key_type {
+            options.push(("CURLOPT_SSLKEYTYPE", string_literal(key_type)));
+        }
+
+        if curl_cmd.compressed {
+            options.push(("CURLOPT_ENCODING", "''".to_string()));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            options.push(("CURLOPT_PROXY", string_literal(proxy)));
+        }
+
+        if let Some((username, password)) = &curl_cmd.proxy_auth {
+            options.push((
+                "CURLOPT_PROXYUSERPWD",
+                string_literal(&format!("{}:{}", username, password)),
+            ));
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            options.push(("CURLOPT_CONNECTTIMEOUT", timeout.to_string()));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            options.push(("CURLOPT_TIMEOUT", timeout.to_string()));
+        }
+
+        if curl_cmd.location {
+            options.push(("CURLOPT_FOLLOWLOCATION", "true".to_string()));
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                options.push(("CURLOPT_MAXREDIRS", max_redirs.to_string()));
+            }
+        }
+
+        if curl_cmd.no_alpn {
+            options.push(("CURLOPT_SSL_ENABLE_ALPN", "false".to_string()));
+        }
+
+        output.push_str("curl_setopt_array($ch, [\n");
+        for (option, value) in &options {
+            output.push_str(&format!("    {} => {},\n", option, value));
+        }
+        output.push_str("]);\n\n");
+
+        output.push_str("$response = curl_exec($ch);\n\n");
+        output.push_str("if ($response === false) {\n");
+        output.push_str("    echo 'Error: ' . curl_error($ch);\n");
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("} else {\n");
+                output.push_str("    echo curl_getinfo($ch, CURLINFO_HTTP_CODE);\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("} else {\n");
+                    output.push_str("    echo $response;\n");
+                }
+            }
+        }
+
+        output.push_str("}\n\n");
+        output.push_str("curl_close($ch);\n");
+
+        if curl_cmd.output.is_some() {
+            output.push_str("fclose($fp);\n");
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "ext-curl has no retry option, call curl_exec in a loop to retry {} times",
+                retry
+            ));
+        }
+
+        warnings
+    }
+}
@@ -0,0 +1,218 @@
+use crate::converters::php::{string_literal, value_literal};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Guzzle;
+
+impl Convert for Guzzle {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+
+        output.push_str("<?php\n\n");
+        output.push_str(&utils::warning_comments("//", &self.warnings(curl_cmd)));
+        output.push_str("require 'vendor/autoload.php';\n\n");
+        output.push_str("use GuzzleHttp\\Client;\n\n");
+        output.push_str("$client = new Client();\n\n");
+
+        output.push_str(&format!(
+            "$response = $client->request({}, {}, [\n",
+            string_literal(&curl_cmd.method),
+            string_literal(&curl_cmd.url)
+        ));
+
+        let headers = utils::request_headers(curl_cmd);
+        if !headers.is_empty() {
+            output.push_str("    'headers' => [\n");
+            for (key, value) in &headers {
+                output.push_str(&format!(
+                    "        {} => {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+            output.push_str("    ],\n");
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            match serde_json::from_str::<serde_json::Value>(data) {
+                Ok(json) if curl_cmd.data_is_json || json.is_object() || json.is_array() => {
+                    output.push_str(&format!("    'json' => {},\n", value_literal(&json, 1)));
+                }
+                _ => {
+                    output.push_str(&format!("    'body' => {},\n", string_literal(data)));
+                }
+            }
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            output.push_str(&format!(
+                "    'body' => fopen({}, 'r'),\n",
+                string_literal(binary_file)
+            ));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            output.push_str("    'multipart' => [\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                output.push_str("        [\n");
+                output.push_str(&format!("            'name' => {},\n", string_literal(key)));
+                output.push_str(&format!(
+                    "            'contents' => {},\n",
+                    string_literal(value)
+                ));
+                output.push_str("        ],\n");
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                output.push_str("        [\n");
+                output.push_str(&format!("            'name' => {},\n", string_literal(key)));
+                output.push_str(&format!(
+                    "            'contents' => fopen({}, 'r'),\n",
+                    string_literal(&file_upload.path)
+                ));
+                output.push_str(&format!(
+                    "            'filename' => {},\n",
+                    string_literal(filename)
+                ));
+                if let Some(content_type) = &file_upload.content_type {
+                    output.push_str(&format!(
+                        "            'headers' => ['Content-Type' => {}],\n",
+                        string_literal(content_type)
+                    ));
+                }
+                output.push_str("        ],\n");
+            }
+
+            output.push_str("    ],\n");
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            output.push_str("    'form_params' => [\n");
+
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            for (key, value) in params {
+                output.push_str(&format!(
+                    "        {} => {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            output.push_str("    ],\n");
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            match curl_cmd.auth_type {
+                Some(AuthType::Digest) => output.push_str(&format!(
+                    "    'auth' => [{}, {}, 'digest'],\n",
+                    string_literal(username),
+                    string_literal(password)
+                )),
+                Some(AuthType::Ntlm) => output.push_str(&format!(
+                    "    'auth' => [{}, {}, 'ntlm'],\n",
+                    string_literal(username),
+                    string_literal(password)
+                )),
+                Some(AuthType::Negotiate) => {}
+                _ => output.push_str(&format!(
+                    "    'auth' => [{}, {}],\n",
+                    string_literal(username),
+                    string_literal(password)
+                )),
+            }
+        }
+
+        if curl_cmd.insecure {
+            output.push_str("    'verify' => false,\n");
+        } else if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            output.push_str(&format!("    'verify' => {},\n", string_literal(cacert)));
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            output.push_str(&format!("    'cert' => {},\n", string_literal(cert)));
+        }
+
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            output.push_str(&format!("    'ssl_key' => {},\n", string_literal(key)));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let proxy_url = match &curl_cmd.proxy_auth {
+                Some((username, password)) => {
+                    utils::proxy_with_credentials(proxy, username, password)
+                }
+                None => proxy.clone(),
+            };
+            output.push_str(&format!("    'proxy' => {},\n", string_literal(&proxy_url)));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            output.push_str(&format!("    'timeout' => {},\n", timeout));
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            output.push_str(&format!("    'connect_timeout' => {},\n", timeout));
+        }
+
+        if curl_cmd.location {
+            match curl_cmd.max_redirs {
+                Some(max_redirs) => output.push_str(&format!(
+                    "    'allow_redirects' => ['max' => {}],\n",
+                    max_redirs
+                )),
+                None => output.push_str("    'allow_redirects' => true,\n"),
+            }
+        } else {
+            output.push_str("    'allow_redirects' => false,\n");
+        }
+
+        if curl_cmd.compressed {
+            output.push_str("    'decode_content' => true,\n");
+        }
+
+        if curl_cmd.no_alpn {
+            output.push_str("    'curl' => [CURLOPT_SSL_ENABLE_ALPN => false],\n");
+        }
+
+        if let Some(output_file) = &curl_cmd.output {
+            output.push_str(&format!("    'sink' => {},\n", string_literal(output_file)));
+        }
+
+        output.push_str("    'http_errors' => false,\n");
+        output.push_str("]);\n\n");
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("echo $response->getStatusCode();\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("echo $response->getBody();\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Negotiate)) {
+            warnings.push("Negotiate authentication is not supported by Guzzle".to_string());
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "Guzzle needs a Middleware::retry handler to retry {} times",
+                retry
+            ));
+        }
+
+        warnings
+    }
+}
@@ -0,0 +1,50 @@
+pub mod curl;
+pub mod guzzle;
+
+pub use curl::Curl;
+pub use guzzle::Guzzle;
+
+use serde_json::Value;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+pub(crate) fn value_literal(value: &Value, indent: usize) -> String {
+    let padding = "    ".repeat(indent + 1);
+    let closing = "    ".repeat(indent);
+
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => string_literal(s),
+        Value::Array(items) if items.is_empty() => "[]".to_string(),
+        Value::Array(items) => {
+            let mut output = String::from("[\n");
+            for item in items {
+                output.push_str(&format!(
+                    "{}{},\n",
+                    padding,
+                    value_literal(item, indent + 1)
+                ));
+            }
+            output.push_str(&format!("{}]", closing));
+            output
+        }
+        Value::Object(map) if map.is_empty() => "new \\stdClass()".to_string(),
+        Value::Object(map) => {
+            let mut output = String::from("[\n");
+            for (key, item) in map {
+                output.push_str(&format!(
+                    "{}{} => {},\n",
+                    padding,
+                    string_literal(key),
+                    value_literal(item, indent + 1)
+                ));
+            }
+            output.push_str(&format!("{}]", closing));
+            output
+        }
+    }
+}
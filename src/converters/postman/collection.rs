@@ -0,0 +1,264 @@
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::postman::{
+    Auth, AuthAttribute, Body, BodyOptions, Collection, FileSource, FormParam, Header, Info, Item,
+    ProtocolProfileBehavior, QueryParam, RawOptions, Request, RequestUrl, UrlDetail, SCHEMA_V2_1,
+};
+use crate::{AuthType, CurlCommand};
+use serde_json::Value;
+use url::Url;
+
+pub struct PostmanCollection;
+
+impl Convert for PostmanCollection {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Negotiate)) {
+            warnings.push("Postman has no negotiate auth type, credentials omitted".to_string());
+        }
+
+        if curl_cmd.proxy.is_some() {
+            warnings.push("Postman proxies are configured in the app settings".to_string());
+        }
+
+        if curl_cmd.ssl_options.cert.is_some() || curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push("Postman certificates are configured in the app settings".to_string());
+        }
+
+        if curl_cmd.connect_timeout.is_some() || curl_cmd.max_time.is_some() {
+            warnings
+                .push("Postman request timeouts are configured in the app settings".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for PostmanCollection {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let collection = Collection {
+            info: Info {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                schema: SCHEMA_V2_1.to_string(),
+            },
+            item: curl_cmds
+                .iter()
+                .map(|curl_cmd| self.item(curl_cmd))
+                .collect(),
+            auth: None,
+            variable: Vec::new(),
+        };
+
+        serde_json::to_string_pretty(&collection).unwrap_or_default()
+    }
+}
+
+impl PostmanCollection {
+    fn item(&self, curl_cmd: &CurlCommand) -> Item {
+        let parsed_url = Url::parse(&curl_cmd.url).ok();
+
+        let header = utils::request_headers(curl_cmd)
+            .into_iter()
+            .filter(|(key, _)| {
+                curl_cmd.oauth2_bearer.is_none() || !key.eq_ignore_ascii_case("authorization")
+            })
+            .map(|(key, value)| Header {
+                key,
+                value,
+                disabled: false,
+            })
+            .collect();
+
+        let mut protocol_profile_behavior = ProtocolProfileBehavior {
+            follow_redirects: Some(curl_cmd.location),
+            ..ProtocolProfileBehavior::default()
+        };
+        if curl_cmd.location {
+            protocol_profile_behavior.max_redirects = curl_cmd.max_redirs;
+        }
+        if curl_cmd.insecure {
+            protocol_profile_behavior.strict_ssl = Some(false);
+        }
+
+        Item {
+            name: utils::request_name(curl_cmd),
+            request: Some(Request {
+                method: curl_cmd.method.clone(),
+                header,
+                url: RequestUrl::Detailed(self.url(curl_cmd, parsed_url.as_ref())),
+                auth: self.auth(curl_cmd),
+                body: self.body(curl_cmd),
+            }),
+            protocol_profile_behavior: Some(protocol_profile_behavior),
+            ..Item::default()
+        }
+    }
+
+    fn url(&self, curl_cmd: &CurlCommand, parsed_url: Option<&Url>) -> UrlDetail {
+        let Some(url) = parsed_url else {
+            return UrlDetail {
+                raw: curl_cmd.url.clone(),
+                ..UrlDetail::default()
+            };
+        };
+
+        UrlDetail {
+            raw: curl_cmd.url.clone(),
+            protocol: Some(url.scheme().to_string()),
+            host: url
+                .host_str()
+                .map(|host| host.split('.').map(str::to_string).collect())
+                .unwrap_or_default(),
+            port: url.port().map(|port| port.to_string()),
+            path: url
+                .path_segments()
+                .map(|segments| {
+                    segments
+                        .filter(|segment| !segment.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            query: url
+                .query_pairs()
+                .map(|(key, value)| QueryParam {
+                    key: key.into_owned(),
+                    value: Some(value.into_owned()),
+                    disabled: false,
+                })
+                .collect(),
+        }
+    }
+
+    fn auth(&self, curl_cmd: &CurlCommand) -> Option<Auth> {
+        let mut auth = Auth {
+            auth_type: String::new(),
+            basic: None,
+            digest: None,
+            ntlm: None,
+            bearer: None,
+        };
+
+        if let Some(token) = &curl_cmd.oauth2_bearer {
+            auth.auth_type = "bearer".to_string();
+            auth.bearer = Some(vec![self.attribute("token", token)]);
+            return Some(auth);
+        }
+
+        let (username, password) = curl_cmd.auth.as_ref()?;
+        let attributes = Some(vec![
+            self.attribute("username", username),
+            self.attribute("password", password),
+        ]);
+        match curl_cmd.auth_type {
+            None | Some(AuthType::Basic) => {
+                auth.auth_type = "basic".to_string();
+                auth.basic = attributes;
+            }
+            Some(AuthType::Digest) => {
+                auth.auth_type = "digest".to_string();
+                auth.digest = attributes;
+            }
+            Some(AuthType::Ntlm) => {
+                auth.auth_type = "ntlm".to_string();
+                auth.ntlm = attributes;
+            }
+            Some(AuthType::Negotiate) => return None,
+        }
+
+        Some(auth)
+    }
+
+    fn attribute(&self, key: &str, value: &str) -> AuthAttribute {
+        AuthAttribute {
+            key: key.to_string(),
+            value: Value::String(value.to_string()),
+            attribute_type: Some("string".to_string()),
+        }
+    }
+
+    fn body(&self, curl_cmd: &CurlCommand) -> Option<Body> {
+        let mut body = Body {
+            mode: String::new(),
+            raw: None,
+            urlencoded: None,
+            formdata: None,
+            file: None,
+            options: None,
+        };
+
+        if let Some(data) = &curl_cmd.data {
+            let is_json = curl_cmd.data_is_json
+                || curl_cmd.headers.iter().any(|(key, value)| {
+                    key.eq_ignore_ascii_case("content-type") && value.contains("json")
+                });
+            body.mode = "raw".to_string();
+            body.raw = Some(data.clone());
+            if is_json {
+                body.options = Some(BodyOptions {
+                    raw: Some(RawOptions {
+                        language: "json".to_string(),
+                    }),
+                });
+            }
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            body.mode = "file".to_string();
+            body.file = Some(FileSource {
+                src: Some(binary_file.clone()),
+            });
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            let mut formdata: Vec<FormParam> = fields
+                .into_iter()
+                .map(|(key, value)| self.text_param(key, value))
+                .collect();
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                formdata.push(FormParam {
+                    key: key.clone(),
+                    value: None,
+                    src: Value::String(file_upload.path.clone()),
+                    param_type: Some("file".to_string()),
+                    content_type: file_upload.content_type.clone(),
+                    disabled: false,
+                });
+            }
+
+            body.mode = "formdata".to_string();
+            body.formdata = Some(formdata);
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+
+            body.mode = "urlencoded".to_string();
+            body.urlencoded = Some(
+                params
+                    .into_iter()
+                    .map(|(key, value)| self.text_param(key, value))
+                    .collect(),
+            );
+        } else {
+            return None;
+        }
+
+        Some(body)
+    }
+
+    fn text_param(&self, key: &str, value: &str) -> FormParam {
+        FormParam {
+            key: key.to_string(),
+            value: Some(value.to_string()),
+            src: Value::Null,
+            param_type: Some("text".to_string()),
+            content_type: None,
+            disabled: false,
+        }
+    }
+}
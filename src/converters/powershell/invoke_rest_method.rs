@@ -0,0 +1,259 @@
+use crate::converters::powershell::{credential, string_literal};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct InvokeRestMethod;
+
+const STANDARD_METHODS: [&str; 8] = [
+    "GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS", "TRACE",
+];
+
+impl Convert for InvokeRestMethod {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+        let mut params = Vec::new();
+
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+
+        let method = curl_cmd.method.to_uppercase();
+        if STANDARD_METHODS.contains(&method.as_str()) {
+            params.push(format!("-Method {}", self.method_name(&method)));
+        } else {
+            params.push(format!(
+                "-CustomMethod {}",
+                string_literal(&curl_cmd.method)
+            ));
+        }
+
+        let headers: Vec<_> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .filter(|(key, _)| {
+                let is_user_agent = curl_cmd.user_agent.is_some() && key == "User-Agent";
+                !(key.eq_ignore_ascii_case("content-type") || is_user_agent)
+            })
+            .collect();
+        if !headers.is_empty() {
+            output.push_str("$headers = @{\n");
+            for (key, value) in &headers {
+                output.push_str(&format!(
+                    "    {} = {}\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+            output.push_str("}\n\n");
+            params.push("-Headers $headers".to_string());
+        }
+
+        if let Some(user_agent) = &curl_cmd.user_agent {
+            params.push(format!("-UserAgent {}", string_literal(user_agent)));
+        }
+
+        if let Some(content_type) = curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value)
+        {
+            params.push(format!("-ContentType {}", string_literal(content_type)));
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            output.push_str(&format!("$body = {}\n\n", string_literal(data)));
+            params.push("-Body $body".to_string());
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            params.push(format!("-InFile {}", string_literal(binary_file)));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            output.push_str("$form = @{\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                output.push_str(&format!(
+                    "    {} = {}\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                output.push_str(&format!(
+                    "    {} = Get-Item -Path {}\n",
+                    string_literal(key),
+                    string_literal(&file_upload.path)
+                ));
+            }
+
+            output.push_str("}\n\n");
+            params.push("-Form $form".to_string());
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            output.push_str("$body = @{\n");
+
+            let mut url_params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            url_params.sort();
+            for (key, value) in url_params {
+                output.push_str(&format!(
+                    "    {} = {}\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            output.push_str("}\n\n");
+            params.push("-Body $body".to_string());
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            output.push_str(&credential("$credential", username, password));
+            params.push("-Credential $credential".to_string());
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                params.push("-Authentication Basic".to_string());
+                if curl_cmd.url.starts_with("http://") {
+                    params.push("-AllowUnencryptedAuthentication".to_string());
+                }
+            }
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            params.push(format!("-Proxy {}", string_literal(proxy)));
+            if let Some((username, password)) = &curl_cmd.proxy_auth {
+                output.push_str(&credential("$proxyCredential", username, password));
+                params.push("-ProxyCredential $proxyCredential".to_string());
+            }
+        }
+
+        if curl_cmd.auth.is_some() || curl_cmd.proxy_auth.is_some() {
+            output.push('\n');
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            let is_p12 = curl_cmd
+                .ssl_options
+                .cert_type
+                .as_deref()
+                .is_some_and(|cert_type| cert_type.eq_ignore_ascii_case("p12"));
+            if is_p12 || curl_cmd.ssl_options.key.is_none() {
+                output.push_str(&format!(
+                    "$certificate = Get-PfxCertificate -FilePath {}\n\n",
+                    string_literal(cert)
+                ));
+            } else if let Some(key) = &curl_cmd.ssl_options.key {
+                output.push_str(&format!(
+                    "$certificate = [System.Security.Cryptography.X509Certificates.X509Certificate2]::CreateFromPemFile({}, {})\n\n",
+                    string_literal(cert),
+                    string_literal(key)
+                ));
+            }
+            params.push("-Certificate $certificate".to_string());
+        }
+
+        if curl_cmd.insecure {
+            params.push("-SkipCertificateCheck".to_string());
+        }
+
+        if curl_cmd.location {
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                params.push(format!("-MaximumRedirection {}", max_redirs));
+            }
+        } else {
+            params.push("-MaximumRedirection 0".to_string());
+        }
+
+        if let Some(timeout) = curl_cmd.max_time.or(curl_cmd.connect_timeout) {
+            params.push(format!("-TimeoutSec {}", timeout));
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            params.push(format!("-MaximumRetryCount {}", retry));
+        }
+
+        let wants_status_code = curl_cmd
+            .write_out
+            .as_deref()
+            .is_some_and(|write_out| write_out.contains("%{http_code}"));
+
+        if let Some(output_file) = &curl_cmd.output {
+            params.push(format!("-OutFile {}", string_literal(output_file)));
+            if wants_status_code {
+                params.push("-PassThru".to_string());
+            }
+        }
+
+        params.push("-SkipHttpErrorCheck".to_string());
+
+        let cmdlet = if wants_status_code {
+            "Invoke-WebRequest"
+        } else {
+            "Invoke-RestMethod"
+        };
+
+        output.push_str(&format!(
+            "$response = {} -Uri {}",
+            cmdlet,
+            string_literal(&curl_cmd.url)
+        ));
+        for param in &params {
+            output.push_str(&format!(" `\n    {}", param));
+        }
+        output.push_str("\n\n");
+
+        if wants_status_code {
+            output.push_str("$response.StatusCode\n");
+        } else if curl_cmd.output.is_none() {
+            output.push_str("$response\n");
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        let has_file_options = curl_cmd.files.values().any(|file_upload| {
+            file_upload.content_type.is_some() || file_upload.filename.is_some()
+        });
+        if has_file_options {
+            warnings.push(
+                "-Form uploads files with their own name and type, use MultipartFormDataContent to override them"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.connect_timeout.is_some() {
+            warnings.push(
+                "Invoke-RestMethod has a single timeout, --connect-timeout applied to -TimeoutSec"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push(
+                "custom CA certificates (--cacert) must be imported into the certificate store"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("Invoke-RestMethod does not expose ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl InvokeRestMethod {
+    fn method_name(&self, method: &str) -> &'static str {
+        match method {
+            "POST" => "Post",
+            "PUT" => "Put",
+            "DELETE" => "Delete",
+            "PATCH" => "Patch",
+            "HEAD" => "Head",
+            "OPTIONS" => "Options",
+            "TRACE" => "Trace",
+            _ => "Get",
+        }
+    }
+}
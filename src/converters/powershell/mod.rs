@@ -0,0 +1,16 @@
+pub mod invoke_rest_method;
+
+pub use invoke_rest_method::InvokeRestMethod;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+pub(crate) fn credential(variable: &str, username: &str, password: &str) -> String {
+    format!(
+        "{} = New-Object System.Management.Automation.PSCredential({}, (ConvertTo-SecureString {} -AsPlainText -Force))\n",
+        variable,
+        string_literal(username),
+        string_literal(password)
+    )
+}
@@ -0,0 +1,282 @@
+use crate::converters::{utils, Convert, ConvertMany};
+use crate::{AuthType, CurlCommand};
+use url::{Origin, Position, Url};
+
+pub struct Locust;
+
+impl Convert for Locust {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        self.convert_many(std::slice::from_ref(curl_cmd))
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some()
+            && matches!(
+                curl_cmd.auth_type,
+                Some(AuthType::Ntlm) | Some(AuthType::Negotiate)
+            )
+        {
+            warnings.push(
+                "Locust's client only supports basic and digest auth, credentials omitted"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.location && curl_cmd.max_redirs.is_some() {
+            warnings.push(
+                "Locust's client has no per-request redirect limit, --max-redirs ignored"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.retry.is_some() {
+            warnings.push("Locust does not retry requests, --retry ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl ConvertMany for Locust {
+    fn convert_many(&self, curl_cmds: &[CurlCommand]) -> String {
+        let mut output = String::new();
+        for curl_cmd in curl_cmds {
+            output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        }
+
+        output.push_str("from locust import HttpUser, between, task\n");
+        let uses_digest = curl_cmds.iter().any(|curl_cmd| {
+            curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Digest))
+        });
+        if uses_digest {
+            output.push_str("from requests.auth import HTTPDigestAuth\n");
+        }
+        output.push_str("\n\n");
+
+        let host = curl_cmds
+            .first()
+            .and_then(|curl_cmd| Url::parse(&curl_cmd.url).ok())
+            .map(|url| url.origin())
+            .filter(Origin::is_tuple);
+
+        output.push_str("class ApiUser(HttpUser):\n");
+        if let Some(host) = &host {
+            output.push_str(&format!(
+                "    host = \"{}\"\n",
+                utils::escape_string_literal(&host.ascii_serialization())
+            ));
+        }
+        output.push_str("    wait_time = between(1, 3)\n\n");
+        output.push_str("    @task\n");
+
+        let task_name = match curl_cmds {
+            [curl_cmd] => self.task_name(curl_cmd),
+            _ => "request_sequence".to_string(),
+        };
+        output.push_str(&format!("    def {}(self):\n", task_name));
+
+        if curl_cmds.is_empty() {
+            output.push_str("        pass\n");
+        }
+        let requests: Vec<String> = curl_cmds
+            .iter()
+            .map(|curl_cmd| self.request(curl_cmd, host.as_ref()))
+            .collect();
+        output.push_str(&requests.join("\n"));
+        output
+    }
+}
+
+impl Locust {
+    fn task_name(&self, curl_cmd: &CurlCommand) -> String {
+        let mut name = curl_cmd.method.to_lowercase();
+        let path = Url::parse(&curl_cmd.url)
+            .map(|url| url.path().to_string())
+            .unwrap_or_default();
+        for part in path.split(|c: char| !c.is_ascii_alphanumeric()) {
+            if !part.is_empty() {
+                name.push('_');
+                name.push_str(&part.to_lowercase());
+            }
+        }
+        name
+    }
+
+    fn target(&self, curl_cmd: &CurlCommand, host: Option<&Origin>) -> String {
+        match Url::parse(&curl_cmd.url) {
+            Ok(url) if host == Some(&url.origin()) => {
+                url[Position::BeforePath..Position::AfterQuery].to_string()
+            }
+            _ => curl_cmd.url.clone(),
+        }
+    }
+
+    fn request(&self, curl_cmd: &CurlCommand, host: Option<&Origin>) -> String {
+        let mut arguments = vec![
+            format!("\"{}\"", curl_cmd.method),
+            format!(
+                "\"{}\"",
+                utils::escape_string_literal(&self.target(curl_cmd, host))
+            ),
+            format!(
+                "name=\"{}\"",
+                utils::escape_string_literal(&utils::request_name(curl_cmd))
+            ),
+        ];
+
+        let mut headers: Vec<(String, String)> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .filter(|(key, _)| !key.eq_ignore_ascii_case("cookie"))
+            .collect();
+        let has_content_type = headers
+            .iter()
+            .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+        if curl_cmd.data.is_some() && !has_content_type {
+            headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+        if !headers.is_empty() {
+            arguments.push(format!("headers={}", dict(&headers)));
+        }
+
+        if !curl_cmd.cookies.is_empty() {
+            let mut cookies: Vec<(String, String)> = curl_cmd
+                .cookies
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            cookies.sort();
+            arguments.push(format!("cookies={}", dict(&cookies)));
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            arguments.push(format!("data=\"{}\"", utils::escape_string_literal(data)));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            arguments.push(format!(
+                "data=open(\"{}\", \"rb\").read()",
+                utils::escape_string_literal(binary_file)
+            ));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            if !curl_cmd.form.is_empty() {
+                let mut form: Vec<(String, String)> = curl_cmd
+                    .form
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                form.sort();
+                arguments.push(format!("data={}", dict(&form)));
+            }
+
+            if !curl_cmd.files.is_empty() {
+                let mut uploads: Vec<_> = curl_cmd.files.iter().collect();
+                uploads.sort_by(|a, b| a.0.cmp(b.0));
+                let entries: Vec<String> = uploads
+                    .into_iter()
+                    .map(|(key, file_upload)| {
+                        let filename = file_upload
+                            .filename
+                            .as_deref()
+                            .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                        let mut file = format!(
+                            "\"{}\", open(\"{}\", \"rb\")",
+                            utils::escape_string_literal(filename),
+                            utils::escape_string_literal(&file_upload.path)
+                        );
+                        if let Some(content_type) = &file_upload.content_type {
+                            file.push_str(&format!(
+                                ", \"{}\"",
+                                utils::escape_string_literal(content_type)
+                            ));
+                        }
+                        format!("\"{}\": ({})", utils::escape_string_literal(key), file)
+                    })
+                    .collect();
+                arguments.push(format!("files={{{}}}", entries.join(", ")));
+            }
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<(String, String)> = curl_cmd
+                .url_encoded_params
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            params.sort();
+            arguments.push(format!("data={}", dict(&params)));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            let credentials = format!(
+                "\"{}\", \"{}\"",
+                utils::escape_string_literal(username),
+                utils::escape_string_literal(password)
+            );
+            match curl_cmd.auth_type {
+                None | Some(AuthType::Basic) => arguments.push(format!("auth=({})", credentials)),
+                Some(AuthType::Digest) => {
+                    arguments.push(format!("auth=HTTPDigestAuth({})", credentials))
+                }
+                _ => {}
+            }
+        }
+
+        if curl_cmd.insecure {
+            arguments.push("verify=False".to_string());
+        } else if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            arguments.push(format!(
+                "verify=\"{}\"",
+                utils::escape_string_literal(cacert)
+            ));
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            match &curl_cmd.ssl_options.key {
+                Some(key) => arguments.push(format!(
+                    "cert=(\"{}\", \"{}\")",
+                    utils::escape_string_literal(cert),
+                    utils::escape_string_literal(key)
+                )),
+                None => arguments.push(format!("cert=\"{}\"", utils::escape_string_literal(cert))),
+            }
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let proxy = utils::escape_string_literal(proxy);
+            arguments.push(format!(
+                "proxies={{\"http\": \"{}\", \"https\": \"{}\"}}",
+                proxy, proxy
+            ));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time.or(curl_cmd.connect_timeout) {
+            arguments.push(format!("timeout={}", timeout));
+        }
+
+        if !curl_cmd.location {
+            arguments.push("allow_redirects=False".to_string());
+        }
+
+        let mut output = String::from("        self.client.request(\n");
+        for argument in arguments {
+            output.push_str(&format!("            {},\n", argument));
+        }
+        output.push_str("        )\n");
+        output
+    }
+}
+
+fn dict(pairs: &[(String, String)]) -> String {
+    let entries: Vec<String> = pairs
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "\"{}\": \"{}\"",
+                utils::escape_string_literal(key),
+                utils::escape_string_literal(value)
+            )
+        })
+        .collect();
+    format!("{{{}}}", entries.join(", "))
+}
@@ -1,3 +1,5 @@
+pub mod locust;
 pub mod requests;
 
+pub use locust::Locust;
 pub use requests::Requests;
@@ -245,7 +245,7 @@ impl Convert for Requests {
             } else {
                 params.push("data=data");
             }
-        } else if let Some(_) = &curl_cmd.binary_file {
+        } else if curl_cmd.binary_file.is_some() {
             params.push("data=binary_data");
         }
 
@@ -257,7 +257,7 @@ impl Convert for Requests {
             params.push("files=files");
         }
 
-        if let Some(_) = &curl_cmd.auth {
+        if curl_cmd.auth.is_some() {
             params.push("auth=auth");
         }
 
@@ -267,11 +267,11 @@ impl Convert for Requests {
 
         if curl_cmd.insecure {
             params.push("verify=False");
-        } else if let Some(_) = &curl_cmd.ssl_options.cacert {
+        } else if curl_cmd.ssl_options.cacert.is_some() {
             params.push("verify=verify");
         }
 
-        if let Some(_) = &curl_cmd.proxy {
+        if curl_cmd.proxy.is_some() {
             params.push("proxies=proxies");
         }
 
@@ -305,7 +305,7 @@ impl Convert for Requests {
 
             if let Some(max_redirs) = curl_cmd.max_redirs {
                 
-                output.push_str(&format!("\n# Custom session for redirect control\n"));
+                output.push_str("\n# Custom session for redirect control\n");
 
                 if curl_cmd.retry.is_none() {
                     
@@ -0,0 +1,238 @@
+use crate::converters::r::{argument_name, string_literal, value_literal};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Httr2;
+
+impl Convert for Httr2 {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut steps = Vec::new();
+
+        if curl_cmd.method != "GET" {
+            steps.push(format!("req_method({})", string_literal(&curl_cmd.method)));
+        }
+
+        let headers: Vec<String> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .filter(|(key, _)| {
+                let is_user_agent = curl_cmd.user_agent.is_some() && key == "User-Agent";
+                !(key.eq_ignore_ascii_case("content-type") || is_user_agent)
+            })
+            .map(|(key, value)| format!("{} = {}", argument_name(&key), string_literal(&value)))
+            .collect();
+        if !headers.is_empty() {
+            steps.push(format!(
+                "req_headers(\n    {}\n  )",
+                headers.join(",\n    ")
+            ));
+        }
+
+        if let Some(user_agent) = &curl_cmd.user_agent {
+            steps.push(format!("req_user_agent({})", string_literal(user_agent)));
+        }
+
+        let content_type = curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.as_str());
+
+        if let Some(data) = &curl_cmd.data {
+            match serde_json::from_str::<serde_json::Value>(data) {
+                Ok(json) if curl_cmd.data_is_json || json.is_object() || json.is_array() => {
+                    steps.push(format!("req_body_json({})", value_literal(&json)));
+                }
+                _ => steps.push(format!(
+                    "req_body_raw({}, type = {})",
+                    string_literal(data),
+                    string_literal(content_type.unwrap_or("application/x-www-form-urlencoded"))
+                )),
+            }
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            steps.push(format!(
+                "req_body_file({}, type = {})",
+                string_literal(binary_file),
+                string_literal(content_type.unwrap_or("application/octet-stream"))
+            ));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            let mut parts = Vec::new();
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                parts.push(format!(
+                    "{} = {}",
+                    argument_name(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let mut arguments = vec![string_literal(&file_upload.path)];
+                if let Some(file_content_type) = &file_upload.content_type {
+                    arguments.push(format!("type = {}", string_literal(file_content_type)));
+                }
+                if let Some(filename) = &file_upload.filename {
+                    arguments.push(format!("name = {}", string_literal(filename)));
+                }
+                parts.push(format!(
+                    "{} = curl::form_file({})",
+                    argument_name(key),
+                    arguments.join(", ")
+                ));
+            }
+
+            steps.push(format!(
+                "req_body_multipart(\n    {}\n  )",
+                parts.join(",\n    ")
+            ));
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| format!("{} = {}", argument_name(key), string_literal(value)))
+                .collect();
+            steps.push(format!("req_body_form({})", pairs.join(", ")));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                steps.push(format!(
+                    "req_auth_basic({}, {})",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, host, port) = utils::split_proxy(proxy);
+            let mut arguments = vec![
+                string_literal(&format!("{}://{}", scheme, host)),
+                port.to_string(),
+            ];
+            if let Some((username, password)) = &curl_cmd.proxy_auth {
+                arguments.push(format!("username = {}", string_literal(username)));
+                arguments.push(format!("password = {}", string_literal(password)));
+            }
+            steps.push(format!("req_proxy({})", arguments.join(", ")));
+        }
+
+        let curl_options = self.curl_options(curl_cmd);
+        if !curl_options.is_empty() {
+            steps.push(format!("req_options({})", curl_options.join(", ")));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            steps.push(format!("req_timeout({})", timeout));
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            steps.push(format!("req_retry(max_tries = {})", retry + 1));
+        }
+
+        steps.push("req_error(is_error = \\(resp) FALSE)".to_string());
+
+        let mut output = String::new();
+        output.push_str("library(httr2)\n\n");
+        output.push_str(&format!(
+            "req <- request({})",
+            string_literal(&curl_cmd.url)
+        ));
+        for step in &steps {
+            output.push_str(&format!(" |>\n  {}", step));
+        }
+        output.push_str("\n\n");
+
+        match &curl_cmd.output {
+            Some(output_file) => output.push_str(&format!(
+                "resp <- req_perform(req, path = {})\n",
+                string_literal(output_file)
+            )),
+            None => output.push_str("resp <- req_perform(req)\n"),
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("print(resp_status(resp))\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("cat(resp_body_string(resp))\n");
+                }
+            }
+        }
+
+        output
+    }
+}
+
+impl Httr2 {
+    fn curl_options(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut options = Vec::new();
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            let auth_constant = match curl_cmd.auth_type {
+                Some(AuthType::Digest) => Some(2),
+                Some(AuthType::Negotiate) => Some(4),
+                Some(AuthType::Ntlm) => Some(8),
+                _ => None,
+            };
+            if let Some(auth_constant) = auth_constant {
+                options.push(format!("httpauth = {}", auth_constant));
+                options.push(format!(
+                    "userpwd = {}",
+                    string_literal(&format!("{}:{}", username, password))
+                ));
+            }
+        }
+
+        if curl_cmd.insecure {
+            options.push("ssl_verifypeer = 0".to_string());
+            options.push("ssl_verifyhost = 0".to_string());
+        }
+
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            options.push(format!("cainfo = {}", string_literal(cacert)));
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            options.push(format!("sslcert = {}", string_literal(cert)));
+        }
+
+        if let Some(cert_type) = &curl_cmd.ssl_options.cert_type {
+            options.push(format!("sslcerttype = {}", string_literal(cert_type)));
+        }
+
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            options.push(format!("sslkey = {}", string_literal(key)));
+        }
+
+        if let Some(key_type) = &curl_cmd.ssl_options.key_type {
+            options.push(format!("sslkeytype = {}", string_literal(key_type)));
+        }
+
+        if curl_cmd.no_alpn {
+            options.push("ssl_enable_alpn = 0".to_string());
+        }
+
+        if curl_cmd.compressed {
+            options.push("accept_encoding = \"\"".to_string());
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            options.push(format!("connecttimeout = {}", timeout));
+        }
+
+        if !curl_cmd.location {
+            options.push("followlocation = 0".to_string());
+        } else if let Some(max_redirs) = curl_cmd.max_redirs {
+            options.push(format!("maxredirs = {}", max_redirs));
+        }
+
+        options
+    }
+}
@@ -0,0 +1,66 @@
+pub mod httr2;
+
+pub use httr2::Httr2;
+
+use crate::converters::utils;
+use serde_json::Value;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("\"{}\"", utils::escape_string_literal(s))
+}
+
+pub(crate) fn argument_name(s: &str) -> String {
+    let mut chars = s.chars();
+    let is_syntactic = match chars.next() {
+        Some('.') => !s[1..].starts_with(|c: char| c.is_ascii_digit()),
+        Some(c) => c.is_ascii_alphabetic(),
+        None => false,
+    } && s
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_');
+    let is_reserved = matches!(
+        s,
+        "if" | "else"
+            | "repeat"
+            | "while"
+            | "function"
+            | "for"
+            | "next"
+            | "break"
+            | "TRUE"
+            | "FALSE"
+            | "NULL"
+            | "Inf"
+            | "NaN"
+            | "NA"
+            | "in"
+    );
+
+    if is_syntactic && !is_reserved {
+        s.to_string()
+    } else {
+        format!("`{}`", s.replace('\\', "\\\\").replace('`', "\\`"))
+    }
+}
+
+pub(crate) fn value_literal(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::Bool(true) => "TRUE".to_string(),
+        Value::Bool(false) => "FALSE".to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => string_literal(s),
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(value_literal).collect();
+            format!("list({})", items.join(", "))
+        }
+        Value::Object(map) if map.is_empty() => "setNames(list(), character(0))".to_string(),
+        Value::Object(map) => {
+            let pairs: Vec<String> = map
+                .iter()
+                .map(|(key, item)| format!("{} = {}", argument_name(key), value_literal(item)))
+                .collect();
+            format!("list({})", pairs.join(", "))
+        }
+    }
+}
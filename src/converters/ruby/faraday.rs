@@ -0,0 +1,251 @@
+use crate::converters::ruby::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Faraday;
+
+const STANDARD_METHODS: [&str; 7] = ["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS"];
+
+impl Convert for Faraday {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+
+        let has_multipart = !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty();
+        let has_url_encoded = utils::has_url_encoded_body(curl_cmd)
+            && curl_cmd.data.is_none()
+            && curl_cmd.binary_file.is_none()
+            && !has_multipart;
+
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        output.push_str("require 'faraday'\n");
+        if has_multipart {
+            output.push_str("require 'faraday/multipart'\n");
+        }
+        if curl_cmd.location {
+            output.push_str("require 'faraday/follow_redirects'\n");
+        }
+        if curl_cmd.retry.is_some() {
+            output.push_str("require 'faraday/retry'\n");
+        }
+        output.push('\n');
+
+        output.push_str("conn = Faraday.new(\n");
+        output.push_str(&format!("  url: {},\n", string_literal(&curl_cmd.url)));
+
+        let headers = utils::request_headers(curl_cmd);
+        if !headers.is_empty() {
+            output.push_str("  headers: {\n");
+            for (key, value) in &headers {
+                output.push_str(&format!(
+                    "    {} => {},\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+            output.push_str("  },\n");
+        }
+
+        let mut ssl_options = Vec::new();
+        if curl_cmd.insecure {
+            ssl_options.push("verify: false".to_string());
+        }
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            ssl_options.push(format!("ca_file: {}", string_literal(cacert)));
+        }
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            ssl_options.push(format!(
+                "client_cert: OpenSSL::X509::Certificate.new(File.read({}))",
+                string_literal(cert)
+            ));
+        }
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            ssl_options.push(format!(
+                "client_key: OpenSSL::PKey.read(File.read({}))",
+                string_literal(key)
+            ));
+        }
+        if !ssl_options.is_empty() {
+            output.push_str(&format!("  ssl: {{ {} }},\n", ssl_options.join(", ")));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let proxy_url = match &curl_cmd.proxy_auth {
+                Some((username, password)) => {
+                    utils::proxy_with_credentials(proxy, username, password)
+                }
+                None => proxy.clone(),
+            };
+            output.push_str(&format!("  proxy: {},\n", string_literal(&proxy_url)));
+        }
+
+        let mut request_options = Vec::new();
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            request_options.push(format!("open_timeout: {}", timeout));
+        }
+        if let Some(timeout) = curl_cmd.max_time {
+            request_options.push(format!("timeout: {}", timeout));
+        }
+        if !request_options.is_empty() {
+            output.push_str(&format!(
+                "  request: {{ {} }},\n",
+                request_options.join(", ")
+            ));
+        }
+
+        output.push_str(") do |f|\n");
+
+        if has_multipart {
+            output.push_str("  f.request :multipart\n");
+        }
+        if has_url_encoded {
+            output.push_str("  f.request :url_encoded\n");
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                output.push_str(&format!(
+                    "  f.request :authorization, :basic, {}, {}\n",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            output.push_str(&format!("  f.request :retry, max: {}\n", retry));
+        }
+
+        if curl_cmd.location {
+            match curl_cmd.max_redirs {
+                Some(max_redirs) => output.push_str(&format!(
+                    "  f.response :follow_redirects, limit: {}\n",
+                    max_redirs
+                )),
+                None => output.push_str("  f.response :follow_redirects\n"),
+            }
+        }
+
+        output.push_str("  f.adapter Faraday.default_adapter\n");
+        output.push_str("end\n\n");
+
+        let body = if let Some(data) = &curl_cmd.data {
+            Some(string_literal(data))
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            Some(format!("File.binread({})", string_literal(binary_file)))
+        } else if has_multipart {
+            let mut parts = Vec::new();
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                parts.push(format!(
+                    "  {} => {}",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let content_type = file_upload
+                    .content_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream");
+                parts.push(format!(
+                    "  {} => Faraday::Multipart::FilePart.new({}, {}, {})",
+                    string_literal(key),
+                    string_literal(&file_upload.path),
+                    string_literal(content_type),
+                    string_literal(filename)
+                ));
+            }
+
+            Some(format!("{{\n{}\n}}", parts.join(",\n")))
+        } else if has_url_encoded {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| format!("{} => {}", string_literal(key), string_literal(value)))
+                .collect();
+            Some(format!("{{ {} }}", pairs.join(", ")))
+        } else {
+            None
+        };
+
+        if let Some(body) = &body {
+            output.push_str(&format!("body = {}\n\n", body));
+        }
+
+        let body_arg = if body.is_some() { "body" } else { "nil" };
+
+        let method = curl_cmd.method.as_str();
+        if body.is_some() && matches!(method, "POST" | "PUT" | "PATCH") {
+            output.push_str(&format!(
+                "response = conn.{}(nil, body)\n",
+                method.to_lowercase()
+            ));
+        } else if body.is_none() && STANDARD_METHODS.contains(&method) {
+            output.push_str(&format!("response = conn.{}\n", method.to_lowercase()));
+        } else {
+            output.push_str(&format!(
+                "response = conn.run_request(:{}, nil, {}, nil)\n",
+                curl_cmd.method.to_lowercase(),
+                body_arg
+            ));
+        }
+
+        if let Some(output_file) = &curl_cmd.output {
+            output.push_str(&format!(
+                "File.binwrite({}, response.body)\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("puts response.status\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("puts response.body\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if !STANDARD_METHODS.contains(&curl_cmd.method.as_str()) {
+            warnings.push(format!(
+                "Faraday raises ArgumentError for the non-standard {} method, use Net::HTTP instead",
+                curl_cmd.method
+            ));
+        }
+
+        if curl_cmd.auth.is_some() {
+            match curl_cmd.auth_type {
+                Some(AuthType::Digest) => warnings
+                    .push("Digest authentication requires the faraday-digestauth gem".to_string()),
+                Some(AuthType::Ntlm) | Some(AuthType::Negotiate) => warnings.push(
+                    "NTLM and Negotiate authentication are not supported by Faraday".to_string(),
+                ),
+                _ => {}
+            }
+        }
+
+        if curl_cmd.compressed {
+            warnings.push("--compressed requires the faraday-gzip middleware".to_string());
+        }
+
+        warnings
+    }
+}
@@ -0,0 +1,9 @@
+pub mod faraday;
+pub mod net_http;
+
+pub use faraday::Faraday;
+pub use net_http::NetHttp;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
@@ -0,0 +1,241 @@
+use crate::converters::ruby::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct NetHttp;
+
+impl Convert for NetHttp {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        output.push_str("require 'net/http'\n");
+        output.push_str("require 'uri'\n");
+
+        let uses_openssl = curl_cmd.insecure
+            || curl_cmd.ssl_options.cert.is_some()
+            || curl_cmd.ssl_options.key.is_some();
+        if uses_openssl {
+            output.push_str("require 'openssl'\n");
+        }
+        output.push('\n');
+
+        output.push_str(&format!("uri = URI({})\n\n", string_literal(&curl_cmd.url)));
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (_, host, port) = utils::split_proxy(proxy);
+            match &curl_cmd.proxy_auth {
+                Some((username, password)) => output.push_str(&format!(
+                    "proxy = Net::HTTP::Proxy({}, {}, {}, {})\n",
+                    string_literal(&host),
+                    port,
+                    string_literal(username),
+                    string_literal(password)
+                )),
+                None => output.push_str(&format!(
+                    "proxy = Net::HTTP::Proxy({}, {})\n",
+                    string_literal(&host),
+                    port
+                )),
+            }
+            output.push_str("http = proxy.new(uri.host, uri.port)\n");
+        } else {
+            output.push_str("http = Net::HTTP.new(uri.host, uri.port)\n");
+        }
+
+        output.push_str("http.use_ssl = uri.scheme == 'https'\n");
+
+        if curl_cmd.insecure {
+            output.push_str("http.verify_mode = OpenSSL::SSL::VERIFY_NONE\n");
+        }
+
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            output.push_str(&format!("http.ca_file = {}\n", string_literal(cacert)));
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            output.push_str(&format!(
+                "http.cert = OpenSSL::X509::Certificate.new(File.read({}))\n",
+                string_literal(cert)
+            ));
+        }
+
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            output.push_str(&format!(
+                "http.key = OpenSSL::PKey.read(File.read({}))\n",
+                string_literal(key)
+            ));
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            output.push_str(&format!("http.open_timeout = {}\n", timeout));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            output.push_str(&format!("http.read_timeout = {}\n", timeout));
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            output.push_str(&format!("http.max_retries = {}\n", retry));
+        }
+
+        output.push('\n');
+
+        let has_body = curl_cmd.data.is_some()
+            || curl_cmd.binary_file.is_some()
+            || !curl_cmd.form.is_empty()
+            || !curl_cmd.files.is_empty()
+            || utils::has_url_encoded_body(curl_cmd);
+
+        match self.request_class(&curl_cmd.method) {
+            Some(class) => {
+                output.push_str(&format!("request = Net::HTTP::{}.new(uri)\n", class));
+            }
+            None => {
+                output.push_str(&format!(
+                    "request = Net::HTTPGenericRequest.new({}, {}, {}, uri)\n",
+                    string_literal(&curl_cmd.method),
+                    has_body,
+                    curl_cmd.method != "HEAD"
+                ));
+            }
+        }
+
+        for (key, value) in utils::request_headers(curl_cmd) {
+            output.push_str(&format!(
+                "request[{}] = {}\n",
+                string_literal(&key),
+                string_literal(&value)
+            ));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                output.push_str(&format!(
+                    "request.basic_auth({}, {})\n",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            output.push_str(&format!("request.body = {}\n", string_literal(data)));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            output.push_str(&format!(
+                "request.body = File.binread({})\n",
+                string_literal(binary_file)
+            ));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            output.push_str("request.set_form([\n");
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                output.push_str(&format!(
+                    "  [{}, {}],\n",
+                    string_literal(key),
+                    string_literal(value)
+                ));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let filename = file_upload
+                    .filename
+                    .as_deref()
+                    .unwrap_or_else(|| utils::file_name(&file_upload.path));
+                let mut options = format!("filename: {}", string_literal(filename));
+                if let Some(content_type) = &file_upload.content_type {
+                    options.push_str(&format!(", content_type: {}", string_literal(content_type)));
+                }
+                output.push_str(&format!(
+                    "  [{}, File.open({}), {{ {} }}],\n",
+                    string_literal(key),
+                    string_literal(&file_upload.path),
+                    options
+                ));
+            }
+
+            output.push_str("], 'multipart/form-data')\n");
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            let pairs: Vec<String> = params
+                .into_iter()
+                .map(|(key, value)| format!("{} => {}", string_literal(key), string_literal(value)))
+                .collect();
+            output.push_str(&format!(
+                "request.set_form_data({{ {} }})\n",
+                pairs.join(", ")
+            ));
+        }
+
+        output.push('\n');
+        output.push_str("response = http.request(request)\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            output.push_str(&format!(
+                "File.binwrite({}, response.body)\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("puts response.code\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("puts response.body\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() {
+            match curl_cmd.auth_type {
+                Some(AuthType::Digest) => warnings.push(
+                    "Digest authentication requires the net-http-digest_auth gem".to_string(),
+                ),
+                Some(AuthType::Ntlm) => {
+                    warnings.push("NTLM authentication requires the rubyntlm gem".to_string())
+                }
+                Some(AuthType::Negotiate) => warnings
+                    .push("Negotiate authentication is not supported by Net::HTTP".to_string()),
+                _ => {}
+            }
+        }
+
+        if curl_cmd.location {
+            warnings.push(
+                "Net::HTTP does not follow redirects, handle Net::HTTPRedirection manually"
+                    .to_string(),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl NetHttp {
+    fn request_class(&self, method: &str) -> Option<&'static str> {
+        match method {
+            "GET" => Some("Get"),
+            "POST" => Some("Post"),
+            "PUT" => Some("Put"),
+            "DELETE" => Some("Delete"),
+            "PATCH" => Some("Patch"),
+            "HEAD" => Some("Head"),
+            "OPTIONS" => Some("Options"),
+            "TRACE" => Some("Trace"),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::converters::shell::{command_lines, shell_quote};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Httpie;
+
+impl Convert for Httpie {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut args = Vec::new();
+        let mut items = Vec::new();
+        let mut stdin_file = None;
+
+        let json_object = curl_cmd.data.as_deref().and_then(|data| {
+            match serde_json::from_str::<serde_json::Value>(data) {
+                Ok(serde_json::Value::Object(map)) => Some(map),
+                _ => None,
+            }
+        });
+
+        let mut headers = utils::request_headers(curl_cmd);
+
+        if let Some(map) = &json_object {
+            for (key, value) in map {
+                match value {
+                    serde_json::Value::String(text) => {
+                        items.push(shell_quote(&format!("{}={}", key, text)));
+                    }
+                    other => items.push(shell_quote(&format!("{}:={}", key, other))),
+                }
+            }
+        } else if let Some(data) = &curl_cmd.data {
+            let has_content_type = headers
+                .iter()
+                .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+            if !has_content_type {
+                headers.push((
+                    "Content-Type".to_string(),
+                    "application/x-www-form-urlencoded".to_string(),
+                ));
+            }
+            args.push(format!("--raw={}", shell_quote(data)));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            stdin_file = Some(binary_file.clone());
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            args.push("--multipart".to_string());
+
+            let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+            fields.sort();
+            for (key, value) in fields {
+                items.push(shell_quote(&format!("{}={}", key, value)));
+            }
+
+            let mut files: Vec<_> = curl_cmd.files.iter().collect();
+            files.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, file_upload) in files {
+                let mut item = format!("{}@{}", key, file_upload.path);
+                if let Some(content_type) = &file_upload.content_type {
+                    item.push_str(&format!(";type={}", content_type));
+                }
+                items.push(shell_quote(&item));
+            }
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            args.push("--form".to_string());
+
+            let mut params: Vec<_> = curl_cmd.url_encoded_params.iter().collect();
+            params.sort();
+            for (key, value) in params {
+                items.push(shell_quote(&format!("{}={}", key, value)));
+            }
+        }
+
+        let header_items: Vec<String> = headers
+            .iter()
+            .map(|(key, value)| shell_quote(&format!("{}:{}", key, value)))
+            .collect();
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            match curl_cmd.auth_type {
+                Some(AuthType::Digest) => args.push("--auth-type=digest".to_string()),
+                Some(AuthType::Ntlm) => args.push("--auth-type=ntlm".to_string()),
+                Some(AuthType::Negotiate) => args.push("--auth-type=negotiate".to_string()),
+                _ => {}
+            }
+            args.push(format!(
+                "--auth={}",
+                shell_quote(&format!("{}:{}", username, password))
+            ));
+        }
+
+        if curl_cmd.insecure {
+            args.push("--verify=no".to_string());
+        } else if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            args.push(format!("--verify={}", shell_quote(cacert)));
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            args.push(format!("--cert={}", shell_quote(cert)));
+        }
+
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            args.push(format!("--cert-key={}", shell_quote(key)));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let proxy_url = match &curl_cmd.proxy_auth {
+                Some((username, password)) => {
+                    let (scheme, host, port) = utils::split_proxy(proxy);
+                    format!("{}://{}:{}@{}:{}", scheme, username, password, host, port)
+                }
+                None => proxy.clone(),
+            };
+            args.push(format!(
+                "--proxy={}",
+                shell_quote(&format!("http:{}", proxy_url))
+            ));
+            args.push(format!(
+                "--proxy={}",
+                shell_quote(&format!("https:{}", proxy_url))
+            ));
+        }
+
+        if curl_cmd.location {
+            args.push("--follow".to_string());
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                args.push(format!("--max-redirects={}", max_redirs));
+            }
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            args.push(format!("--timeout={}", timeout));
+        }
+
+        if let Some(output_file) = &curl_cmd.output {
+            args.push("--download".to_string());
+            args.push(format!("--output={}", shell_quote(output_file)));
+        }
+
+        args.push(shell_quote(&curl_cmd.method));
+        args.push(shell_quote(&curl_cmd.url));
+        args.extend(header_items);
+        args.extend(items);
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        output.push_str(&command_lines("http", &args));
+
+        if let Some(stdin_file) = stdin_file {
+            output.pop();
+            output.push_str(&format!(" \\\n  < {}\n", shell_quote(&stdin_file)));
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.auth.is_some() {
+            match curl_cmd.auth_type {
+                Some(AuthType::Ntlm) => {
+                    warnings.push("--auth-type=ntlm requires the httpie-ntlm plugin".to_string())
+                }
+                Some(AuthType::Negotiate) => warnings
+                    .push("--auth-type=negotiate requires the httpie-negotiate plugin".to_string()),
+                _ => {}
+            }
+        }
+
+        if curl_cmd
+            .files
+            .values()
+            .any(|file_upload| file_upload.filename.is_some())
+        {
+            warnings
+                .push("HTTPie cannot override multipart filenames, ;filename= ignored".to_string());
+        }
+
+        if curl_cmd.connect_timeout.is_some() {
+            warnings.push("HTTPie has no connect timeout, --connect-timeout ignored".to_string());
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "HTTPie has no retry option, rerun the command to retry {} times",
+                retry
+            ));
+        }
+
+        if curl_cmd.write_out.is_some() {
+            warnings.push("HTTPie has no equivalent for --write-out".to_string());
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("HTTPie does not expose ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
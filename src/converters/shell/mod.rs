@@ -0,0 +1,26 @@
+pub mod httpie;
+pub mod wget;
+
+pub use httpie::Httpie;
+pub use wget::Wget;
+
+pub(crate) fn shell_quote(s: &str) -> String {
+    let is_safe = !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_@%+=:,./-".contains(c));
+    if is_safe {
+        s.to_string()
+    } else {
+        format!("'{}'", s.replace('\'', "'\\''"))
+    }
+}
+
+pub(crate) fn command_lines(program: &str, args: &[String]) -> String {
+    let mut output = program.to_string();
+    for arg in args {
+        output.push_str(" \\\n  ");
+        output.push_str(arg);
+    }
+    output.push('\n');
+    output
+}
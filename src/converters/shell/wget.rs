@@ -0,0 +1,172 @@
+use crate::converters::shell::{command_lines, shell_quote};
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct Wget;
+
+impl Convert for Wget {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut args = Vec::new();
+
+        let has_body = curl_cmd.data.is_some()
+            || curl_cmd.binary_file.is_some()
+            || utils::has_url_encoded_body(curl_cmd);
+        if curl_cmd.method != "GET" || has_body {
+            args.push(format!("--method={}", shell_quote(&curl_cmd.method)));
+        }
+
+        for (key, value) in utils::request_headers(curl_cmd) {
+            if curl_cmd.user_agent.is_some() && key == "User-Agent" {
+                continue;
+            }
+            args.push(format!(
+                "--header={}",
+                shell_quote(&format!("{}: {}", key, value))
+            ));
+        }
+
+        if let Some(user_agent) = &curl_cmd.user_agent {
+            args.push(format!("--user-agent={}", shell_quote(user_agent)));
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            let has_content_type = curl_cmd
+                .headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case("content-type"));
+            if !has_content_type {
+                args.push(format!(
+                    "--header={}",
+                    shell_quote("Content-Type: application/x-www-form-urlencoded")
+                ));
+            }
+            args.push(format!("--body-data={}", shell_quote(data)));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            args.push(format!("--body-file={}", shell_quote(binary_file)));
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            args.push(format!(
+                "--body-data={}",
+                shell_quote(&utils::url_encoded_body(&curl_cmd.url_encoded_params))
+            ));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            args.push(format!("--user={}", shell_quote(username)));
+            args.push(format!("--password={}", shell_quote(password)));
+            if curl_cmd.auth_type.is_none() || matches!(curl_cmd.auth_type, Some(AuthType::Basic)) {
+                args.push("--auth-no-challenge".to_string());
+            }
+        }
+
+        if curl_cmd.insecure {
+            args.push("--no-check-certificate".to_string());
+        }
+
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            args.push(format!("--ca-certificate={}", shell_quote(cacert)));
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            args.push(format!("--certificate={}", shell_quote(cert)));
+        }
+
+        if let Some(cert_type) = &curl_cmd.ssl_options.cert_type {
+            args.push(format!(
+                "--certificate-type={}",
+                shell_quote(&cert_type.to_uppercase())
+            ));
+        }
+
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            args.push(format!("--private-key={}", shell_quote(key)));
+        }
+
+        if let Some(key_type) = &curl_cmd.ssl_options.key_type {
+            args.push(format!(
+                "--private-key-type={}",
+                shell_quote(&key_type.to_uppercase())
+            ));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            args.push("-e use_proxy=yes".to_string());
+            args.push(format!(
+                "-e {}",
+                shell_quote(&format!("http_proxy={}", proxy))
+            ));
+            args.push(format!(
+                "-e {}",
+                shell_quote(&format!("https_proxy={}", proxy))
+            ));
+            if let Some((username, password)) = &curl_cmd.proxy_auth {
+                args.push(format!("--proxy-user={}", shell_quote(username)));
+                args.push(format!("--proxy-password={}", shell_quote(password)));
+            }
+        }
+
+        if curl_cmd.location {
+            if let Some(max_redirs) = curl_cmd.max_redirs {
+                args.push(format!("--max-redirect={}", max_redirs));
+            }
+        } else {
+            args.push("--max-redirect=0".to_string());
+        }
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            args.push(format!("--connect-timeout={}", timeout));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            args.push(format!("--timeout={}", timeout));
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            args.push(format!("--tries={}", retry + 1));
+        }
+
+        if curl_cmd.compressed {
+            args.push("--compression=auto".to_string());
+        }
+
+        match &curl_cmd.output {
+            Some(output_file) => args.push(format!("-O {}", shell_quote(output_file))),
+            None => args.push("-O -".to_string()),
+        }
+
+        args.push("--quiet".to_string());
+        args.push(shell_quote(&curl_cmd.url));
+
+        let mut output = String::new();
+        output.push_str(&utils::warning_comments("#", &self.warnings(curl_cmd)));
+        output.push_str(&command_lines("wget", &args));
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            warnings
+                .push("wget cannot send multipart/form-data, -F fields were dropped".to_string());
+        }
+
+        if curl_cmd.auth.is_some() && matches!(curl_cmd.auth_type, Some(AuthType::Negotiate)) {
+            warnings.push("wget does not support Negotiate authentication".to_string());
+        }
+
+        if curl_cmd.max_time.is_some() {
+            warnings.push("wget has no total timeout, --max-time mapped to --timeout".to_string());
+        }
+
+        if curl_cmd.write_out.is_some() {
+            warnings.push("wget has no equivalent for --write-out".to_string());
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("wget does not expose ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
@@ -0,0 +1,9 @@
+pub mod url_session;
+
+pub use url_session::UrlSession;
+
+use crate::converters::utils;
+
+pub(crate) fn string_literal(s: &str) -> String {
+    format!("\"{}\"", utils::escape_string_literal(s))
+}
@@ -0,0 +1,360 @@
+use crate::converters::swift::string_literal;
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct UrlSession;
+
+impl Convert for UrlSession {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = String::new();
+
+        let needs_delegate = curl_cmd.insecure
+            || curl_cmd.ssl_options.cert.is_some()
+            || (curl_cmd.auth.is_some()
+                && !matches!(curl_cmd.auth_type, None | Some(AuthType::Basic)))
+            || !curl_cmd.location;
+
+        output.push_str(&utils::warning_comments("//", &self.warnings(curl_cmd)));
+        output.push_str("import Foundation\n");
+        if needs_delegate {
+            output.push_str("import Security\n");
+        }
+        output.push('\n');
+
+        if needs_delegate {
+            self.delegate(&mut output, curl_cmd);
+        }
+
+        output.push_str(&format!(
+            "let url = URL(string: {})!\n",
+            string_literal(&curl_cmd.url)
+        ));
+        output.push_str("var request = URLRequest(url: url)\n");
+        output.push_str(&format!(
+            "request.httpMethod = {}\n",
+            string_literal(&curl_cmd.method)
+        ));
+
+        for (key, value) in utils::request_headers(curl_cmd) {
+            output.push_str(&format!(
+                "request.setValue({}, forHTTPHeaderField: {})\n",
+                string_literal(&value),
+                string_literal(&key)
+            ));
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            if matches!(curl_cmd.auth_type, None | Some(AuthType::Basic)) {
+                output.push_str(&format!(
+                    "let credentials = Data({}.utf8).base64EncodedString()\n",
+                    string_literal(&format!("{}:{}", username, password))
+                ));
+                output.push_str(
+                    "request.setValue(\"Basic \\(credentials)\", forHTTPHeaderField: \"Authorization\")\n",
+                );
+            }
+        }
+
+        if let Some(data) = &curl_cmd.data {
+            output.push_str(&format!(
+                "request.httpBody = {}.data(using: .utf8)\n",
+                string_literal(data)
+            ));
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            output.push_str(&format!(
+                "request.httpBody = try Data(contentsOf: URL(fileURLWithPath: {}))\n",
+                string_literal(binary_file)
+            ));
+        } else if !curl_cmd.form.is_empty() || !curl_cmd.files.is_empty() {
+            self.multipart_body(&mut output, curl_cmd);
+        } else if utils::has_url_encoded_body(curl_cmd) {
+            if !curl_cmd
+                .headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case("content-type"))
+            {
+                output.push_str("request.setValue(\"application/x-www-form-urlencoded\", forHTTPHeaderField: \"Content-Type\")\n");
+            }
+            output.push_str(&format!(
+                "request.httpBody = {}.data(using: .utf8)\n",
+                string_literal(&utils::url_encoded_body(&curl_cmd.url_encoded_params))
+            ));
+        }
+
+        output.push('\n');
+        output.push_str("let configuration = URLSessionConfiguration.default\n");
+
+        if let Some(timeout) = curl_cmd.connect_timeout {
+            output.push_str(&format!(
+                "configuration.timeoutIntervalForRequest = {}\n",
+                timeout
+            ));
+        }
+
+        if let Some(timeout) = curl_cmd.max_time {
+            output.push_str(&format!(
+                "configuration.timeoutIntervalForResource = {}\n",
+                timeout
+            ));
+        }
+
+        if let Some(proxy) = &curl_cmd.proxy {
+            let (scheme, host, port) = utils::split_proxy(proxy);
+            if scheme.starts_with("socks") {
+                output.push_str("configuration.connectionProxyDictionary = [\n");
+                output.push_str("    kCFStreamPropertySOCKSProxyHost: ");
+                output.push_str(&format!("{},\n", string_literal(&host)));
+                output.push_str(&format!("    kCFStreamPropertySOCKSProxyPort: {},\n", port));
+                output.push_str("]\n");
+            } else {
+                output.push_str("configuration.connectionProxyDictionary = [\n");
+                output.push_str("    kCFNetworkProxiesHTTPEnable: true,\n");
+                output.push_str(&format!(
+                    "    kCFNetworkProxiesHTTPProxy: {},\n",
+                    string_literal(&host)
+                ));
+                output.push_str(&format!("    kCFNetworkProxiesHTTPPort: {},\n", port));
+                output.push_str("]\n");
+                output.push_str("#if os(macOS)\n");
+                output.push_str(
+                    "configuration.connectionProxyDictionary?[kCFNetworkProxiesHTTPSEnable] = true\n",
+                );
+                output.push_str(&format!(
+                    "configuration.connectionProxyDictionary?[kCFNetworkProxiesHTTPSProxy] = {}\n",
+                    string_literal(&host)
+                ));
+                output.push_str(&format!(
+                    "configuration.connectionProxyDictionary?[kCFNetworkProxiesHTTPSPort] = {}\n",
+                    port
+                ));
+                output.push_str("#endif\n");
+            }
+        }
+
+        if needs_delegate {
+            output.push_str(
+                "let session = URLSession(configuration: configuration, delegate: SessionDelegate(), delegateQueue: nil)\n",
+            );
+        } else {
+            output.push_str("let session = URLSession(configuration: configuration)\n");
+        }
+
+        output.push('\n');
+        output.push_str("let (data, response) = try await session.data(for: request)\n");
+
+        if let Some(output_file) = &curl_cmd.output {
+            output.push_str(&format!(
+                "try data.write(to: URL(fileURLWithPath: {}))\n",
+                string_literal(output_file)
+            ));
+        }
+
+        match &curl_cmd.write_out {
+            Some(write_out) if write_out.contains("%{http_code}") => {
+                output.push_str("if let httpResponse = response as? HTTPURLResponse {\n");
+                output.push_str("    print(httpResponse.statusCode)\n");
+                output.push_str("}\n");
+            }
+            _ => {
+                if curl_cmd.output.is_none() {
+                    output.push_str("print(String(decoding: data, as: UTF8.self))\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.proxy_auth.is_some() {
+            warnings.push(
+                "URLSession reads proxy credentials from the system, --proxy-user ignored"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.connect_timeout.is_some() {
+            warnings.push(
+                "URLSession has no connect timeout, --connect-timeout mapped to the idle timeoutIntervalForRequest"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.location && curl_cmd.max_redirs.is_some() {
+            warnings
+                .push("URLSession does not support limiting redirects (--max-redirs)".to_string());
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            warnings.push(format!(
+                "URLSession has no retry policy, retry the request {} times manually",
+                retry
+            ));
+        }
+
+        let is_p12 = curl_cmd
+            .ssl_options
+            .cert_type
+            .as_deref()
+            .is_some_and(|cert_type| cert_type.eq_ignore_ascii_case("p12"));
+        if curl_cmd.ssl_options.cert.is_some() && !is_p12 {
+            warnings.push(
+                "URLSession client certificates must be PKCS#12, convert --cert/--key first"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.ssl_options.cacert.is_some() {
+            warnings.push(
+                "custom CA certificates (--cacert) must be pinned in the delegate".to_string(),
+            );
+        }
+
+        if curl_cmd.no_alpn {
+            warnings.push("URLSession does not expose ALPN, --no-alpn ignored".to_string());
+        }
+
+        warnings
+    }
+}
+
+impl UrlSession {
+    fn delegate(&self, output: &mut String, curl_cmd: &CurlCommand) {
+        output.push_str("final class SessionDelegate: NSObject, URLSessionTaskDelegate {\n");
+        output.push_str("    func urlSession(\n");
+        output.push_str("        _ session: URLSession,\n");
+        output.push_str("        task: URLSessionTask,\n");
+        output.push_str("        didReceive challenge: URLAuthenticationChallenge\n");
+        output.push_str("    ) async -> (URLSession.AuthChallengeDisposition, URLCredential?) {\n");
+        output.push_str("        switch challenge.protectionSpace.authenticationMethod {\n");
+
+        if curl_cmd.insecure {
+            output.push_str("        case NSURLAuthenticationMethodServerTrust:\n");
+            output.push_str(
+                "            guard let trust = challenge.protectionSpace.serverTrust else {\n",
+            );
+            output.push_str("                return (.performDefaultHandling, nil)\n");
+            output.push_str("            }\n");
+            output.push_str("            return (.useCredential, URLCredential(trust: trust))\n");
+        }
+
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            output.push_str("        case NSURLAuthenticationMethodClientCertificate:\n");
+            output.push_str(&format!(
+                "            guard let p12 = try? Data(contentsOf: URL(fileURLWithPath: {})) else {{\n",
+                string_literal(cert)
+            ));
+            output.push_str("                return (.performDefaultHandling, nil)\n");
+            output.push_str("            }\n");
+            output.push_str("            var items: CFArray?\n");
+            output.push_str(
+                "            let options = [kSecImportExportPassphrase as String: \"\"]\n",
+            );
+            output.push_str("            guard SecPKCS12Import(p12 as CFData, options as CFDictionary, &items) == errSecSuccess,\n");
+            output.push_str("                  let entries = items as? [[String: Any]],\n");
+            output.push_str("                  let identity = entries.first?[kSecImportItemIdentity as String] else {\n");
+            output.push_str("                return (.performDefaultHandling, nil)\n");
+            output.push_str("            }\n");
+            output.push_str("            let credential = URLCredential(\n");
+            output.push_str("                identity: identity as! SecIdentity,\n");
+            output.push_str("                certificates: nil,\n");
+            output.push_str("                persistence: .forSession\n");
+            output.push_str("            )\n");
+            output.push_str("            return (.useCredential, credential)\n");
+        }
+
+        if let Some((username, password)) = &curl_cmd.auth {
+            let method = match curl_cmd.auth_type {
+                Some(AuthType::Digest) => Some("NSURLAuthenticationMethodHTTPDigest"),
+                Some(AuthType::Ntlm) => Some("NSURLAuthenticationMethodNTLM"),
+                Some(AuthType::Negotiate) => Some("NSURLAuthenticationMethodNegotiate"),
+                _ => None,
+            };
+            if let Some(method) = method {
+                output.push_str(&format!("        case {}:\n", method));
+                output.push_str(&format!(
+                    "            return (.useCredential, URLCredential(user: {}, password: {}, persistence: .forSession))\n",
+                    string_literal(username),
+                    string_literal(password)
+                ));
+            }
+        }
+
+        output.push_str("        default:\n");
+        output.push_str("            return (.performDefaultHandling, nil)\n");
+        output.push_str("        }\n");
+        output.push_str("    }\n");
+
+        if !curl_cmd.location {
+            output.push('\n');
+            output.push_str("    func urlSession(\n");
+            output.push_str("        _ session: URLSession,\n");
+            output.push_str("        task: URLSessionTask,\n");
+            output.push_str("        willPerformHTTPRedirection response: HTTPURLResponse,\n");
+            output.push_str("        newRequest request: URLRequest\n");
+            output.push_str("    ) async -> URLRequest? {\n");
+            output.push_str("        return nil\n");
+            output.push_str("    }\n");
+        }
+
+        output.push_str("}\n\n");
+    }
+
+    fn multipart_body(&self, output: &mut String, curl_cmd: &CurlCommand) {
+        output.push_str("let boundary = \"Boundary-\\(UUID().uuidString)\"\n");
+        output.push_str("request.setValue(\"multipart/form-data; boundary=\\(boundary)\", forHTTPHeaderField: \"Content-Type\")\n\n");
+        output.push_str("var body = Data()\n");
+
+        let mut fields: Vec<_> = curl_cmd.form.iter().collect();
+        fields.sort();
+        for (key, value) in fields {
+            output.push_str("body.append(\"--\\(boundary)\\r\\n\".data(using: .utf8)!)\n");
+            output.push_str(&format!(
+                "body.append({}.data(using: .utf8)!)\n",
+                string_literal(&format!(
+                    "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                    key
+                ))
+            ));
+            output.push_str(&format!(
+                "body.append({}.data(using: .utf8)!)\n",
+                string_literal(&format!("{}\r\n", value))
+            ));
+        }
+
+        let mut files: Vec<_> = curl_cmd.files.iter().collect();
+        files.sort_by(|a, b| a.0.cmp(b.0));
+        for (key, file_upload) in files {
+            let filename = file_upload
+                .filename
+                .as_deref()
+                .unwrap_or_else(|| utils::file_name(&file_upload.path));
+            let content_type = file_upload
+                .content_type
+                .as_deref()
+                .unwrap_or("application/octet-stream");
+            output.push_str("body.append(\"--\\(boundary)\\r\\n\".data(using: .utf8)!)\n");
+            output.push_str(&format!(
+                "body.append({}.data(using: .utf8)!)\n",
+                string_literal(&format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+                    key, filename
+                ))
+            ));
+            output.push_str(&format!(
+                "body.append({}.data(using: .utf8)!)\n",
+                string_literal(&format!("Content-Type: {}\r\n\r\n", content_type))
+            ));
+            output.push_str(&format!(
+                "body.append(try Data(contentsOf: URL(fileURLWithPath: {})))\n",
+                string_literal(&file_upload.path)
+            ));
+            output.push_str("body.append(\"\\r\\n\".data(using: .utf8)!)\n");
+        }
+
+        output.push_str("body.append(\"--\\(boundary)--\\r\\n\".data(using: .utf8)!)\n");
+        output.push_str("request.httpBody = body\n");
+    }
+}
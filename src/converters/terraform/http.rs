@@ -0,0 +1,163 @@
+use crate::converters::{utils, Convert};
+use crate::{AuthType, CurlCommand};
+
+pub struct TerraformHttp;
+
+impl Convert for TerraformHttp {
+    fn convert(&self, curl_cmd: &CurlCommand) -> String {
+        let mut output = utils::warning_comments("#", &self.warnings(curl_cmd));
+
+        output.push_str(&format!("data \"http\" \"{}\" {{\n", self.name(curl_cmd)));
+        output.push_str(&format!("  url    = {}\n", hcl_string(&curl_cmd.url)));
+        output.push_str(&format!("  method = {}\n", hcl_string(&curl_cmd.method)));
+
+        let mut headers: Vec<(String, String)> = utils::request_headers(curl_cmd)
+            .into_iter()
+            .map(|(key, value)| (hcl_string(&key), hcl_string(&value)))
+            .collect();
+        let has_content_type = curl_cmd
+            .headers
+            .keys()
+            .any(|key| key.eq_ignore_ascii_case("content-type"));
+        if self.body(curl_cmd).is_some() && !has_content_type {
+            headers.push((
+                "\"Content-Type\"".to_string(),
+                "\"application/x-www-form-urlencoded\"".to_string(),
+            ));
+        }
+        if let Some((username, password)) = &curl_cmd.auth {
+            if matches!(curl_cmd.auth_type, None | Some(AuthType::Basic)) {
+                headers.push((
+                    "\"Authorization\"".to_string(),
+                    format!(
+                        "\"Basic ${{base64encode({})}}\"",
+                        hcl_string(&format!("{}:{}", username, password))
+                    ),
+                ));
+            }
+        }
+        if !headers.is_empty() {
+            output.push_str("\n  request_headers = {\n");
+            let width = headers.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+            for (key, value) in &headers {
+                output.push_str(&format!("    {:<width$} = {}\n", key, value, width = width));
+            }
+            output.push_str("  }\n");
+        }
+
+        if let Some(body) = self.body(curl_cmd) {
+            output.push_str(&format!("\n  request_body = {}\n", body));
+        }
+
+        let mut settings: Vec<(&str, String)> = Vec::new();
+        if curl_cmd.insecure {
+            settings.push(("insecure", "true".to_string()));
+        }
+        if let Some(cacert) = &curl_cmd.ssl_options.cacert {
+            settings.push(("ca_cert_pem", format!("file({})", hcl_string(cacert))));
+        }
+        if let Some(cert) = &curl_cmd.ssl_options.cert {
+            settings.push(("client_cert_pem", format!("file({})", hcl_string(cert))));
+        }
+        if let Some(key) = &curl_cmd.ssl_options.key {
+            settings.push(("client_key_pem", format!("file({})", hcl_string(key))));
+        }
+        if let Some(timeout) = curl_cmd.max_time.or(curl_cmd.connect_timeout) {
+            settings.push((
+                "request_timeout_ms",
+                (u64::from(timeout) * 1000).to_string(),
+            ));
+        }
+        if !settings.is_empty() {
+            output.push('\n');
+            let width = settings.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+            for (key, value) in &settings {
+                output.push_str(&format!("  {:<width$} = {}\n", key, value, width = width));
+            }
+        }
+
+        if let Some(retry) = curl_cmd.retry {
+            output.push_str("\n  retry {\n");
+            output.push_str(&format!("    attempts = {}\n", retry));
+            output.push_str("  }\n");
+        }
+
+        output.push_str("}\n");
+        output
+    }
+
+    fn warnings(&self, curl_cmd: &CurlCommand) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if curl_cmd.data.is_none()
+            && curl_cmd.binary_file.is_none()
+            && (!curl_cmd.form.is_empty() || !curl_cmd.files.is_empty())
+        {
+            warnings
+                .push("The http data source cannot send multipart bodies, -F ignored".to_string());
+        }
+
+        if curl_cmd.auth.is_some() && !matches!(curl_cmd.auth_type, None | Some(AuthType::Basic)) {
+            warnings.push(
+                "The http data source only supports basic auth through headers, credentials omitted"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.proxy.is_some() {
+            warnings.push(
+                "The http data source reads proxies from the environment, --proxy ignored"
+                    .to_string(),
+            );
+        }
+
+        if curl_cmd.output.is_some() {
+            warnings.push(
+                "The response is exposed as response_body, use local_file to write it to disk"
+                    .to_string(),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl TerraformHttp {
+    fn name(&self, curl_cmd: &CurlCommand) -> String {
+        let mut name = curl_cmd.method.to_lowercase();
+        let path = url::Url::parse(&curl_cmd.url)
+            .map(|url| url.path().to_string())
+            .unwrap_or_default();
+        for part in path.split(|c: char| !c.is_ascii_alphanumeric()) {
+            if !part.is_empty() {
+                name.push('_');
+                name.push_str(&part.to_lowercase());
+            }
+        }
+        name
+    }
+
+    fn body(&self, curl_cmd: &CurlCommand) -> Option<String> {
+        if let Some(data) = &curl_cmd.data {
+            Some(hcl_string(data))
+        } else if let Some(binary_file) = &curl_cmd.binary_file {
+            Some(format!("file({})", hcl_string(binary_file)))
+        } else if curl_cmd.form.is_empty()
+            && curl_cmd.files.is_empty()
+            && utils::has_url_encoded_body(curl_cmd)
+        {
+            Some(hcl_string(&utils::url_encoded_body(
+                &curl_cmd.url_encoded_params,
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+fn hcl_string(value: &str) -> String {
+    let escaped = utils::escape_string_literal(value)
+        .replace("${", "$${")
+        .replace("%{", "%%{");
+    format!("\"{}\"", escaped)
+}
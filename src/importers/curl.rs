@@ -0,0 +1,34 @@
+use crate::importers::{Import, ImportError};
+use crate::{parse_curl_command, CurlCommand};
+
+pub struct CurlImporter;
+
+impl Import for CurlImporter {
+    fn import(&self, input: &str) -> Result<Vec<CurlCommand>, ImportError> {
+        let mut commands = Vec::new();
+        let mut pending = String::new();
+        for line in input.lines() {
+            let line = line.trim();
+            if pending.is_empty() && (line.is_empty() || line.starts_with('#')) {
+                continue;
+            }
+
+            match line.strip_suffix('\\') {
+                Some(continued) => {
+                    pending.push_str(continued);
+                    pending.push(' ');
+                }
+                None => {
+                    pending.push_str(line);
+                    commands.push(parse_curl_command(&pending)?);
+                    pending.clear();
+                }
+            }
+        }
+
+        if !pending.trim().is_empty() {
+            commands.push(parse_curl_command(&pending)?);
+        }
+        Ok(commands)
+    }
+}
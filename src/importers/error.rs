@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ImportError {
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+
+    #[error("Unsupported Postman collection: {0}")]
+    UnsupportedCollection(String),
+
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(#[from] regex::Error),
+
+    #[error("Invalid curl command: {0}")]
+    InvalidCurl(#[from] crate::ParseError),
+
+    #[error("Invalid .http file: {0}")]
+    InvalidHttpFile(String),
+}
@@ -0,0 +1,107 @@
+use crate::har::{Har, Request};
+use crate::importers::{insert_cookies, Import, ImportError};
+use crate::{CurlCommand, FileUpload};
+use url::Url;
+
+pub struct HarImporter;
+
+impl Import for HarImporter {
+    fn import(&self, input: &str) -> Result<Vec<CurlCommand>, ImportError> {
+        let har: Har = serde_json::from_str(input)?;
+        har.log
+            .entries
+            .iter()
+            .map(|entry| self.command(&entry.request))
+            .collect()
+    }
+}
+
+impl HarImporter {
+    fn command(&self, request: &Request) -> Result<CurlCommand, ImportError> {
+        Url::parse(&request.url)?;
+
+        let mut curl_cmd = CurlCommand {
+            method: request.method.to_uppercase(),
+            url: request.url.clone(),
+            ..CurlCommand::default()
+        };
+
+        for cookie in &request.cookies {
+            curl_cmd
+                .cookies
+                .insert(cookie.name.clone(), cookie.value.clone());
+        }
+
+        for header in &request.headers {
+            let name = header.name.as_str();
+            if name.starts_with(':')
+                || name.eq_ignore_ascii_case("host")
+                || name.eq_ignore_ascii_case("content-length")
+            {
+                continue;
+            }
+
+            if name.eq_ignore_ascii_case("cookie") {
+                if request.cookies.is_empty() {
+                    insert_cookies(&mut curl_cmd.cookies, &header.value);
+                }
+                continue;
+            }
+
+            curl_cmd
+                .headers
+                .insert(header.name.clone(), header.value.clone());
+        }
+
+        if let Some(post_data) = &request.post_data {
+            let params = post_data.params.as_deref().unwrap_or_default();
+            let is_multipart = post_data.mime_type.starts_with("multipart/form-data");
+
+            if is_multipart && !params.is_empty() {
+                curl_cmd
+                    .headers
+                    .retain(|key, _| !key.eq_ignore_ascii_case("content-type"));
+                for param in params {
+                    match &param.file_name {
+                        Some(file_name) => {
+                            curl_cmd.files.insert(
+                                param.name.clone(),
+                                FileUpload {
+                                    path: file_name.clone(),
+                                    content_type: param.content_type.clone(),
+                                    filename: None,
+                                },
+                            );
+                        }
+                        None => {
+                            curl_cmd.form.insert(
+                                param.name.clone(),
+                                param.value.clone().unwrap_or_default(),
+                            );
+                        }
+                    }
+                }
+            } else if let Some(text) = post_data.text.as_ref().filter(|text| !text.is_empty()) {
+                curl_cmd.data = Some(text.clone());
+                curl_cmd.data_is_json = post_data.mime_type.contains("json");
+                let has_content_type = curl_cmd
+                    .headers
+                    .keys()
+                    .any(|key| key.eq_ignore_ascii_case("content-type"));
+                if !has_content_type && !post_data.mime_type.is_empty() {
+                    curl_cmd
+                        .headers
+                        .insert("Content-Type".to_string(), post_data.mime_type.clone());
+                }
+            } else {
+                for param in params {
+                    curl_cmd
+                        .url_encoded_params
+                        .insert(param.name.clone(), param.value.clone().unwrap_or_default());
+                }
+            }
+        }
+
+        Ok(curl_cmd)
+    }
+}
@@ -0,0 +1,285 @@
+use crate::converters::utils;
+use crate::importers::{insert_cookies, resolve_variables, Import, ImportError};
+use crate::{AuthType, CurlCommand, FileUpload};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use regex::Regex;
+use std::collections::HashMap;
+use url::Url;
+
+pub struct HttpFileImporter;
+
+impl Import for HttpFileImporter {
+    fn import(&self, input: &str) -> Result<Vec<CurlCommand>, ImportError> {
+        let variables = self.variables(input);
+
+        let mut blocks: Vec<Vec<&str>> = vec![Vec::new()];
+        for line in input.lines() {
+            if line.trim_start().starts_with("###") {
+                blocks.push(Vec::new());
+            } else if let Some(block) = blocks.last_mut() {
+                block.push(line);
+            }
+        }
+
+        let mut commands = Vec::new();
+        for block in blocks {
+            if let Some(curl_cmd) = self.request(&block, &variables)? {
+                commands.push(curl_cmd);
+            }
+        }
+        Ok(commands)
+    }
+}
+
+impl HttpFileImporter {
+    fn variables(&self, input: &str) -> HashMap<String, String> {
+        let pattern = Regex::new(r"^\s*@([A-Za-z0-9_.-]+)\s*=\s*(.*?)\s*$").unwrap();
+
+        let mut variables = HashMap::new();
+        for line in input.lines() {
+            if let Some(captures) = pattern.captures(line) {
+                let value = resolve_variables(&captures[2], &variables);
+                variables.insert(captures[1].to_string(), value);
+            }
+        }
+        variables
+    }
+
+    fn request(
+        &self,
+        block: &[&str],
+        variables: &HashMap<String, String>,
+    ) -> Result<Option<CurlCommand>, ImportError> {
+        let resolve = |s: &str| resolve_variables(s, variables);
+        let is_comment = |line: &str| line.starts_with('#') || line.starts_with("//");
+
+        let mut lines = block.iter().map(|line| line.trim_end()).peekable();
+        let mut no_redirect = false;
+        while let Some(line) = lines.peek() {
+            let line = line.trim_start();
+            if is_comment(line) {
+                no_redirect |= line.contains("@no-redirect");
+            } else if !line.is_empty() && !line.starts_with('@') {
+                break;
+            }
+            lines.next();
+        }
+
+        let Some(request_line) = lines.next() else {
+            return Ok(None);
+        };
+        let mut request_line = resolve(request_line.trim());
+        while let Some(line) = lines.peek() {
+            let line = line.trim();
+            if !line.starts_with('?') && !line.starts_with('&') {
+                break;
+            }
+            request_line.push_str(&resolve(line));
+            lines.next();
+        }
+
+        let tokens: Vec<&str> = request_line.split_whitespace().collect();
+        let (method, url) = match tokens.as_slice() {
+            [url] => ("GET".to_string(), url.to_string()),
+            [method, url, ..] if method.chars().all(|c| c.is_ascii_alphabetic()) => {
+                (method.to_uppercase(), url.to_string())
+            }
+            _ => {
+                return Err(ImportError::InvalidHttpFile(format!(
+                    "Invalid request line: {}",
+                    request_line
+                )))
+            }
+        };
+        Url::parse(&url)?;
+
+        let mut curl_cmd = CurlCommand {
+            method,
+            url,
+            location: !no_redirect,
+            ..CurlCommand::default()
+        };
+
+        for line in lines.by_ref() {
+            let line = line.trim_start();
+            if line.is_empty() {
+                break;
+            }
+            if is_comment(line) {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(ImportError::InvalidHttpFile(format!(
+                    "Invalid header line: {}",
+                    line
+                )));
+            };
+            let (key, value) = (key.trim().to_string(), resolve(value.trim()));
+
+            if key.eq_ignore_ascii_case("cookie") {
+                insert_cookies(&mut curl_cmd.cookies, &value);
+            } else if !key.eq_ignore_ascii_case("authorization")
+                || !self.authorization(&mut curl_cmd, &value)
+            {
+                curl_cmd.headers.insert(key, value);
+            }
+        }
+
+        let mut body: Vec<String> = lines.map(&resolve).collect();
+        while body.last().is_some_and(|line| line.trim().is_empty()) {
+            body.pop();
+        }
+        if body.is_empty() {
+            return Ok(Some(curl_cmd));
+        }
+
+        let content_type = curl_cmd
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(key, value)| (key.clone(), value.clone()));
+
+        let boundary = content_type.as_ref().and_then(|(_, value)| {
+            value
+                .to_lowercase()
+                .starts_with("multipart/form-data")
+                .then(|| value.split_once("boundary="))
+                .flatten()
+                .map(|(_, boundary)| boundary.trim_matches('"').to_string())
+        });
+
+        if let (Some((key, _)), Some(boundary)) = (&content_type, &boundary) {
+            curl_cmd.headers.remove(key);
+            self.multipart(&mut curl_cmd, &body, boundary);
+        } else if let [line] = body.as_slice() {
+            match file_reference(line) {
+                Some(path) => curl_cmd.binary_file = Some(path),
+                None => curl_cmd.data = Some(line.clone()),
+            }
+        } else {
+            curl_cmd.data = Some(body.join("\n"));
+        }
+
+        curl_cmd.data_is_json = curl_cmd.data.is_some()
+            && content_type.is_some_and(|(_, value)| value.to_lowercase().contains("json"));
+
+        Ok(Some(curl_cmd))
+    }
+
+    fn authorization(&self, curl_cmd: &mut CurlCommand, value: &str) -> bool {
+        let Some((scheme, credentials)) = value.split_once(' ') else {
+            return false;
+        };
+        let credentials = credentials.trim();
+
+        let user_password = credentials
+            .split_once(' ')
+            .or_else(|| credentials.split_once(':'))
+            .map(|(username, password)| (username.to_string(), password.to_string()));
+
+        if scheme.eq_ignore_ascii_case("basic") {
+            let decoded = STANDARD
+                .decode(credentials)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .and_then(|decoded| {
+                    decoded
+                        .split_once(':')
+                        .map(|(username, password)| (username.to_string(), password.to_string()))
+                });
+            curl_cmd.auth = decoded.or(user_password);
+        } else if scheme.eq_ignore_ascii_case("digest") {
+            curl_cmd.auth = user_password;
+            curl_cmd.auth_type = curl_cmd.auth.as_ref().map(|_| AuthType::Digest);
+        }
+
+        curl_cmd.auth.is_some()
+    }
+
+    fn multipart(&self, curl_cmd: &mut CurlCommand, body: &[String], boundary: &str) {
+        let delimiter = format!("--{}", boundary);
+
+        let mut parts: Vec<Vec<&str>> = Vec::new();
+        for line in body {
+            let line = line.as_str();
+            if line.starts_with(&delimiter) {
+                if line.trim_end() == format!("{}--", delimiter) {
+                    break;
+                }
+                parts.push(Vec::new());
+            } else if let Some(part) = parts.last_mut() {
+                part.push(line);
+            }
+        }
+
+        for part in parts {
+            let split = part
+                .iter()
+                .position(|line| line.trim().is_empty())
+                .unwrap_or(part.len());
+            let (headers, content) = part.split_at(split);
+            let content: Vec<&str> = content.iter().skip(1).copied().collect();
+
+            let mut name = None;
+            let mut filename = None;
+            let mut content_type = None;
+            for header in headers {
+                let Some((key, value)) = header.split_once(':') else {
+                    continue;
+                };
+                if key.trim().eq_ignore_ascii_case("content-type") {
+                    content_type = Some(value.trim().to_string());
+                } else if key.trim().eq_ignore_ascii_case("content-disposition") {
+                    for parameter in value.split(';') {
+                        match parameter.trim().split_once('=') {
+                            Some(("name", value)) => name = Some(value.trim_matches('"')),
+                            Some(("filename", value)) => filename = Some(value.trim_matches('"')),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+
+            let Some(name) = name else {
+                continue;
+            };
+            let file = match content.as_slice() {
+                [line] => file_reference(line),
+                _ => None,
+            };
+            match file {
+                Some(path) => {
+                    let filename = filename
+                        .filter(|filename| *filename != utils::file_name(&path))
+                        .map(str::to_string);
+                    curl_cmd.files.insert(
+                        name.to_string(),
+                        FileUpload {
+                            path,
+                            content_type,
+                            filename,
+                        },
+                    );
+                }
+                None => {
+                    curl_cmd.form.insert(name.to_string(), content.join("\n"));
+                }
+            }
+        }
+    }
+}
+
+fn file_reference(line: &str) -> Option<String> {
+    let reference = line.strip_prefix("<@").or_else(|| line.strip_prefix('<'))?;
+    if !reference.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let path = reference.trim();
+    if path.is_empty() {
+        return None;
+    }
+    Some(path.strip_prefix("./").unwrap_or(path).to_string())
+}
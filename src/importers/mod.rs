@@ -0,0 +1,73 @@
+pub mod curl;
+mod error;
+pub mod har;
+pub mod http_file;
+pub mod postman;
+
+pub use curl::CurlImporter;
+pub use error::ImportError;
+pub use har::HarImporter;
+pub use http_file::HttpFileImporter;
+pub use postman::PostmanImporter;
+
+use crate::CurlCommand;
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+
+pub trait Import {
+    fn import(&self, input: &str) -> Result<Vec<CurlCommand>, ImportError>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub url: Option<Regex>,
+    pub method: Option<String>,
+}
+
+impl Filter {
+    pub fn new(url: Option<&str>, method: Option<&str>) -> Result<Self, ImportError> {
+        Ok(Self {
+            url: url.map(Regex::new).transpose()?,
+            method: method.map(|method| method.to_uppercase()),
+        })
+    }
+
+    pub fn matches(&self, curl_cmd: &CurlCommand) -> bool {
+        let url_matches = self
+            .url
+            .as_ref()
+            .is_none_or(|url| url.is_match(&curl_cmd.url));
+        let method_matches = self
+            .method
+            .as_ref()
+            .is_none_or(|method| *method == curl_cmd.method);
+        url_matches && method_matches
+    }
+
+    pub fn apply(&self, commands: Vec<CurlCommand>) -> Vec<CurlCommand> {
+        commands
+            .into_iter()
+            .filter(|curl_cmd| self.matches(curl_cmd))
+            .collect()
+    }
+}
+
+pub(crate) fn insert_cookies(cookies: &mut HashMap<String, String>, cookie_header: &str) {
+    for pair in cookie_header.split(';') {
+        if let Some((key, value)) = pair.split_once('=') {
+            cookies.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+}
+
+pub(crate) fn resolve_variables(s: &str, variables: &HashMap<String, String>) -> String {
+    let pattern = Regex::new(r"\{\{([^{}]+)\}\}").unwrap();
+    pattern
+        .replace_all(s, |captures: &Captures| {
+            variables
+                .get(captures[1].trim())
+                .cloned()
+                .unwrap_or_else(|| captures[0].to_string())
+        })
+        .into_owned()
+}
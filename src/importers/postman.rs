@@ -0,0 +1,197 @@
+use crate::importers::{insert_cookies, resolve_variables, Import, ImportError};
+use crate::postman::{value_string, Auth, Collection, Environment, Item, Request, RequestUrl};
+use crate::{AuthType, CurlCommand, FileUpload};
+use std::collections::HashMap;
+use url::Url;
+
+#[derive(Debug, Clone, Default)]
+pub struct PostmanImporter {
+    environment: HashMap<String, String>,
+}
+
+impl Import for PostmanImporter {
+    fn import(&self, input: &str) -> Result<Vec<CurlCommand>, ImportError> {
+        let collection: Collection = serde_json::from_str(input)?;
+        if !collection.info.schema.contains("/v2.") {
+            return Err(ImportError::UnsupportedCollection(collection.info.schema));
+        }
+
+        let mut variables: HashMap<String, String> = collection
+            .variable
+            .iter()
+            .map(|variable| (variable.key.clone(), value_string(&variable.value)))
+            .collect();
+        variables.extend(self.environment.clone());
+
+        let mut commands = Vec::new();
+        self.collect(
+            &collection.item,
+            collection.auth.as_ref(),
+            &variables,
+            &mut commands,
+        )?;
+        Ok(commands)
+    }
+}
+
+impl PostmanImporter {
+    pub fn with_environment(mut self, input: &str) -> Result<Self, ImportError> {
+        let environment: Environment = serde_json::from_str(input)?;
+        for value in environment.values.into_iter().filter(|value| value.enabled) {
+            self.environment
+                .insert(value.key, value_string(&value.value));
+        }
+        Ok(self)
+    }
+
+    fn collect(
+        &self,
+        items: &[Item],
+        inherited_auth: Option<&Auth>,
+        variables: &HashMap<String, String>,
+        commands: &mut Vec<CurlCommand>,
+    ) -> Result<(), ImportError> {
+        for item in items {
+            let auth = item.auth.as_ref().or(inherited_auth);
+            match &item.request {
+                Some(request) => commands.push(self.command(item, request, auth, variables)?),
+                None => self.collect(&item.item, auth, variables, commands)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn command(
+        &self,
+        item: &Item,
+        request: &Request,
+        inherited_auth: Option<&Auth>,
+        variables: &HashMap<String, String>,
+    ) -> Result<CurlCommand, ImportError> {
+        let resolve = |s: &str| resolve_variables(s, variables);
+
+        let raw_url = match &request.url {
+            RequestUrl::Raw(raw) => raw.clone(),
+            RequestUrl::Detailed(detail) => detail.raw.clone(),
+        };
+        let mut url = resolve(&raw_url);
+        if Url::parse(&url) == Err(url::ParseError::RelativeUrlWithoutBase) {
+            url = format!("http://{}", url);
+        }
+        Url::parse(&url)?;
+
+        let mut curl_cmd = CurlCommand {
+            method: request.method.to_uppercase(),
+            url,
+            ..CurlCommand::default()
+        };
+
+        for header in request.header.iter().filter(|header| !header.disabled) {
+            if header.key.eq_ignore_ascii_case("cookie") {
+                insert_cookies(&mut curl_cmd.cookies, &resolve(&header.value));
+            } else {
+                curl_cmd
+                    .headers
+                    .insert(resolve(&header.key), resolve(&header.value));
+            }
+        }
+
+        if let Some(auth) = request.auth.as_ref().or(inherited_auth) {
+            let attribute = |key: &str| resolve(&auth.attribute(key).unwrap_or_default());
+            let credentials = (attribute("username"), attribute("password"));
+            match auth.auth_type.as_str() {
+                "basic" => curl_cmd.auth = Some(credentials),
+                "digest" => {
+                    curl_cmd.auth = Some(credentials);
+                    curl_cmd.auth_type = Some(AuthType::Digest);
+                }
+                "ntlm" => {
+                    curl_cmd.auth = Some(credentials);
+                    curl_cmd.auth_type = Some(AuthType::Ntlm);
+                }
+                "bearer" => {
+                    let token = attribute("token");
+                    curl_cmd
+                        .headers
+                        .insert("Authorization".to_string(), format!("Bearer {}", token));
+                    curl_cmd.oauth2_bearer = Some(token);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(body) = &request.body {
+            match body.mode.as_str() {
+                "raw" => {
+                    let raw = resolve(body.raw.as_deref().unwrap_or_default());
+                    let is_json = body
+                        .options
+                        .as_ref()
+                        .and_then(|options| options.raw.as_ref())
+                        .is_some_and(|raw| raw.language == "json");
+                    if !raw.is_empty() {
+                        curl_cmd.data = Some(raw);
+                        curl_cmd.data_is_json = is_json;
+                    }
+                    let has_content_type = curl_cmd
+                        .headers
+                        .keys()
+                        .any(|key| key.eq_ignore_ascii_case("content-type"));
+                    if is_json && !has_content_type {
+                        curl_cmd
+                            .headers
+                            .insert("Content-Type".to_string(), "application/json".to_string());
+                    }
+                }
+                "urlencoded" => {
+                    for param in body.urlencoded.iter().flatten().filter(|p| !p.disabled) {
+                        curl_cmd.url_encoded_params.insert(
+                            resolve(&param.key),
+                            resolve(param.value.as_deref().unwrap_or_default()),
+                        );
+                    }
+                }
+                "formdata" => {
+                    for param in body.formdata.iter().flatten().filter(|p| !p.disabled) {
+                        if param.param_type.as_deref() == Some("file") {
+                            let src = match &param.src {
+                                serde_json::Value::Array(sources) => {
+                                    sources.first().map(value_string).unwrap_or_default()
+                                }
+                                src => value_string(src),
+                            };
+                            curl_cmd.files.insert(
+                                resolve(&param.key),
+                                FileUpload {
+                                    path: resolve(&src),
+                                    content_type: param.content_type.clone(),
+                                    filename: None,
+                                },
+                            );
+                        } else {
+                            curl_cmd.form.insert(
+                                resolve(&param.key),
+                                resolve(param.value.as_deref().unwrap_or_default()),
+                            );
+                        }
+                    }
+                }
+                "file" => {
+                    curl_cmd.binary_file = body
+                        .file
+                        .as_ref()
+                        .and_then(|file| file.src.as_deref())
+                        .map(resolve);
+                }
+                _ => {}
+            }
+        }
+
+        let behavior = item.protocol_profile_behavior.clone().unwrap_or_default();
+        curl_cmd.location = behavior.follow_redirects.unwrap_or(true);
+        curl_cmd.max_redirs = behavior.max_redirects;
+        curl_cmd.insecure = behavior.strict_ssl == Some(false);
+
+        Ok(curl_cmd)
+    }
+}
@@ -1,8 +1,13 @@
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
 
 pub mod converters;
+pub mod har;
+pub mod importers;
 pub mod parser;
+pub mod postman;
+pub mod validation;
 
 
 pub use parser::{parse_curl_command, ParseError};
@@ -11,6 +16,42 @@ pub use parser::{parse_curl_command, ParseError};
 pub enum ConversionFormat {
     PythonRequests,
     NodeJS,
+    JavaHttpClient,
+    JavaOkHttp,
+    KotlinKtor,
+    CSharpHttpClient,
+    PhpGuzzle,
+    PhpCurl,
+    RubyNetHttp,
+    RubyFaraday,
+    SwiftUrlSession,
+    DartHttp,
+    DartDio,
+    PowerShell,
+    Wget,
+    Httpie,
+    CLibcurl,
+    ElixirReq,
+    ElixirHTTPoison,
+    ErlangHttpc,
+    RHttr2,
+    JuliaHttp,
+    PerlLwp,
+    LuaRestyHttp,
+    Har,
+    PostmanCollection,
+    InsomniaExport,
+    BrunoCollection,
+    HttpFile,
+    Hurl,
+    OpenApiJson,
+    OpenApiYaml,
+    K6,
+    PythonLocust,
+    AnsibleUri,
+    TerraformHttp,
+    KubernetesProbe,
+    JsonAst,
 }
 
 impl std::str::FromStr for ConversionFormat {
@@ -20,22 +61,87 @@ impl std::str::FromStr for ConversionFormat {
         match s.to_lowercase().as_str() {
             "python" | "python-requests" | "requests" => Ok(ConversionFormat::PythonRequests),
             "node" | "nodejs" | "javascript" | "js" => Ok(ConversionFormat::NodeJS),
+            "java" | "java-httpclient" | "httpclient" => Ok(ConversionFormat::JavaHttpClient),
+            "okhttp" | "java-okhttp" => Ok(ConversionFormat::JavaOkHttp),
+            "kotlin" | "ktor" | "kotlin-ktor" => Ok(ConversionFormat::KotlinKtor),
+            "csharp" | "c#" | "dotnet" | "csharp-httpclient" => {
+                Ok(ConversionFormat::CSharpHttpClient)
+            }
+            "php" | "guzzle" | "php-guzzle" => Ok(ConversionFormat::PhpGuzzle),
+            "php-curl" | "php-ext-curl" => Ok(ConversionFormat::PhpCurl),
+            "ruby" | "net-http" | "ruby-net-http" => Ok(ConversionFormat::RubyNetHttp),
+            "faraday" | "ruby-faraday" => Ok(ConversionFormat::RubyFaraday),
+            "swift" | "urlsession" | "swift-urlsession" => Ok(ConversionFormat::SwiftUrlSession),
+            "dart" | "dart-http" => Ok(ConversionFormat::DartHttp),
+            "dio" | "dart-dio" => Ok(ConversionFormat::DartDio),
+            "powershell" | "pwsh" | "invoke-restmethod" => Ok(ConversionFormat::PowerShell),
+            "wget" => Ok(ConversionFormat::Wget),
+            "httpie" | "http" => Ok(ConversionFormat::Httpie),
+            "c" | "libcurl" | "c-libcurl" => Ok(ConversionFormat::CLibcurl),
+            "elixir" | "req" | "elixir-req" => Ok(ConversionFormat::ElixirReq),
+            "httpoison" | "elixir-httpoison" => Ok(ConversionFormat::ElixirHTTPoison),
+            "erlang" | "httpc" | "erlang-httpc" => Ok(ConversionFormat::ErlangHttpc),
+            "r" | "httr2" | "r-httr2" => Ok(ConversionFormat::RHttr2),
+            "julia" | "http.jl" | "julia-http" => Ok(ConversionFormat::JuliaHttp),
+            "perl" | "lwp" | "perl-lwp" => Ok(ConversionFormat::PerlLwp),
+            "lua" | "resty-http" | "lua-resty-http" => Ok(ConversionFormat::LuaRestyHttp),
+            "har" | "har-1.2" => Ok(ConversionFormat::Har),
+            "postman" | "postman-collection" => Ok(ConversionFormat::PostmanCollection),
+            "insomnia" | "insomnia-v4" => Ok(ConversionFormat::InsomniaExport),
+            "bruno" | "bru" => Ok(ConversionFormat::BrunoCollection),
+            "http-file" | ".http" | "rest-client" | "jetbrains-http" => {
+                Ok(ConversionFormat::HttpFile)
+            }
+            "hurl" => Ok(ConversionFormat::Hurl),
+            "openapi" | "openapi-json" => Ok(ConversionFormat::OpenApiJson),
+            "openapi-yaml" | "openapi-yml" => Ok(ConversionFormat::OpenApiYaml),
+            "k6" | "k6-js" => Ok(ConversionFormat::K6),
+            "locust" | "python-locust" => Ok(ConversionFormat::PythonLocust),
+            "ansible" | "ansible-uri" => Ok(ConversionFormat::AnsibleUri),
+            "terraform" | "terraform-http" => Ok(ConversionFormat::TerraformHttp),
+            "kubernetes" | "k8s" | "k8s-probe" => Ok(ConversionFormat::KubernetesProbe),
+            "json" | "json-ast" => Ok(ConversionFormat::JsonAst),
             _ => Err(format!("Unknown conversion format: {}", s)),
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ImportFormat {
+    Curl,
+    Har,
+    Postman,
+    HttpFile,
+}
+
+impl std::str::FromStr for ImportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "curl" => Ok(ImportFormat::Curl),
+            "har" => Ok(ImportFormat::Har),
+            "postman" | "postman-collection" => Ok(ImportFormat::Postman),
+            "http-file" | ".http" | "rest-client" => Ok(ImportFormat::HttpFile),
+            _ => Err(format!("Unknown import format: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CurlCommand {
     pub method: String,
     pub url: String,
+    #[serde(serialize_with = "sorted")]
     pub headers: HashMap<String, String>,
     pub data: Option<String>,
     pub data_is_json: bool,
     pub binary_file: Option<String>,
+    #[serde(serialize_with = "sorted")]
     pub form: HashMap<String, String>,
     pub auth: Option<(String, String)>,
     pub oauth2_bearer: Option<String>,
+    #[serde(serialize_with = "sorted")]
     pub cookies: HashMap<String, String>,
     pub user_agent: Option<String>,
     pub insecure: bool,
@@ -48,7 +154,9 @@ pub struct CurlCommand {
     pub max_redirs: Option<u32>,
     pub output: Option<String>,
     pub auth_type: Option<AuthType>,
+    #[serde(serialize_with = "sorted")]
     pub url_encoded_params: HashMap<String, String>,
+    #[serde(serialize_with = "sorted")]
     pub files: HashMap<String, FileUpload>,
     pub ssl_options: SslOptions,
     pub write_out: Option<String>,
@@ -56,7 +164,7 @@ pub struct CurlCommand {
     pub no_alpn: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum AuthType {
     Basic,
     Digest,
@@ -64,14 +172,14 @@ pub enum AuthType {
     Negotiate,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FileUpload {
     pub path: String,
     pub content_type: Option<String>,
     pub filename: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct SslOptions {
     pub cacert: Option<String>,
     pub cert: Option<String>,
@@ -80,6 +188,13 @@ pub struct SslOptions {
     pub key_type: Option<String>,
 }
 
+fn sorted<V: Serialize, S: Serializer>(
+    map: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
 impl Default for CurlCommand {
     fn default() -> Self {
         Self {
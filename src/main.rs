@@ -1,60 +1,338 @@
-use clap::Parser;
-use curl_parser::converters::nodejs::Axios;
-use curl_parser::converters::python::Requests;
-use curl_parser::converters::Convert;
-use curl_parser::{parse_curl_command, ConversionFormat, ParseError};
-use std::process;
+use clap::{Args, Parser, Subcommand};
+use curl_parser::converters::ansible::AnsibleUri;
+use curl_parser::converters::bruno::BrunoCollection;
+use curl_parser::converters::c::Libcurl;
+use curl_parser::converters::csharp::HttpClient as CSharpHttpClient;
+use curl_parser::converters::dart::{Dio, Http as DartHttp};
+use curl_parser::converters::elixir::{HTTPoison, Req};
+use curl_parser::converters::erlang::Httpc;
+use curl_parser::converters::har::HarLog;
+use curl_parser::converters::http_file::RestClient;
+use curl_parser::converters::hurl::HurlEntry;
+use curl_parser::converters::insomnia::InsomniaExport;
+use curl_parser::converters::java::{HttpClient, OkHttp};
+use curl_parser::converters::json::JsonAst;
+use curl_parser::converters::julia::HttpJl;
+use curl_parser::converters::kotlin::Ktor;
+use curl_parser::converters::kubernetes::KubernetesProbe;
+use curl_parser::converters::lua::RestyHttp;
+use curl_parser::converters::nodejs::{Axios, K6};
+use curl_parser::converters::openapi::{OpenApiSpec, SpecFormat};
+use curl_parser::converters::perl::Lwp;
+use curl_parser::converters::php::{Curl, Guzzle};
+use curl_parser::converters::postman::PostmanCollection;
+use curl_parser::converters::powershell::InvokeRestMethod;
+use curl_parser::converters::python::{Locust, Requests};
+use curl_parser::converters::r::Httr2;
+use curl_parser::converters::ruby::{Faraday, NetHttp};
+use curl_parser::converters::shell::{Httpie, Wget};
+use curl_parser::converters::swift::UrlSession;
+use curl_parser::converters::terraform::TerraformHttp;
+use curl_parser::converters::{Convert, ConvertMany, ConvertTree};
+use curl_parser::importers::{
+    CurlImporter, Filter, HarImporter, HttpFileImporter, Import, PostmanImporter,
+};
+use curl_parser::validation::SpecValidator;
+use curl_parser::{parse_curl_command, ConversionFormat, CurlCommand, ImportFormat, ParseError};
+use std::path::{Component, Path};
+use std::{fs, process};
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
     
-    #[arg(required = true)]
-    curl_command: String,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    
+    #[command(flatten)]
+    input: Input,
 
     
     #[arg(short, long, default_value = "python-requests")]
     format: String,
+
+    
+    #[arg(long)]
+    output_dir: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    
+    Validate {
+        
+        #[arg(long)]
+        spec: String,
+
+        
+        #[command(flatten)]
+        input: Input,
+    },
+
+    
+    Schema,
+}
+
+#[derive(Args)]
+struct Input {
+    
+    #[arg(required = true)]
+    curl_command: Option<String>,
+
+    
+    #[arg(long)]
+    from: Option<String>,
+
+    
+    #[arg(long)]
+    filter: Option<String>,
+
+    
+    #[arg(long)]
+    method: Option<String>,
+
+    
+    #[arg(long)]
+    environment: Option<String>,
 }
 
 fn main() {
     let args = Cli::parse();
 
     
-    let result = parse_curl_command(&args.curl_command);
+    match &args.command {
+        Some(Command::Validate { spec, input }) => {
+            validate(spec, &read_commands(input));
+            return;
+        }
+        Some(Command::Schema) => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&JsonAst::schema()).unwrap_or_default()
+            );
+            return;
+        }
+        None => {}
+    }
+
+    
+    let commands = read_commands(&args.input);
 
-    match result {
-        Ok(parsed) => {
-            
-            match args.format.parse::<ConversionFormat>() {
-                Ok(format) => match format {
-                    ConversionFormat::PythonRequests => {
-                        let converter = Requests;
-                        println!("{}", converter.convert(&parsed));
+    
+    match args.format.parse::<ConversionFormat>() {
+        Ok(format) => convert(&format, &commands, args.output_dir.as_deref()),
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn read_commands(input: &Input) -> Vec<CurlCommand> {
+    let curl_command = input.curl_command.as_deref().unwrap_or_default();
+
+    match &input.from {
+        Some(from) => import_commands(input, curl_command, from),
+        None => match parse_curl_command(curl_command) {
+            Ok(parsed) => vec![parsed],
+            Err(err) => {
+                match err {
+                    ParseError::MissingUrl => {
+                        eprintln!("Error: Missing URL in curl command");
                     }
-                    ConversionFormat::NodeJS => {
-                        let converter = Axios;
-                        println!("{}", converter.convert(&parsed));
+                    ParseError::InvalidUrl(err) => {
+                        eprintln!("Error: Invalid URL: {}", err);
+                    }
+                    ParseError::ParseFailure(msg) => {
+                        eprintln!("Error: {}", msg);
                     }
-                },
-                Err(err) => {
-                    eprintln!("Error: {}", err);
-                    process::exit(1);
                 }
+                process::exit(1);
             }
+        },
+    }
+}
+
+fn import_commands(input: &Input, path: &str, from: &str) -> Vec<CurlCommand> {
+    let format = from.parse::<ImportFormat>().unwrap_or_else(|err| {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    });
+
+    let contents = read_input(path);
+
+    let imported = match format {
+        ImportFormat::Curl => CurlImporter.import(&contents),
+        ImportFormat::Har => HarImporter.import(&contents),
+        ImportFormat::Postman => match &input.environment {
+            Some(environment) => PostmanImporter::default()
+                .with_environment(&read_input(environment))
+                .and_then(|importer| importer.import(&contents)),
+            None => PostmanImporter::default().import(&contents),
+        },
+        ImportFormat::HttpFile => HttpFileImporter.import(&contents),
+    };
+
+    let filter = Filter::new(input.filter.as_deref(), input.method.as_deref());
+    let commands = imported
+        .and_then(|commands| Ok(filter?.apply(commands)))
+        .unwrap_or_else(|err| {
+            eprintln!("Error: {}", err);
+            process::exit(1);
+        });
+
+    if commands.is_empty() {
+        eprintln!("Error: No requests matched in {}", path);
+        process::exit(1);
+    }
+
+    commands
+}
+
+fn validate(spec: &str, commands: &[CurlCommand]) {
+    let validator = SpecValidator::new(&read_input(spec)).unwrap_or_else(|err| {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    });
+
+    let mut failed = false;
+    for parsed in commands {
+        let mismatches = validator.validate(parsed);
+        if mismatches.is_empty() {
+            println!("ok: {} {}", parsed.method, parsed.url);
+            continue;
         }
-        Err(err) => {
-            match err {
-                ParseError::MissingUrl => {
-                    eprintln!("Error: Missing URL in curl command");
-                }
-                ParseError::InvalidUrl(err) => {
-                    eprintln!("Error: Invalid URL: {}", err);
-                }
-                ParseError::ParseFailure(msg) => {
-                    eprintln!("Error: {}", msg);
+
+        failed = true;
+        println!("mismatch: {} {}", parsed.method, parsed.url);
+        for mismatch in mismatches {
+            println!("  - {}", mismatch);
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn read_input(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("Error: Failed to read {}: {}", path, err);
+        process::exit(1);
+    })
+}
+
+fn write_tree(output_dir: &str, files: &[(String, String)]) {
+    for (path, contents) in files {
+        let relative = Path::new(path);
+        if relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_)))
+        {
+            eprintln!("Error: Refusing to write {} outside {}", path, output_dir);
+            process::exit(1);
+        }
+
+        let path = Path::new(output_dir).join(relative);
+        let written = match path.parent() {
+            Some(parent) => fs::create_dir_all(parent),
+            None => Ok(()),
+        }
+        .and_then(|_| fs::write(&path, contents));
+
+        if let Err(err) = written {
+            eprintln!("Error: Failed to write {}: {}", path.display(), err);
+            process::exit(1);
+        }
+        eprintln!("Wrote {}", path.display());
+    }
+}
+
+fn convert(format: &ConversionFormat, commands: &[CurlCommand], output_dir: Option<&str>) {
+    match format {
+        ConversionFormat::PythonRequests => convert_each(&Requests, commands),
+        ConversionFormat::NodeJS => convert_each(&Axios, commands),
+        ConversionFormat::JavaHttpClient => convert_each(&HttpClient, commands),
+        ConversionFormat::JavaOkHttp => convert_each(&OkHttp, commands),
+        ConversionFormat::KotlinKtor => convert_each(&Ktor, commands),
+        ConversionFormat::CSharpHttpClient => convert_each(&CSharpHttpClient, commands),
+        ConversionFormat::PhpGuzzle => convert_each(&Guzzle, commands),
+        ConversionFormat::PhpCurl => convert_each(&Curl, commands),
+        ConversionFormat::RubyNetHttp => convert_each(&NetHttp, commands),
+        ConversionFormat::RubyFaraday => convert_each(&Faraday, commands),
+        ConversionFormat::SwiftUrlSession => convert_each(&UrlSession, commands),
+        ConversionFormat::DartHttp => convert_each(&DartHttp, commands),
+        ConversionFormat::DartDio => convert_each(&Dio, commands),
+        ConversionFormat::PowerShell => convert_each(&InvokeRestMethod, commands),
+        ConversionFormat::Wget => convert_each(&Wget, commands),
+        ConversionFormat::Httpie => convert_each(&Httpie, commands),
+        ConversionFormat::CLibcurl => convert_each(&Libcurl, commands),
+        ConversionFormat::ElixirReq => convert_each(&Req, commands),
+        ConversionFormat::ElixirHTTPoison => convert_each(&HTTPoison, commands),
+        ConversionFormat::ErlangHttpc => convert_each(&Httpc, commands),
+        ConversionFormat::RHttr2 => convert_each(&Httr2, commands),
+        ConversionFormat::JuliaHttp => convert_each(&HttpJl, commands),
+        ConversionFormat::PerlLwp => convert_each(&Lwp, commands),
+        ConversionFormat::LuaRestyHttp => convert_each(&RestyHttp, commands),
+        ConversionFormat::Har => convert_many(&HarLog, commands),
+        ConversionFormat::PostmanCollection => convert_many(&PostmanCollection, commands),
+        ConversionFormat::InsomniaExport => convert_many(&InsomniaExport, commands),
+        ConversionFormat::BrunoCollection => match output_dir {
+            Some(output_dir) => {
+                let converter = BrunoCollection;
+                for parsed in commands {
+                    print_warnings(&converter.warnings(parsed));
                 }
+                write_tree(output_dir, &converter.convert_tree(commands));
             }
-            process::exit(1);
+            None if commands.len() > 1 => {
+                eprintln!("Error: Bruno collections with several requests need --output-dir");
+                process::exit(1);
+            }
+            None => convert_each(&BrunoCollection, commands),
+        },
+        ConversionFormat::HttpFile => convert_many(&RestClient, commands),
+        ConversionFormat::Hurl => convert_many(&HurlEntry, commands),
+        ConversionFormat::K6 => convert_many(&K6, commands),
+        ConversionFormat::PythonLocust => convert_many(&Locust, commands),
+        ConversionFormat::JsonAst => convert_many(&JsonAst, commands),
+        ConversionFormat::TerraformHttp => convert_each(&TerraformHttp, commands),
+        ConversionFormat::KubernetesProbe => convert_each(&KubernetesProbe, commands),
+        ConversionFormat::AnsibleUri => convert_many(&AnsibleUri, commands),
+        ConversionFormat::OpenApiJson | ConversionFormat::OpenApiYaml => {
+            convert_many(&open_api_spec(format), commands)
         }
     }
 }
+
+fn convert_each<C: Convert>(converter: &C, commands: &[CurlCommand]) {
+    for (index, parsed) in commands.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        print_warnings(&converter.warnings(parsed));
+        println!("{}", converter.convert(parsed));
+    }
+}
+
+fn convert_many<C: ConvertMany>(converter: &C, commands: &[CurlCommand]) {
+    for parsed in commands {
+        print_warnings(&converter.warnings(parsed));
+    }
+    println!("{}", converter.convert_many(commands));
+}
+
+fn open_api_spec(format: &ConversionFormat) -> OpenApiSpec {
+    let format = match format {
+        ConversionFormat::OpenApiYaml => SpecFormat::Yaml,
+        _ => SpecFormat::Json,
+    };
+    OpenApiSpec { format }
+}
+
+fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        eprintln!("Warning: {}", warning);
+    }
+}
@@ -15,7 +15,7 @@ pub fn parse_curl_command(command: &str) -> Result<CurlCommand, ParseError> {
     let tokens = tokenize_command(command)?;
 
     
-    let skip_count = if tokens.get(0).map_or(false, |t| t == "curl") {
+    let skip_count = if tokens.first().is_some_and(|t| t == "curl") {
         1
     } else {
         0
@@ -73,8 +73,8 @@ pub fn parse_form_field(
         let value = parts[1].trim();
 
         
-        if value.starts_with('@') {
-            let file_parts: Vec<&str> = value[1..].split(';').collect();
+        if let Some(file_spec) = value.strip_prefix('@') {
+            let file_parts: Vec<&str> = file_spec.split(';').collect();
             let file_path = file_parts[0].trim();
 
             let mut file_upload = FileUpload {
@@ -85,10 +85,10 @@ pub fn parse_form_field(
 
             
             for part in file_parts.iter().skip(1) {
-                if part.starts_with("type=") {
-                    file_upload.content_type = Some(part[5..].to_string());
-                } else if part.starts_with("filename=") {
-                    file_upload.filename = Some(part[9..].to_string());
+                if let Some(content_type) = part.strip_prefix("type=") {
+                    file_upload.content_type = Some(content_type.to_string());
+                } else if let Some(filename) = part.strip_prefix("filename=") {
+                    file_upload.filename = Some(filename.to_string());
                 }
             }
 
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const SCHEMA_V2_1: &str =
+    "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Collection {
+    pub info: Info,
+    #[serde(default)]
+    pub item: Vec<Item>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<Auth>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variable: Vec<Variable>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Info {
+    pub name: String,
+    pub schema: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Item {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub item: Vec<Item>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<Auth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request: Option<Request>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol_profile_behavior: Option<ProtocolProfileBehavior>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Request {
+    #[serde(default = "default_method")]
+    pub method: String,
+    #[serde(default)]
+    pub header: Vec<Header>,
+    pub url: RequestUrl,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<Auth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Body>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RequestUrl {
+    Raw(String),
+    Detailed(UrlDetail),
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UrlDetail {
+    #[serde(default)]
+    pub raw: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub query: Vec<QueryParam>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub key: String,
+    #[serde(default)]
+    pub value: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryParam {
+    pub key: String,
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Auth {
+    #[serde(rename = "type")]
+    pub auth_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub basic: Option<Vec<AuthAttribute>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<Vec<AuthAttribute>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ntlm: Option<Vec<AuthAttribute>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bearer: Option<Vec<AuthAttribute>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthAttribute {
+    pub key: String,
+    #[serde(default)]
+    pub value: Value,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub attribute_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Body {
+    pub mode: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub urlencoded: Option<Vec<FormParam>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formdata: Option<Vec<FormParam>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<FileSource>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<BodyOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormParam {
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub src: Value,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub param_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileSource {
+    #[serde(default)]
+    pub src: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BodyOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<RawOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawOptions {
+    pub language: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolProfileBehavior {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow_redirects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_redirects: Option<u32>,
+    #[serde(rename = "strictSSL", default, skip_serializing_if = "Option::is_none")]
+    pub strict_ssl: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Variable {
+    pub key: String,
+    #[serde(default)]
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Environment {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub values: Vec<EnvironmentValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentValue {
+    pub key: String,
+    #[serde(default)]
+    pub value: Value,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+impl Auth {
+    pub fn attributes(&self) -> &[AuthAttribute] {
+        let attributes = match self.auth_type.as_str() {
+            "basic" => &self.basic,
+            "digest" => &self.digest,
+            "ntlm" => &self.ntlm,
+            "bearer" => &self.bearer,
+            _ => return &[],
+        };
+        attributes.as_deref().unwrap_or_default()
+    }
+
+    pub fn attribute(&self, key: &str) -> Option<String> {
+        self.attributes()
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| value_string(&attribute.value))
+    }
+}
+
+pub fn value_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn default_method() -> String {
+    "GET".to_string()
+}
+
+fn default_enabled() -> bool {
+    true
+}
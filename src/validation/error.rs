@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SpecError {
+    #[error("Invalid OpenAPI spec: {0}")]
+    InvalidSpec(#[from] serde_yaml_ng::Error),
+
+    #[error("OpenAPI spec has no paths")]
+    MissingPaths,
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum Mismatch {
+    #[error("no operation matches path {0}")]
+    UnknownPath(String),
+
+    #[error("{path} has no {method} operation")]
+    UnknownMethod { method: String, path: String },
+
+    #[error("missing required header {0}")]
+    MissingHeader(String),
+
+    #[error("missing required query parameter {0}")]
+    MissingQuery(String),
+
+    #[error("missing required cookie {0}")]
+    MissingCookie(String),
+
+    #[error("missing required request body")]
+    MissingBody,
+
+    #[error("content type {actual} is not accepted, expected {}", .expected.join(", "))]
+    WrongContentType {
+        actual: String,
+        expected: Vec<String>,
+    },
+
+    #[error("request body does not match the schema: {0}")]
+    InvalidBody(String),
+}
@@ -0,0 +1,291 @@
+mod error;
+
+pub use error::{Mismatch, SpecError};
+
+use crate::converters::utils;
+use crate::CurlCommand;
+use serde_json::Value;
+use url::Url;
+
+const MAX_REF_DEPTH: usize = 32;
+const IGNORED_HEADERS: [&str; 3] = ["accept", "authorization", "content-type"];
+
+pub struct SpecValidator {
+    spec: Value,
+}
+
+impl SpecValidator {
+    pub fn new(input: &str) -> Result<Self, SpecError> {
+        let spec: Value = serde_yaml_ng::from_str(input)?;
+        if !spec["paths"].is_object() {
+            return Err(SpecError::MissingPaths);
+        }
+        Ok(Self { spec })
+    }
+
+    pub fn validate(&self, curl_cmd: &CurlCommand) -> Vec<Mismatch> {
+        let Ok(url) = Url::parse(&curl_cmd.url) else {
+            return vec![Mismatch::UnknownPath(curl_cmd.url.clone())];
+        };
+
+        let (path_item, operation) = match self.operation(&url, &curl_cmd.method) {
+            Ok(found) => found,
+            Err(mismatch) => return vec![mismatch],
+        };
+
+        let mut mismatches = self.check_parameters(curl_cmd, &url, path_item, operation);
+        mismatches.extend(self.check_body(curl_cmd, operation));
+        mismatches
+    }
+
+    fn operation(&self, url: &Url, method: &str) -> Result<(&Value, &Value), Mismatch> {
+        let request_path = url.path();
+
+        let mut best: Option<(usize, &String, &Value)> = None;
+        for prefix in self.server_paths() {
+            let Some(rest) = request_path.strip_prefix(prefix.as_str()) else {
+                continue;
+            };
+            let rest = if rest.is_empty() { "/" } else { rest };
+            if !rest.starts_with('/') {
+                continue;
+            }
+
+            for (template, path_item) in self.spec["paths"].as_object().into_iter().flatten() {
+                let Some(templated) = match_template(template, rest) else {
+                    continue;
+                };
+                if best.is_none_or(|(fewest, ..)| templated < fewest) {
+                    best = Some((templated, template, path_item));
+                }
+            }
+        }
+
+        let Some((_, template, path_item)) = best else {
+            return Err(Mismatch::UnknownPath(request_path.to_string()));
+        };
+        let path_item = self.resolve(path_item);
+        match path_item.get(method.to_lowercase()) {
+            Some(operation) => Ok((path_item, operation)),
+            None => Err(Mismatch::UnknownMethod {
+                method: method.to_uppercase(),
+                path: template.clone(),
+            }),
+        }
+    }
+
+    fn server_paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self.spec["servers"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|server| server["url"].as_str())
+            .filter_map(|server_url| match Url::parse(server_url) {
+                Ok(server_url) => Some(server_url.path().to_string()),
+                Err(_) => server_url.starts_with('/').then(|| server_url.to_string()),
+            })
+            .map(|path| path.trim_end_matches('/').to_string())
+            .collect();
+        if !paths.iter().any(String::is_empty) {
+            paths.push(String::new());
+        }
+        paths
+    }
+
+    fn check_parameters(
+        &self,
+        curl_cmd: &CurlCommand,
+        url: &Url,
+        path_item: &Value,
+        operation: &Value,
+    ) -> Vec<Mismatch> {
+        let mut parameters: Vec<&Value> = Vec::new();
+        for parameter in [&path_item["parameters"], &operation["parameters"]]
+            .into_iter()
+            .filter_map(Value::as_array)
+            .flatten()
+            .map(|parameter| self.resolve(parameter))
+        {
+            parameters.retain(|known| {
+                known["name"] != parameter["name"] || known["in"] != parameter["in"]
+            });
+            parameters.push(parameter);
+        }
+
+        let mut mismatches = Vec::new();
+        for parameter in parameters {
+            if parameter["required"] != Value::Bool(true) {
+                continue;
+            }
+            let Some(name) = parameter["name"].as_str() else {
+                continue;
+            };
+
+            let missing = match parameter["in"].as_str() {
+                Some("query") => !url.query_pairs().any(|(key, _)| key == name),
+                Some("header") if !IGNORED_HEADERS.contains(&name.to_lowercase().as_str()) => {
+                    !utils::request_headers(curl_cmd)
+                        .iter()
+                        .any(|(key, _)| key.eq_ignore_ascii_case(name))
+                }
+                Some("cookie") => !curl_cmd.cookies.contains_key(name),
+                _ => false,
+            };
+            if !missing {
+                continue;
+            }
+
+            mismatches.push(match parameter["in"].as_str() {
+                Some("query") => Mismatch::MissingQuery(name.to_string()),
+                Some("header") => Mismatch::MissingHeader(name.to_string()),
+                _ => Mismatch::MissingCookie(name.to_string()),
+            });
+        }
+        mismatches
+    }
+
+    fn check_body(&self, curl_cmd: &CurlCommand, operation: &Value) -> Vec<Mismatch> {
+        let request_body = self.resolve(&operation["requestBody"]);
+
+        let Some(media_type) = request_media_type(curl_cmd) else {
+            if request_body["required"] == Value::Bool(true) {
+                return vec![Mismatch::MissingBody];
+            }
+            return Vec::new();
+        };
+        let Some(content) = request_body["content"].as_object() else {
+            return Vec::new();
+        };
+
+        let Some(media) = content
+            .iter()
+            .find(|(accepted, _)| media_range_matches(accepted, &media_type))
+            .map(|(_, media)| media)
+        else {
+            return vec![Mismatch::WrongContentType {
+                actual: media_type,
+                expected: content.keys().cloned().collect(),
+            }];
+        };
+
+        match (&curl_cmd.data, media.get("schema")) {
+            (Some(data), Some(schema)) if media_type.contains("json") => {
+                self.check_json(data, schema)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn check_json(&self, data: &str, schema: &Value) -> Vec<Mismatch> {
+        let instance: Value = match serde_json::from_str(data) {
+            Ok(instance) => instance,
+            Err(err) => return vec![Mismatch::InvalidBody(format!("invalid JSON: {}", err))],
+        };
+
+        let mut root = match schema {
+            Value::Object(_) => schema.clone(),
+            _ => serde_json::json!({"allOf": [schema]}),
+        };
+        if let Some(components) = self.spec.get("components") {
+            root["components"] = components.clone();
+        }
+
+        let validator = match jsonschema::validator_for(&root) {
+            Ok(validator) => validator,
+            Err(err) => return vec![Mismatch::InvalidBody(format!("unusable schema: {}", err))],
+        };
+        validator
+            .iter_errors(&instance)
+            .map(|err| {
+                let location = err.instance_path().to_string();
+                if location.is_empty() {
+                    Mismatch::InvalidBody(err.to_string())
+                } else {
+                    Mismatch::InvalidBody(format!("{}: {}", location, err))
+                }
+            })
+            .collect()
+    }
+
+    fn resolve<'a>(&'a self, mut value: &'a Value) -> &'a Value {
+        for _ in 0..MAX_REF_DEPTH {
+            let Some(pointer) = value["$ref"].as_str().and_then(|r| r.strip_prefix('#')) else {
+                break;
+            };
+            match self.spec.pointer(pointer) {
+                Some(target) => value = target,
+                None => break,
+            }
+        }
+        value
+    }
+}
+
+fn match_template(template: &str, path: &str) -> Option<usize> {
+    let template_segments: Vec<&str> = template.trim_end_matches('/').split('/').collect();
+    let path_segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+    if template_segments.len() != path_segments.len() {
+        return None;
+    }
+
+    let mut templated = 0;
+    for (expected, actual) in template_segments.iter().zip(&path_segments) {
+        if expected.starts_with('{') && expected.ends_with('}') && !actual.is_empty() {
+            templated += 1;
+        } else if expected != actual {
+            return None;
+        }
+    }
+    Some(templated)
+}
+
+fn request_media_type(curl_cmd: &CurlCommand) -> Option<String> {
+    let has_body = curl_cmd.data.is_some()
+        || curl_cmd.binary_file.is_some()
+        || !curl_cmd.form.is_empty()
+        || !curl_cmd.files.is_empty()
+        || utils::has_url_encoded_body(curl_cmd);
+    if !has_body {
+        return None;
+    }
+
+    let content_type = curl_cmd
+        .headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+        .map(|(_, value)| {
+            value
+                .split(';')
+                .next()
+                .unwrap_or(value)
+                .trim()
+                .to_lowercase()
+        });
+
+    Some(content_type.unwrap_or_else(|| {
+        if curl_cmd.data.is_none()
+            && curl_cmd.binary_file.is_none()
+            && (!curl_cmd.form.is_empty() || !curl_cmd.files.is_empty())
+        {
+            "multipart/form-data".to_string()
+        } else if curl_cmd.data_is_json {
+            "application/json".to_string()
+        } else {
+            "application/x-www-form-urlencoded".to_string()
+        }
+    }))
+}
+
+fn media_range_matches(range: &str, media_type: &str) -> bool {
+    let range = range
+        .split(';')
+        .next()
+        .unwrap_or(range)
+        .trim()
+        .to_lowercase();
+    match range.split_once('/') {
+        Some(("*", "*")) => true,
+        Some((kind, "*")) => media_type.split('/').next() == Some(kind),
+        _ => range == media_type,
+    }
+}
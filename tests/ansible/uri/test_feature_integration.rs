@@ -0,0 +1,110 @@
+use curl_parser::converters::ansible::AnsibleUri;
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+use serde_json::{json, Value};
+
+fn tasks(output: &str) -> Value {
+    serde_yaml_ng::from_str(output).unwrap()
+}
+
+#[test]
+fn test_json_body_and_basic_auth() {
+    let cmd = "curl -X POST -u admin:secret -H 'Content-Type: application/json' \
+               -d '{\"name\":\"test\",\"tags\":[1,2]}' https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = AnsibleUri;
+    let result = converter.convert(&parsed);
+
+    assert!(result.starts_with("- name: POST /items\n  ansible.builtin.uri:\n"));
+    assert_eq!(
+        tasks(&result),
+        json!([{
+            "name": "POST /items",
+            "ansible.builtin.uri": {
+                "url": "https://api.example.com/items",
+                "method": "POST",
+                "headers": {"Content-Type": "application/json"},
+                "body_format": "json",
+                "body": {"name": "test", "tags": [1, 2]},
+                "url_username": "admin",
+                "url_password": "secret",
+                "force_basic_auth": true,
+                "follow_redirects": "none",
+            }
+        }])
+    );
+}
+
+#[test]
+fn test_form_bodies() {
+    let converter = AnsibleUri;
+
+    let parsed =
+        parse_curl_command("curl -d 'user=alice&role=admin' https://example.com/login").unwrap();
+    let uri = &tasks(&converter.convert(&parsed))[0]["ansible.builtin.uri"];
+    assert_eq!(uri["body_format"], "form-urlencoded");
+    assert_eq!(uri["body"], json!({"user": "alice", "role": "admin"}));
+
+    let parsed = parse_curl_command(
+        "curl -F 'name=report' -F 'file=@/tmp/data.csv;type=text/csv' https://example.com/upload",
+    )
+    .unwrap();
+    let uri = &tasks(&converter.convert(&parsed))[0]["ansible.builtin.uri"];
+    assert_eq!(uri["body_format"], "form-multipart");
+    assert_eq!(
+        uri["body"],
+        json!({
+            "name": "report",
+            "file": {"filename": "/tmp/data.csv", "mime_type": "text/csv"},
+        })
+    );
+}
+
+#[test]
+fn test_tls_redirects_timeout_and_dest() {
+    let cmd = "curl -L -k -m 30 -o out.json --cert client.pem --key client.key \
+               --cacert ca.pem https://example.com/report";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = AnsibleUri;
+    let uri = &tasks(&converter.convert(&parsed))[0]["ansible.builtin.uri"];
+
+    assert_eq!(uri["validate_certs"], false);
+    assert_eq!(uri["client_cert"], "client.pem");
+    assert_eq!(uri["client_key"], "client.key");
+    assert_eq!(uri["ca_path"], "ca.pem");
+    assert_eq!(uri["follow_redirects"], "all");
+    assert_eq!(uri["timeout"], 30);
+    assert_eq!(uri["dest"], "out.json");
+}
+
+#[test]
+fn test_multiple_commands_become_task_list() {
+    let commands = vec![
+        parse_curl_command("curl https://example.com/users").unwrap(),
+        parse_curl_command("curl -X DELETE https://example.com/users/1").unwrap(),
+    ];
+
+    let converter = AnsibleUri;
+    let result = tasks(&converter.convert_many(&commands));
+
+    assert_eq!(result.as_array().unwrap().len(), 2);
+    assert_eq!(result[0]["name"], "GET /users");
+    assert_eq!(result[1]["ansible.builtin.uri"]["method"], "DELETE");
+}
+
+#[test]
+fn test_unsupported_options_warn() {
+    let cmd = "curl --ntlm -u user:pass -x http://proxy:8080 --retry 3 https://example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = AnsibleUri;
+    let warnings = converter.warnings(&parsed);
+    assert_eq!(warnings.len(), 3);
+
+    let result = converter.convert(&parsed);
+    assert!(result.starts_with("# "));
+    let uri = &tasks(&result)[0]["ansible.builtin.uri"];
+    assert!(uri.get("url_username").is_none());
+}
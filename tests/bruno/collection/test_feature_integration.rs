@@ -0,0 +1,117 @@
+use curl_parser::converters::bruno::BrunoCollection;
+use curl_parser::converters::{Convert, ConvertTree};
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_bru_file_blocks() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' -u admin:secret \
+               -d '{\"name\":\"test\"}' 'https://api.example.com/items?draft=true'";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = BrunoCollection;
+    let bru = converter.convert(&parsed);
+
+    assert!(bru.starts_with("meta {\n  name: POST /items\n  type: http\n  seq: 1\n}\n"));
+    assert!(bru.contains(
+        "post {\n  url: https://api.example.com/items?draft=true\n  body: json\n  auth: basic\n}\n"
+    ));
+    assert!(bru.contains("params:query {\n  draft: true\n}\n"));
+    assert!(bru.contains("headers {\n  Content-Type: application/json\n}\n"));
+    assert!(bru.contains("auth:basic {\n  username: admin\n  password: secret\n}\n"));
+    assert!(bru.ends_with("body:json {\n  {\"name\":\"test\"}\n}\n"));
+}
+
+#[test]
+fn test_bearer_auth_and_multipart_body() {
+    let cmd = "curl --oauth2-bearer token123 -F 'name=report' -F 'file=@/tmp/data.csv' \
+               https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = BrunoCollection;
+    let bru = converter.convert(&parsed);
+
+    assert!(bru.contains("  body: multipart-form\n  auth: bearer\n"));
+    assert!(!bru.contains("headers {"));
+    assert!(bru.contains("auth:bearer {\n  token: token123\n}\n"));
+    assert!(
+        bru.contains("body:multipart-form {\n  name: report\n  file: @file(/tmp/data.csv)\n}\n")
+    );
+}
+
+#[test]
+fn test_form_urlencoded_data() {
+    let cmd = "curl -d 'user=alice&note=hello%20world' https://api.example.com/login";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = BrunoCollection;
+    let bru = converter.convert(&parsed);
+
+    assert!(bru.contains("  body: form-urlencoded\n  auth: none\n"));
+    assert!(bru.contains("body:form-urlencoded {\n  user: alice\n  note: hello world\n}\n"));
+}
+
+#[test]
+fn test_unsupported_method_warning() {
+    let parsed = parse_curl_command("curl -X PURGE https://cdn.example.com/asset").unwrap();
+
+    let converter = BrunoCollection;
+    let bru = converter.convert(&parsed);
+
+    assert!(bru.contains("get {\n  url: https://cdn.example.com/asset\n"));
+    assert_eq!(
+        converter.warnings(&parsed),
+        vec!["Bruno does not support the PURGE method, exported as GET".to_string()]
+    );
+}
+
+#[test]
+fn test_directory_tree_for_many_commands() {
+    let curl_cmds = vec![
+        parse_curl_command("curl https://api.example.com/users").unwrap(),
+        parse_curl_command("curl -X DELETE https://api.example.com/users/1").unwrap(),
+        parse_curl_command("curl https://auth.example.com/users").unwrap(),
+        parse_curl_command("curl https://api.example.com/users?page=2").unwrap(),
+    ];
+
+    let converter = BrunoCollection;
+    let files = converter.convert_tree(&curl_cmds);
+
+    let paths: Vec<&str> = files.iter().map(|(path, _)| path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "bruno.json",
+            "api.example.com/get-users.bru",
+            "api.example.com/delete-users-1.bru",
+            "auth.example.com/get-users.bru",
+            "api.example.com/get-users-2.bru",
+        ]
+    );
+    assert!(files[0].1.contains("\"type\": \"collection\""));
+    assert!(files[2].1.contains("  seq: 2\n"));
+    assert!(files[3].1.contains("  seq: 1\n"));
+    assert!(files[4].1.contains("  seq: 3\n"));
+}
+
+#[test]
+fn test_directory_names_are_sanitized() {
+    let curl_cmds = vec![
+        parse_curl_command("curl http://../x").unwrap(),
+        parse_curl_command("curl http://[::1]:8080/health").unwrap(),
+        parse_curl_command("curl http://[2001:db8::1]/").unwrap(),
+    ];
+
+    let converter = BrunoCollection;
+    let files = converter.convert_tree(&curl_cmds);
+
+    let paths: Vec<&str> = files.iter().map(|(path, _)| path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "bruno.json",
+            "requests/get-x.bru",
+            "1/get-health.bru",
+            "2001-db8-1/get.bru",
+        ]
+    );
+}
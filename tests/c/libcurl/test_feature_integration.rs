@@ -0,0 +1,119 @@
+use curl_parser::converters::c::Libcurl;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_program_skeleton_and_headers() {
+    let cmd = "curl -X PUT -H 'Accept: application/json' -H 'Content-Type: application/json' \
+               -d '{\"name\":\"test\"}' https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Libcurl;
+    let c_code = converter.convert(&parsed);
+
+    assert!(c_code.starts_with("#include <stdio.h>\n#include <curl/curl.h>\n\nint main(void)\n{\n"));
+    assert!(c_code.contains("  slist1 = curl_slist_append(slist1, \"Accept: application/json\");"));
+    assert!(c_code
+        .contains("  curl_easy_setopt(hnd, CURLOPT_URL, \"https://api.example.com/items/1\");"));
+    assert!(c_code
+        .contains("  curl_easy_setopt(hnd, CURLOPT_POSTFIELDS, \"{\\\"name\\\":\\\"test\\\"}\");"));
+    assert!(
+        c_code.contains("  curl_easy_setopt(hnd, CURLOPT_POSTFIELDSIZE_LARGE, (curl_off_t)15);")
+    );
+    assert!(c_code.contains("  curl_easy_setopt(hnd, CURLOPT_CUSTOMREQUEST, \"PUT\");"));
+    assert!(c_code.contains("  curl_easy_setopt(hnd, CURLOPT_HTTPHEADER, slist1);"));
+    assert!(c_code.contains("  curl_slist_free_all(slist1);"));
+    assert!(c_code.ends_with("  return (int)ret;\n}\n"));
+}
+
+#[test]
+fn test_mime_parts_for_form() {
+    let cmd =
+        "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Libcurl;
+    let c_code = converter.convert(&parsed);
+
+    assert!(c_code.contains("  mime1 = curl_mime_init(hnd);"));
+    assert!(c_code.contains("  curl_mime_data(part1, \"report\", CURL_ZERO_TERMINATED);"));
+    assert!(c_code.contains("  curl_mime_filedata(part1, \"/tmp/data.csv\");"));
+    assert!(c_code.contains("  curl_mime_type(part1, \"text/csv\");"));
+    assert!(c_code.contains("  curl_easy_setopt(hnd, CURLOPT_MIMEPOST, mime1);"));
+    assert!(c_code.contains("  curl_mime_free(mime1);"));
+    assert!(!c_code.contains("CURLOPT_CUSTOMREQUEST"));
+}
+
+#[test]
+fn test_auth_tls_and_transfer_options() {
+    let cmd = "curl --ntlm -u user:pass -k --cacert ca.pem --cert client.pem --key client.key \
+               --compressed -x http://proxy.local:3128 --proxy-user p:q --connect-timeout 5 -m 30 \
+               -L --max-redirs 3 --no-alpn -o out.bin https://api.example.com/secure";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Libcurl;
+    let c_code = converter.convert(&parsed);
+
+    for option in [
+        "CURLOPT_USERPWD, \"user:pass\"",
+        "CURLOPT_HTTPAUTH, (long)CURLAUTH_NTLM",
+        "CURLOPT_SSL_VERIFYPEER, 0L",
+        "CURLOPT_SSL_VERIFYHOST, 0L",
+        "CURLOPT_CAINFO, \"ca.pem\"",
+        "CURLOPT_SSLCERT, \"client.pem\"",
+        "CURLOPT_SSLKEY, \"client.key\"",
+        "CURLOPT_SSL_ENABLE_ALPN, 0L",
+        "CURLOPT_ACCEPT_ENCODING, \"\"",
+        "CURLOPT_PROXY, \"http://proxy.local:3128\"",
+        "CURLOPT_PROXYUSERPWD, \"p:q\"",
+        "CURLOPT_CONNECTTIMEOUT, 5L",
+        "CURLOPT_TIMEOUT, 30L",
+        "CURLOPT_FOLLOWLOCATION, 1L",
+        "CURLOPT_MAXREDIRS, 3L",
+        "CURLOPT_WRITEDATA, out",
+    ] {
+        assert!(
+            c_code.contains(&format!("  curl_easy_setopt(hnd, {});", option)),
+            "missing {}",
+            option
+        );
+    }
+    assert!(c_code.contains("  out = fopen(\"out.bin\", \"wb\");"));
+}
+
+#[test]
+fn test_write_out_and_bearer() {
+    let cmd =
+        "curl --oauth2-bearer abc123 -w '%{http_code} %{num_connects}' https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Libcurl;
+    let c_code = converter.convert(&parsed);
+
+    assert!(c_code.contains("  curl_easy_setopt(hnd, CURLOPT_XOAUTH2_BEARER, \"abc123\");"));
+    assert!(c_code.contains("  curl_easy_setopt(hnd, CURLOPT_HTTPAUTH, (long)CURLAUTH_BEARER);"));
+    assert!(!c_code.contains("Authorization: Bearer"));
+    assert!(c_code.contains("    curl_easy_getinfo(hnd, CURLINFO_RESPONSE_CODE, &http_code);"));
+    assert!(c_code.contains("    printf(\"%ld %%{num_connects}\", http_code);"));
+    assert_eq!(
+        converter.warnings(&parsed),
+        vec!["--write-out variable %{num_connects} is not translated".to_string()]
+    );
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Libcurl;
+    let c_code = converter.convert(&parsed);
+
+    assert!(c_code.contains("CURLOPT_URL, \"https://api.example.com/search?q=hello+world\""));
+    assert!(!c_code.contains("CURLOPT_POSTFIELDS"));
+}
@@ -0,0 +1,58 @@
+use curl_parser::converters::csharp::HttpClient;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_standard_http_methods() {
+    let methods = vec![
+        ("GET", "HttpMethod.Get"),
+        ("POST", "HttpMethod.Post"),
+        ("PUT", "HttpMethod.Put"),
+        ("DELETE", "HttpMethod.Delete"),
+        ("PATCH", "HttpMethod.Patch"),
+        ("HEAD", "HttpMethod.Head"),
+    ];
+
+    for (method, csharp_method) in methods {
+        let cmd = format!("curl -X {} https://api.example.com/resource", method);
+        let parsed = parse_curl_command(&cmd).unwrap();
+
+        let converter = HttpClient;
+        let csharp_code = converter.convert(&parsed);
+
+        assert!(csharp_code.contains(&format!(
+            "using var request = new HttpRequestMessage({}, \"https://api.example.com/resource\");",
+            csharp_method
+        )));
+    }
+}
+
+#[test]
+fn test_custom_method() {
+    let cmd = "curl -X PURGE https://cdn.example.com/assets/app.js";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code.contains(
+        "using var request = new HttpRequestMessage(new HttpMethod(\"PURGE\"), \"https://cdn.example.com/assets/app.js\");"
+    ));
+}
+
+#[test]
+fn test_json_content_type_goes_to_content_headers() {
+    let cmd = "curl -X PUT --json '{\"enabled\":true}' https://api.example.com/settings";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(
+        csharp_code.contains("request.Content = new StringContent(\"{\\\"enabled\\\":true}\");")
+    );
+    assert!(csharp_code.contains(
+        "request.Content.Headers.ContentType = MediaTypeHeaderValue.Parse(\"application/json\");"
+    ));
+    assert!(!csharp_code.contains("TryAddWithoutValidation(\"Content-Type\""));
+}
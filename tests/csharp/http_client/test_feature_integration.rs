@@ -0,0 +1,127 @@
+use curl_parser::converters::csharp::HttpClient;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_handler_configuration() {
+    let cmd = "curl -k -L --max-redirs 3 --compressed -x http://proxy.example.com:3128 \
+               --proxy-user proxyuser:proxypass https://api.example.com/resource";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code.contains("var handler = new HttpClientHandler"));
+    assert!(csharp_code.contains(
+        "    ServerCertificateCustomValidationCallback = HttpClientHandler.DangerousAcceptAnyServerCertificateValidator,"
+    ));
+    assert!(csharp_code.contains("    AllowAutoRedirect = true,"));
+    assert!(csharp_code.contains("    MaxAutomaticRedirections = 3,"));
+    assert!(csharp_code.contains("    AutomaticDecompression = DecompressionMethods.GZip"));
+    assert!(csharp_code.contains("    Proxy = new WebProxy(\"http://proxy.example.com:3128\")"));
+    assert!(csharp_code
+        .contains("        Credentials = new NetworkCredential(\"proxyuser\", \"proxypass\"),"));
+}
+
+#[test]
+fn test_client_certificate_and_timeout() {
+    let cmd = "curl --cert client.pem --key client.key -m 30 https://mtls.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code.contains(
+        "handler.ClientCertificates.Add(X509Certificate2.CreateFromPemFile(\"client.pem\", \"client.key\"));"
+    ));
+    assert!(csharp_code.contains("client.Timeout = TimeSpan.FromSeconds(30);"));
+    assert!(csharp_code.contains("using var response = await client.SendAsync(request);"));
+}
+
+#[test]
+fn test_multipart_and_form_urlencoded_content() {
+    let cmd =
+        "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code.contains("var content = new MultipartFormDataContent();"));
+    assert!(csharp_code.contains("content.Add(new StringContent(\"report\"), \"name\");"));
+    assert!(csharp_code.contains(
+        "var fileContent1 = new ByteArrayContent(File.ReadAllBytes(\"/tmp/data.csv\"));"
+    ));
+    assert!(csharp_code.contains("content.Add(fileContent1, \"file\", \"data.csv\");"));
+
+    let cmd = "curl -X POST --data-urlencode 'q=hello world' https://api.example.com/search";
+    let parsed = parse_curl_command(cmd).unwrap();
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code
+        .contains("request.Content = new FormUrlEncodedContent(new Dictionary<string, string>"));
+    assert!(csharp_code.contains("    [\"q\"] = \"hello world\","));
+}
+
+#[test]
+fn test_auth_schemes() {
+    let cmd = "curl -u admin:secret https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code.contains(
+        "request.Headers.Authorization = new AuthenticationHeaderValue(\"Basic\", Convert.ToBase64String(Encoding.UTF8.GetBytes(\"admin:secret\")));"
+    ));
+
+    let cmd = "curl --ntlm -u admin:secret https://intranet.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code.contains("    Credentials = new NetworkCredential(\"admin\", \"secret\"),"));
+    assert!(!csharp_code.contains("AuthenticationHeaderValue"));
+}
+
+#[test]
+fn test_connect_timeout_warning() {
+    let cmd = "curl --connect-timeout 5 https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let warnings = converter.warnings(&parsed);
+
+    assert_eq!(warnings.len(), 1);
+    assert!(converter
+        .convert(&parsed)
+        .starts_with("// Warning: HttpClientHandler has no connect timeout"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = HttpClient;
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code.contains("HttpMethod.Get"));
+    assert!(!csharp_code.contains("FormUrlEncodedContent"));
+}
+
+#[test]
+fn test_zero_max_redirs_disables_redirects() {
+    let cmd = "curl -L --max-redirs 0 https://api.example.com/moved";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let csharp_code = converter.convert(&parsed);
+
+    assert!(csharp_code.contains("    AllowAutoRedirect = false,\n"));
+    assert!(!csharp_code.contains("MaxAutomaticRedirections"));
+}
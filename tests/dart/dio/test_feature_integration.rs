@@ -0,0 +1,89 @@
+use curl_parser::converters::dart::Dio;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_options_with_redirects() {
+    let cmd =
+        "curl -L --max-redirs 3 -H 'X-Api-Key: abc' -u admin:secret https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Dio;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("final dio = Dio();"));
+    assert!(dart_code.contains("      method: 'GET',"));
+    assert!(dart_code.contains("        'X-Api-Key': 'abc',"));
+    assert!(dart_code.contains(
+        "        'Authorization': 'Basic ${base64Encode(utf8.encode('admin:secret'))}',"
+    ));
+    assert!(dart_code.contains("      followRedirects: true,"));
+    assert!(dart_code.contains("      maxRedirects: 3,"));
+}
+
+#[test]
+fn test_form_data_from_map() {
+    let cmd =
+        "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Dio;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("final formData = FormData.fromMap({"));
+    assert!(dart_code.contains("    'name': 'report',"));
+    assert!(dart_code.contains(
+        "    'file': await MultipartFile.fromFile('/tmp/data.csv', filename: 'data.csv', contentType: DioMediaType.parse('text/csv')),"
+    ));
+    assert!(dart_code.contains("    data: formData,"));
+    assert!(dart_code.contains("      followRedirects: false,"));
+}
+
+#[test]
+fn test_base_options_and_adapter() {
+    let cmd = "curl -k --connect-timeout 10 --digest -u user:pass https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Dio;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("final dio = Dio(BaseOptions("));
+    assert!(dart_code.contains("    connectTimeout: const Duration(seconds: 10),"));
+    assert!(dart_code.contains("dio.httpClientAdapter = IOHttpClientAdapter("));
+    assert!(
+        dart_code.contains("      httpClient.badCertificateCallback = (cert, host, port) => true;")
+    );
+    assert!(dart_code.contains(
+        "httpClient.addCredentials(Uri.parse('https://api.example.com'), '', HttpClientDigestCredentials('user', 'pass'));"
+    ));
+    assert!(!dart_code.contains("'Authorization'"));
+}
+
+#[test]
+fn test_url_encoded_data() {
+    let cmd = "curl -X POST --data-urlencode 'q=hello world' https://api.example.com/search";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Dio;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("    data: {'q': 'hello world'},"));
+    assert!(dart_code.contains("      contentType: Headers.formUrlEncodedContentType,"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Dio;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("'https://api.example.com/search?q=hello+world',"));
+    assert!(!dart_code.contains("data:"));
+}
@@ -0,0 +1,90 @@
+use curl_parser::converters::dart::Http;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_request_with_headers_and_basic_auth() {
+    let cmd = "curl -L -X PUT -u admin:secret -H 'Accept: application/json' -d 'name=test' https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Http;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("import 'dart:convert';"));
+    assert!(dart_code.contains("final client = http.Client();"));
+    assert!(dart_code.contains(
+        "final request = http.Request('PUT', Uri.parse('https://api.example.com/items'));"
+    ));
+    assert!(dart_code.contains("    'Accept': 'application/json',"));
+    assert!(dart_code.contains("    'Content-Type': 'application/x-www-form-urlencoded',"));
+    assert!(dart_code.contains(
+        "request.headers['Authorization'] = 'Basic ${base64Encode(utf8.encode('admin:secret'))}';"
+    ));
+    assert!(dart_code.contains("request.body = 'name=test';"));
+    assert!(!dart_code.contains("followRedirects"));
+}
+
+#[test]
+fn test_multipart_request_with_file() {
+    let cmd =
+        "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Http;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("final request = http.MultipartRequest('POST'"));
+    assert!(dart_code.contains("request.fields['name'] = 'report';"));
+    assert!(dart_code.contains(
+        "request.files.add(await http.MultipartFile.fromPath('file', '/tmp/data.csv', filename: 'data.csv', contentType: MediaType.parse('text/csv')));"
+    ));
+    assert!(dart_code.contains("import 'package:http_parser/http_parser.dart';"));
+    assert!(dart_code.contains("request.followRedirects = false;"));
+}
+
+#[test]
+fn test_io_client_settings() {
+    let cmd =
+        "curl -k -x http://proxy.local:3128 --connect-timeout 5 -m 30 https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Http;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("httpClient.badCertificateCallback = (cert, host, port) => true;"));
+    assert!(dart_code.contains("httpClient.findProxy = (uri) => 'PROXY proxy.local:3128';"));
+    assert!(dart_code.contains("httpClient.connectionTimeout = const Duration(seconds: 5);"));
+    assert!(dart_code.contains("final client = IOClient(httpClient);"));
+    assert!(dart_code.contains("client.send(request).timeout(const Duration(seconds: 30));"));
+}
+
+#[test]
+fn test_warnings() {
+    let cmd = "curl --ntlm -u user:pass --retry 3 https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Http;
+    let warnings = converter.warnings(&parsed);
+
+    assert_eq!(warnings.len(), 2);
+    assert!(converter
+        .convert(&parsed)
+        .starts_with("// Warning: NTLM and Negotiate authentication are not supported by dart:io"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Http;
+    let dart_code = converter.convert(&parsed);
+
+    assert!(dart_code.contains("http.Request('GET'"));
+    assert!(!dart_code.contains("bodyFields"));
+}
@@ -0,0 +1,54 @@
+use curl_parser::converters::elixir::HTTPoison;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_request_with_form_body() {
+    let cmd = "curl -X POST --data-urlencode 'q=hello world' -H 'Accept: text/html' https://api.example.com/search";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HTTPoison;
+    let elixir_code = converter.convert(&parsed);
+
+    assert!(elixir_code.contains("url = \"https://api.example.com/search\"\n"));
+    assert!(elixir_code.contains("headers = [{\"Accept\", \"text/html\"}]\n"));
+    assert!(elixir_code.contains("body = {:form, [{\"q\", \"hello world\"}]}\n"));
+    assert!(elixir_code
+        .contains("{:ok, response} = HTTPoison.request(:post, url, body, headers, options)"));
+}
+
+#[test]
+fn test_multipart_and_options() {
+    let cmd = "curl -u user:pass --cacert ca.pem -m 30 -L -F 'file=@/tmp/data.csv;type=text/csv' \
+               -o out.json https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HTTPoison;
+    let elixir_code = converter.convert(&parsed);
+
+    assert!(elixir_code.contains(
+        "body = {:multipart, [{:file, \"/tmp/data.csv\", {\"form-data\", [{\"name\", \"file\"}, {\"filename\", \"data.csv\"}]}, [{\"Content-Type\", \"text/csv\"}]}]}"
+    ));
+    assert!(elixir_code.contains("ssl: [cacertfile: ~c\"ca.pem\"]"));
+    assert!(elixir_code.contains("hackney: [basic_auth: {\"user\", \"pass\"}]"));
+    assert!(elixir_code.contains("recv_timeout: 30000"));
+    assert!(elixir_code.contains("follow_redirect: true"));
+    assert!(elixir_code.contains("File.write!(\"out.json\", response.body)"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = HTTPoison;
+    let elixir_code = converter.convert(&parsed);
+
+    assert!(elixir_code.contains("body = \"\""));
+    assert!(!elixir_code.contains(":form"));
+}
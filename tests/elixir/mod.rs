@@ -0,0 +1,2 @@
+pub mod httpoison;
+pub mod req;
@@ -0,0 +1,78 @@
+use curl_parser::converters::elixir::Req;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_json_body_and_headers() {
+    let cmd = "curl -X PATCH -H 'X-Trace: #{id}' --json '{\"name\":\"test\",\"tags\":[1,null]}' \
+               https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Req;
+    let elixir_code = converter.convert(&parsed);
+
+    assert!(elixir_code.contains("{:ok, response} =\n  Req.request(\n"));
+    assert!(elixir_code.contains("    method: :patch,\n"));
+    assert!(elixir_code.contains("    url: \"https://api.example.com/items/1\",\n"));
+    assert!(elixir_code.contains("{\"x-trace\", \"\\#{id}\"}"));
+    assert!(elixir_code.contains("    json: %{\"name\" => \"test\", \"tags\" => [1, nil]},\n"));
+    assert!(elixir_code.contains("    retry: false\n"));
+}
+
+#[test]
+fn test_form_multipart_keyword_keys() {
+    let cmd = "curl -F name=report -F 'file-name=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Req;
+    let elixir_code = converter.convert(&parsed);
+
+    assert!(elixir_code.contains(
+        "    form_multipart: [name: \"report\", \"file-name\": {File.stream!(\"/tmp/data.csv\"), filename: \"data.csv\", content_type: \"text/csv\"}],\n"
+    ));
+}
+
+#[test]
+fn test_auth_and_connect_options() {
+    let cmd = "curl -u admin:secret -k --connect-timeout 5 -x http://proxy.local:3128 \
+               -L --max-redirs 3 --retry 2 https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Req;
+    let elixir_code = converter.convert(&parsed);
+
+    assert!(elixir_code.contains("    auth: {:basic, \"admin:secret\"},\n"));
+    assert!(elixir_code.contains(
+        "    connect_options: [timeout: 5000, proxy: {:http, \"proxy.local\", 3128, []}, transport_opts: [verify: :verify_none]],\n"
+    ));
+    assert!(elixir_code.contains("    redirect: true,\n    max_redirects: 3,\n"));
+    assert!(elixir_code.contains("    retry: :transient,\n    max_retries: 2\n"));
+}
+
+#[test]
+fn test_custom_method_atom() {
+    let cmd = "curl -X M-SEARCH https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Req;
+    let elixir_code = converter.convert(&parsed);
+
+    assert!(elixir_code.contains("    method: :\"m-search\",\n"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Req;
+    let elixir_code = converter.convert(&parsed);
+
+    assert!(elixir_code.contains("method: :get"));
+    assert!(!elixir_code.contains("form:"));
+}
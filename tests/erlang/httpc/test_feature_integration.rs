@@ -0,0 +1,77 @@
+use curl_parser::converters::erlang::Httpc;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_escript_with_body() {
+    let cmd = "curl -X PUT -H 'Content-Type: application/json' -H 'Accept: application/json' \
+               -d '{\"name\":\"caf\u{e9}\"}' https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httpc;
+    let erlang_code = converter.convert(&parsed);
+
+    assert!(erlang_code.starts_with("#!/usr/bin/env escript\n\nmain(_) ->\n"));
+    assert!(erlang_code.contains("    URL = \"https://api.example.com/items/1\",\n"));
+    assert!(erlang_code.contains("    Headers = [{\"accept\", \"application/json\"}],\n"));
+    assert!(erlang_code.contains("    Body = <<\"{\\\"name\\\":\\\"caf\u{e9}\\\"}\"/utf8>>,\n"));
+    assert!(erlang_code.contains(
+        "httpc:request(put, {URL, Headers, \"application/json\", Body}, HttpOptions, Options),\n"
+    ));
+    assert!(erlang_code.ends_with("    io:format(\"~s~n\", [ResponseBody]).\n"));
+}
+
+#[test]
+fn test_http_options_and_auth() {
+    let cmd = "curl -u admin:secret -k --connect-timeout 5 -m 30 -L -x http://proxy.local:3128 \
+               https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httpc;
+    let erlang_code = converter.convert(&parsed);
+
+    assert!(erlang_code
+        .contains("{\"authorization\", \"Basic \" ++ base64:encode_to_string(\"admin:secret\")}"));
+    assert!(erlang_code.contains(
+        "ok = httpc:set_options([{proxy, {{\"proxy.local\", 3128}, []}}, {https_proxy, {{\"proxy.local\", 3128}, []}}]),"
+    ));
+    assert!(erlang_code.contains(
+        "HttpOptions = [{ssl, [{verify, verify_none}]}, {timeout, 30000}, {connect_timeout, 5000}, {autoredirect, true}],"
+    ));
+    assert!(erlang_code.contains("httpc:request(get, {URL, Headers}, HttpOptions, Options)"));
+}
+
+#[test]
+fn test_multipart_body_and_status_code() {
+    let cmd = "curl -F name=report -F file=@/tmp/data.csv -w '%{http_code}' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httpc;
+    let erlang_code = converter.convert(&parsed);
+
+    assert!(erlang_code.contains("    {ok, File1} = file:read_file(\"/tmp/data.csv\"),\n"));
+    assert!(erlang_code.contains(
+        "\"Content-Disposition: form-data; name=\\\"file\\\"; filename=\\\"data.csv\\\"\\r\\nContent-Type: application/octet-stream\\r\\n\\r\\n\""
+    ));
+    assert!(erlang_code.contains("\"multipart/form-data; boundary=\" ++ Boundary"));
+    assert!(erlang_code
+        .contains("{ok, {{_Version, Status, _Reason}, _ResponseHeaders, _ResponseBody}} ="));
+    assert!(erlang_code.ends_with("    io:format(\"~p~n\", [Status]).\n"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Httpc;
+    let erlang_code = converter.convert(&parsed);
+
+    assert!(erlang_code.contains("httpc:request(get, {URL, Headers}"));
+    assert!(!erlang_code.contains("compose_query"));
+}
@@ -0,0 +1,152 @@
+use curl_parser::converters::har::HarLog;
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+use serde_json::{json, Value};
+
+fn validate(har: &Value) {
+    let schema: Value =
+        serde_json::from_str(include_str!("../har-1.2.schema.json")).expect("valid schema");
+    let validator = jsonschema::validator_for(&schema).expect("schema compiles");
+    let errors: Vec<String> = validator
+        .iter_errors(har)
+        .map(|error| error.to_string())
+        .collect();
+    assert!(errors.is_empty(), "HAR schema errors: {:?}", errors);
+}
+
+fn request(cmd: &str) -> Value {
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HarLog;
+    let har: Value = serde_json::from_str(&converter.convert(&parsed)).unwrap();
+    validate(&har);
+
+    assert_eq!(har["log"]["version"], "1.2");
+    assert_eq!(har["log"]["entries"].as_array().unwrap().len(), 1);
+    har["log"]["entries"][0]["request"].clone()
+}
+
+#[test]
+fn test_json_post_data_and_headers() {
+    let request = request(
+        "curl -X POST -H 'Content-Type: application/json' -A 'my-agent/1.0' -u admin:secret \
+         -d '{\"name\":\"test\"}' https://api.example.com/items",
+    );
+
+    assert_eq!(request["method"], "POST");
+    assert_eq!(request["url"], "https://api.example.com/items");
+    assert_eq!(request["httpVersion"], "HTTP/1.1");
+    assert_eq!(
+        request["headers"],
+        json!([
+            {"name": "Content-Type", "value": "application/json"},
+            {"name": "User-Agent", "value": "my-agent/1.0"},
+            {"name": "Authorization", "value": "Basic YWRtaW46c2VjcmV0"}
+        ])
+    );
+    assert_eq!(
+        request["postData"],
+        json!({"mimeType": "application/json", "text": "{\"name\":\"test\"}"})
+    );
+    assert_eq!(request["bodySize"], 15);
+}
+
+#[test]
+fn test_query_string_and_cookies() {
+    let request = request(
+        "curl -b 'session=abc; theme=dark' 'https://api.example.com/search?q=rust%20lang&page=2'",
+    );
+
+    assert_eq!(request["method"], "GET");
+    assert_eq!(
+        request["queryString"],
+        json!([
+            {"name": "q", "value": "rust lang"},
+            {"name": "page", "value": "2"}
+        ])
+    );
+    assert_eq!(
+        request["cookies"],
+        json!([
+            {"name": "session", "value": "abc"},
+            {"name": "theme", "value": "dark"}
+        ])
+    );
+    assert!(request.get("postData").is_none());
+    assert_eq!(request["bodySize"], 0);
+}
+
+#[test]
+fn test_multipart_params() {
+    let request = request(
+        "curl -F 'name=report' -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload",
+    );
+
+    assert_eq!(
+        request["postData"],
+        json!({
+            "mimeType": "multipart/form-data",
+            "params": [
+                {"name": "name", "value": "report"},
+                {"name": "file", "fileName": "data.csv", "contentType": "text/csv"}
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_url_encoded_params() {
+    let request = request(
+        "curl -X POST --data-urlencode 'q=hello world' --data-urlencode 'lang=en' https://api.example.com/search",
+    );
+
+    assert_eq!(
+        request["postData"],
+        json!({
+            "mimeType": "application/x-www-form-urlencoded",
+            "params": [
+                {"name": "lang", "value": "en"},
+                {"name": "q", "value": "hello world"}
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_binary_file_warning() {
+    let cmd = "curl --data-binary @payload.bin https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HarLog;
+    let warnings = converter.warnings(&parsed);
+    assert!(warnings
+        .iter()
+        .any(|warning| warning.contains("payload.bin")));
+
+    let request = request(cmd);
+    assert_eq!(
+        request["postData"],
+        json!({"mimeType": "application/octet-stream", "comment": "Body read from payload.bin"})
+    );
+}
+
+#[test]
+fn test_several_commands_share_one_log() {
+    let commands = vec![
+        parse_curl_command("curl https://api.example.com/users").unwrap(),
+        parse_curl_command("curl -X DELETE https://api.example.com/users/1").unwrap(),
+    ];
+
+    let converter = HarLog;
+    let har: Value = serde_json::from_str(&converter.convert_many(&commands)).unwrap();
+    validate(&har);
+
+    let entries = har["log"]["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["request"]["method"], "GET");
+    assert_eq!(entries[1]["request"]["method"], "DELETE");
+    assert_eq!(
+        entries[1]["request"]["url"],
+        "https://api.example.com/users/1"
+    );
+}
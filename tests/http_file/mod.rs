@@ -0,0 +1 @@
+pub mod rest_client;
@@ -0,0 +1,95 @@
+use curl_parser::converters::http_file::RestClient;
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_request_line_headers_and_body() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' -u admin:secret \
+               -d '{\"name\":\"test\"}' https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = RestClient;
+    let result = converter.convert(&parsed);
+
+    assert_eq!(
+        result,
+        "### POST /items\n\
+         # @no-redirect\n\
+         POST https://api.example.com/items HTTP/1.1\n\
+         Content-Type: application/json\n\
+         Authorization: Basic YWRtaW46c2VjcmV0\n\
+         \n\
+         {\"name\":\"test\"}\n"
+    );
+}
+
+#[test]
+fn test_binary_file_reference() {
+    let cmd = "curl -L -X PUT --data-binary @payload.bin https://api.example.com/blobs/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = RestClient;
+    let result = converter.convert(&parsed);
+
+    assert!(!result.contains("@no-redirect"));
+    assert!(result.contains("PUT https://api.example.com/blobs/1 HTTP/1.1\n"));
+    assert!(result.ends_with("\n< ./payload.bin\n"));
+}
+
+#[test]
+fn test_multipart_boundary() {
+    let cmd = "curl -L -F 'name=report' -F 'file=@/tmp/data.csv;type=text/csv' \
+               https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = RestClient;
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains("Content-Type: multipart/form-data; boundary=WebAppBoundary\n"));
+    assert!(result.contains(
+        "--WebAppBoundary\n\
+         Content-Disposition: form-data; name=\"name\"\n\
+         \n\
+         report\n"
+    ));
+    assert!(result.contains(
+        "--WebAppBoundary\n\
+         Content-Disposition: form-data; name=\"file\"; filename=\"data.csv\"\n\
+         Content-Type: text/csv\n\
+         \n\
+         < /tmp/data.csv\n\
+         --WebAppBoundary--\n"
+    ));
+}
+
+#[test]
+fn test_unsupported_options_warning() {
+    let parsed = parse_curl_command("curl -L -k https://api.example.com/health").unwrap();
+
+    let converter = RestClient;
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains(
+        "# Warning: .http files have no certificate validation setting, --insecure ignored\n"
+    ));
+}
+
+#[test]
+fn test_requests_separated_by_markers() {
+    let curl_cmds = vec![
+        parse_curl_command("curl -L https://api.example.com/users").unwrap(),
+        parse_curl_command("curl -L -X DELETE https://api.example.com/users/1").unwrap(),
+    ];
+
+    let converter = RestClient;
+    let result = converter.convert_many(&curl_cmds);
+
+    assert_eq!(
+        result,
+        "### GET /users\n\
+         GET https://api.example.com/users HTTP/1.1\n\
+         \n\
+         ### DELETE /users/1\n\
+         DELETE https://api.example.com/users/1 HTTP/1.1\n"
+    );
+}
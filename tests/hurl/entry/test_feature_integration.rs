@@ -0,0 +1,119 @@
+use curl_parser::converters::hurl::HurlEntry;
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_json_body_and_response_section() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' \
+               -d '{\"name\":\"test\"}' https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HurlEntry;
+    let result = converter.convert(&parsed);
+
+    assert_eq!(
+        result,
+        "POST https://api.example.com/items\n\
+         Content-Type: application/json\n\
+         {\"name\":\"test\"}\n\
+         \n\
+         HTTP *\n"
+    );
+}
+
+#[test]
+fn test_options_basic_auth_and_cookies() {
+    let cmd = "curl -k -L --max-redirs 3 --compressed --connect-timeout 5 --retry 2 \
+               -u admin:secret -b 'session=abc123' https://api.example.com/me";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HurlEntry;
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains(
+        "[Options]\n\
+         insecure: true\n\
+         location: true\n\
+         max-redirs: 3\n\
+         compressed: true\n\
+         connect-timeout: 5s\n\
+         retry: 2\n"
+    ));
+    assert!(result.contains("[BasicAuth]\nadmin: secret\n"));
+    assert!(result.contains("[Cookies]\nsession: abc123\n"));
+    assert!(!result.contains("Cookie:"));
+}
+
+#[test]
+fn test_form_params_and_multipart_form_data() {
+    let cmd = "curl -X POST --data-urlencode 'q=rust lang' https://api.example.com/search";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HurlEntry;
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains("POST https://api.example.com/search\n[FormParams]\nq: rust lang\n"));
+
+    let cmd = "curl -F 'name=report' -F 'file=@data.csv;type=text/csv' \
+               https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains(
+        "[MultipartFormData]\n\
+         name: report\n\
+         file: file,data.csv; text/csv\n"
+    ));
+}
+
+#[test]
+fn test_write_out_status_capture() {
+    let cmd = "curl -s -w '%{http_code}' https://api.example.com/health";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HurlEntry;
+    let result = converter.convert(&parsed);
+
+    assert!(result.ends_with(
+        "HTTP *\n\
+         [Captures]\n\
+         http_code: status\n\
+         [Asserts]\n\
+         status < 400\n"
+    ));
+}
+
+#[test]
+fn test_many_entries_and_digest_warning() {
+    let curl_cmds = vec![
+        parse_curl_command("curl https://api.example.com/users").unwrap(),
+        parse_curl_command("curl --digest -u admin:secret https://api.example.com/me").unwrap(),
+    ];
+
+    let converter = HurlEntry;
+    let result = converter.convert_many(&curl_cmds);
+
+    assert!(result.starts_with("GET https://api.example.com/users\n\nHTTP *\n\n"));
+    assert!(result.contains("# Warning: Hurl only supports basic auth, credentials omitted\n"));
+    assert!(!result.contains("[BasicAuth]"));
+}
+
+#[test]
+fn test_template_braces_are_escaped() {
+    let cmd = "curl -H 'X-Token: {{token}}' -H 'Content-Type: application/json' \
+               -d '{\"a\":\"{{x}}\"}' 'https://example.com/search?q={{term}}'";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HurlEntry;
+    let hurl = converter.convert(&parsed);
+
+    assert!(hurl.starts_with("POST https://example.com/search?q=\\u{7b}{term}}\n"));
+    assert!(hurl.contains("X-Token: \\u{7b}{token}}\n"));
+    assert!(hurl.contains("{\"a\":\"\\u007b{x}}\"}\n"));
+    assert!(!hurl.contains("{{"));
+
+    let parsed =
+        parse_curl_command("curl -d 'note={{{draft}}}' https://example.com/notes").unwrap();
+    let hurl = converter.convert(&parsed);
+    assert!(hurl.contains("`note=\\u{7b}\\u{7b}{draft}}}`\n"));
+}
@@ -0,0 +1,30 @@
+use curl_parser::importers::{CurlImporter, Import, ImportError};
+
+const COMMANDS: &str = include_str!("commands.txt");
+
+#[test]
+fn test_one_command_per_line() {
+    let commands = CurlImporter.import(COMMANDS).unwrap();
+
+    assert_eq!(commands.len(), 3);
+    assert_eq!(commands[0].url, "https://api.example.com/users");
+    assert_eq!(commands[2].method, "DELETE");
+    assert_eq!(commands[2].url, "https://api.example.com/users/1");
+}
+
+#[test]
+fn test_line_continuations() {
+    let commands = CurlImporter.import(COMMANDS).unwrap();
+    let curl_cmd = &commands[1];
+
+    assert_eq!(curl_cmd.method, "POST");
+    assert_eq!(curl_cmd.headers["Content-Type"], "application/json");
+    assert_eq!(curl_cmd.data.as_deref(), Some("{\"name\":\"test\"}"));
+}
+
+#[test]
+fn test_invalid_command() {
+    let result = CurlImporter.import("curl -X GET\n");
+
+    assert!(matches!(result, Err(ImportError::InvalidCurl(_))));
+}
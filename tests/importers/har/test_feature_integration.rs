@@ -0,0 +1,92 @@
+use curl_parser::converters::har::HarLog;
+use curl_parser::converters::python::Requests;
+use curl_parser::converters::Convert;
+use curl_parser::importers::{Filter, HarImporter, Import, ImportError};
+use curl_parser::parse_curl_command;
+
+const SESSION: &str = include_str!("session.har");
+
+#[test]
+fn test_imports_every_entry() {
+    let commands = HarImporter.import(SESSION).unwrap();
+
+    assert_eq!(commands.len(), 4);
+    assert_eq!(commands[0].method, "GET");
+    assert_eq!(commands[0].url, "https://app.example.com/index.html");
+    assert_eq!(commands[0].headers.len(), 1);
+    assert_eq!(commands[0].headers["accept"], "text/html");
+}
+
+#[test]
+fn test_headers_cookies_and_json_body() {
+    let commands = HarImporter.import(SESSION).unwrap();
+    let curl_cmd = &commands[1];
+
+    assert_eq!(curl_cmd.method, "POST");
+    assert_eq!(curl_cmd.url, "https://app.example.com/api/items?draft=true");
+    assert_eq!(curl_cmd.data.as_deref(), Some("{\"name\":\"test\"}"));
+    assert!(curl_cmd.data_is_json);
+    assert_eq!(curl_cmd.headers["Authorization"], "Bearer token123");
+    assert!(!curl_cmd.headers.contains_key("Host"));
+    assert!(!curl_cmd.headers.contains_key("Content-Length"));
+    assert!(!curl_cmd.headers.contains_key("Cookie"));
+    assert_eq!(curl_cmd.cookies["session"], "abc");
+    assert_eq!(curl_cmd.cookies["theme"], "dark");
+
+    let python_code = Requests.convert(curl_cmd);
+    assert!(python_code.contains("https://app.example.com/api/items?draft=true"));
+    assert!(python_code.contains("Bearer token123"));
+}
+
+#[test]
+fn test_multipart_and_url_encoded_params() {
+    let commands = HarImporter.import(SESSION).unwrap();
+
+    let upload = &commands[2];
+    assert!(upload.headers.is_empty());
+    assert_eq!(upload.form["title"], "report");
+    assert_eq!(upload.files["file"].path, "data.csv");
+    assert_eq!(
+        upload.files["file"].content_type.as_deref(),
+        Some("text/csv")
+    );
+
+    let login = &commands[3];
+    assert_eq!(login.method, "PUT");
+    assert_eq!(login.url_encoded_params["user"], "alice");
+    assert_eq!(login.url_encoded_params["remember"], "1");
+}
+
+#[test]
+fn test_filter_by_url_and_method() {
+    let commands = HarImporter.import(SESSION).unwrap();
+
+    let filter = Filter::new(Some("/api/"), None).unwrap();
+    assert_eq!(filter.apply(commands.clone()).len(), 3);
+
+    let filter = Filter::new(Some("/api/"), Some("post")).unwrap();
+    let matched = filter.apply(commands);
+    assert_eq!(matched.len(), 2);
+    assert!(matched.iter().all(|curl_cmd| curl_cmd.method == "POST"));
+
+    assert!(matches!(
+        Filter::new(Some("("), None),
+        Err(ImportError::InvalidFilter(_))
+    ));
+}
+
+#[test]
+fn test_round_trip_from_har_export() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' -b 'a=1' \
+               -d '{\"id\":1}' 'https://api.example.com/items?page=2'";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let commands = HarImporter.import(&HarLog.convert(&parsed)).unwrap();
+
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].method, parsed.method);
+    assert_eq!(commands[0].url, parsed.url);
+    assert_eq!(commands[0].headers, parsed.headers);
+    assert_eq!(commands[0].cookies, parsed.cookies);
+    assert_eq!(commands[0].data, parsed.data);
+}
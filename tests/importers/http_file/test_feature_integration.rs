@@ -0,0 +1,105 @@
+use curl_parser::converters::http_file::RestClient;
+use curl_parser::converters::Convert;
+use curl_parser::importers::{HttpFileImporter, Import, ImportError};
+use curl_parser::{parse_curl_command, AuthType};
+
+const REQUESTS: &str = include_str!("requests.http");
+
+#[test]
+fn test_variables_query_continuation_and_cookies() {
+    let commands = HttpFileImporter.import(REQUESTS).unwrap();
+    assert_eq!(commands.len(), 5);
+
+    let curl_cmd = &commands[0];
+    assert_eq!(curl_cmd.method, "GET");
+    assert_eq!(
+        curl_cmd.url,
+        "https://api.example.com/users?page=2&per_page=50"
+    );
+    assert_eq!(curl_cmd.headers["Accept"], "application/json");
+    assert_eq!(curl_cmd.cookies["session"], "abc123");
+    assert_eq!(curl_cmd.cookies["theme"], "dark");
+    assert!(curl_cmd.location);
+}
+
+#[test]
+fn test_json_body_and_no_redirect() {
+    let commands = HttpFileImporter.import(REQUESTS).unwrap();
+    let curl_cmd = &commands[1];
+
+    assert_eq!(curl_cmd.method, "POST");
+    assert_eq!(curl_cmd.url, "https://api.example.com/items");
+    assert_eq!(curl_cmd.headers["Authorization"], "Bearer token123");
+    assert_eq!(curl_cmd.data.as_deref(), Some("{\n  \"name\": \"test\"\n}"));
+    assert!(curl_cmd.data_is_json);
+    assert!(!curl_cmd.location);
+
+    let login = &commands[2];
+    assert_eq!(
+        login.auth,
+        Some(("admin".to_string(), "secret".to_string()))
+    );
+    assert!(!login.headers.contains_key("Authorization"));
+    assert_eq!(login.data.as_deref(), Some("user=alice&remember=1"));
+    assert!(!login.data_is_json);
+}
+
+#[test]
+fn test_multipart_and_file_bodies() {
+    let commands = HttpFileImporter.import(REQUESTS).unwrap();
+
+    let upload = &commands[3];
+    assert!(upload.headers.is_empty());
+    assert_eq!(upload.form["name"], "report");
+    assert_eq!(upload.files["file"].path, "data.csv");
+    assert_eq!(
+        upload.files["file"].content_type.as_deref(),
+        Some("text/csv")
+    );
+    assert_eq!(upload.files["file"].filename, None);
+
+    let binary = &commands[4];
+    assert_eq!(binary.method, "PUT");
+    assert_eq!(binary.binary_file.as_deref(), Some("payload.bin"));
+    assert_eq!(binary.data, None);
+}
+
+#[test]
+fn test_xml_body_is_not_a_file_reference() {
+    let input = "PROPFIND https://dav.example.com/files/\n\
+                 Content-Type: application/xml\n\
+                 \n\
+                 <propfind/>\n";
+    let commands = HttpFileImporter.import(input).unwrap();
+
+    assert_eq!(commands[0].method, "PROPFIND");
+    assert_eq!(commands[0].data.as_deref(), Some("<propfind/>"));
+    assert_eq!(commands[0].binary_file, None);
+}
+
+#[test]
+fn test_round_trip_through_rest_client() {
+    let cmd = "curl -X POST --digest -u admin:secret -H 'Content-Type: application/json' \
+               -d '{\"name\":\"test\"}' https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = RestClient;
+    let commands = HttpFileImporter
+        .import(&converter.convert(&parsed))
+        .unwrap();
+
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].method, parsed.method);
+    assert_eq!(commands[0].url, parsed.url);
+    assert_eq!(commands[0].data, parsed.data);
+    assert_eq!(commands[0].auth, parsed.auth);
+    assert!(matches!(commands[0].auth_type, Some(AuthType::Digest)));
+    assert_eq!(commands[0].headers, parsed.headers);
+}
+
+#[test]
+fn test_invalid_header_line() {
+    let result = HttpFileImporter.import("GET https://api.example.com/users\nnot a header\n");
+
+    assert!(matches!(result, Err(ImportError::InvalidHttpFile(_))));
+}
@@ -0,0 +1,4 @@
+pub mod curl;
+pub mod har;
+pub mod http_file;
+pub mod postman;
@@ -0,0 +1,107 @@
+use curl_parser::converters::postman::PostmanCollection;
+use curl_parser::converters::ConvertMany;
+use curl_parser::importers::{Import, ImportError, PostmanImporter};
+use curl_parser::{parse_curl_command, AuthType};
+
+const COLLECTION: &str = include_str!("collection.json");
+const ENVIRONMENT: &str = include_str!("environment.json");
+
+#[test]
+fn test_imports_nested_folders_with_environment() {
+    let importer = PostmanImporter::default()
+        .with_environment(ENVIRONMENT)
+        .unwrap();
+    let commands = importer.import(COLLECTION).unwrap();
+
+    assert_eq!(commands.len(), 3);
+
+    let create = &commands[0];
+    assert_eq!(create.method, "POST");
+    assert_eq!(create.url, "https://api.example.com/items?draft=true");
+    assert_eq!(create.data.as_deref(), Some("{\"name\":\"widget\"}"));
+    assert!(create.data_is_json);
+    assert_eq!(create.headers["Content-Type"], "application/json");
+    assert_eq!(create.headers["X-Request-Id"], "{{requestId}}");
+    assert!(!create.headers.contains_key("X-Debug"));
+    assert_eq!(create.oauth2_bearer.as_deref(), Some("prod-token"));
+    assert_eq!(create.headers["Authorization"], "Bearer prod-token");
+    assert!(!create.location);
+    assert!(create.insecure);
+}
+
+#[test]
+fn test_collection_variables_without_environment() {
+    let commands = PostmanImporter::default().import(COLLECTION).unwrap();
+
+    assert_eq!(
+        commands[0].url,
+        "https://staging.example.com/api/items?draft=true"
+    );
+    assert_eq!(commands[0].oauth2_bearer.as_deref(), Some("{{token}}"));
+    assert!(commands[1].location);
+}
+
+#[test]
+fn test_form_data_and_auth_inheritance() {
+    let importer = PostmanImporter::default()
+        .with_environment(ENVIRONMENT)
+        .unwrap();
+    let commands = importer.import(COLLECTION).unwrap();
+
+    let upload = &commands[1];
+    assert_eq!(upload.url, "https://api.example.com/upload");
+    assert!(upload.oauth2_bearer.is_none());
+    assert!(upload.headers.is_empty());
+    assert_eq!(upload.form["title"], "report");
+    assert_eq!(upload.files["file"].path, "/tmp/data.csv");
+    assert_eq!(
+        upload.files["file"].content_type.as_deref(),
+        Some("text/csv")
+    );
+
+    let login = &commands[2];
+    assert_eq!(login.method, "PUT");
+    assert_eq!(
+        login.auth,
+        Some(("admin".to_string(), "s3cret".to_string()))
+    );
+    assert!(matches!(login.auth_type, Some(AuthType::Digest)));
+    assert_eq!(login.cookies["session"], "abc");
+    assert_eq!(login.url_encoded_params.len(), 1);
+    assert_eq!(login.url_encoded_params["user"], "alice");
+}
+
+#[test]
+fn test_round_trip_from_collection_export() {
+    let curl_cmds = vec![
+        parse_curl_command(
+            "curl -X POST -u user:pass -L --max-redirs 3 -H 'Content-Type: application/json' \
+             -d '{\"id\":1}' https://api.example.com/items",
+        )
+        .unwrap(),
+        parse_curl_command("curl -k --oauth2-bearer abc https://api.example.com/me").unwrap(),
+    ];
+
+    let collection = PostmanCollection.convert_many(&curl_cmds);
+    let commands = PostmanImporter::default().import(&collection).unwrap();
+
+    assert_eq!(commands.len(), 2);
+    assert_eq!(commands[0].url, curl_cmds[0].url);
+    assert_eq!(commands[0].auth, curl_cmds[0].auth);
+    assert_eq!(commands[0].data, curl_cmds[0].data);
+    assert_eq!(commands[0].max_redirs, Some(3));
+    assert!(commands[0].location);
+    assert_eq!(commands[1].oauth2_bearer.as_deref(), Some("abc"));
+    assert!(commands[1].insecure);
+    assert!(!commands[1].location);
+}
+
+#[test]
+fn test_rejects_v1_collection() {
+    let collection = r#"{"info": {"name": "Old", "schema": "https://schema.getpostman.com/json/collection/v1.0.0/collection.json"}}"#;
+
+    assert!(matches!(
+        PostmanImporter::default().import(collection),
+        Err(ImportError::UnsupportedCollection(_))
+    ));
+}
@@ -0,0 +1,123 @@
+use curl_parser::converters::insomnia::InsomniaExport;
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+use serde_json::{json, Value};
+
+fn export(cmd: &str) -> Value {
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = InsomniaExport;
+    serde_json::from_str(&converter.convert(&parsed)).unwrap()
+}
+
+#[test]
+fn test_export_envelope_and_request() {
+    let export = export(
+        "curl -X POST -H 'Content-Type: application/json' -u admin:secret -L \
+         -d '{\"name\":\"test\"}' https://api.example.com/items",
+    );
+
+    assert_eq!(export["_type"], "export");
+    assert_eq!(export["__export_format"], 4);
+
+    let resources = export["resources"].as_array().unwrap();
+    assert_eq!(resources.len(), 2);
+    assert_eq!(resources[0]["_type"], "workspace");
+
+    let request = &resources[1];
+    assert_eq!(request["_type"], "request");
+    assert_eq!(request["parentId"], resources[0]["_id"]);
+    assert_eq!(request["name"], "POST /items");
+    assert_eq!(request["method"], "POST");
+    assert_eq!(request["url"], "https://api.example.com/items");
+    assert_eq!(
+        request["headers"],
+        json!([{"name": "Content-Type", "value": "application/json"}])
+    );
+    assert_eq!(
+        request["body"],
+        json!({"mimeType": "application/json", "text": "{\"name\":\"test\"}"})
+    );
+    assert_eq!(
+        request["authentication"],
+        json!({"type": "basic", "username": "admin", "password": "secret"})
+    );
+    assert_eq!(request["settingFollowRedirects"], "on");
+}
+
+#[test]
+fn test_bearer_and_digest_authentication() {
+    let bearer = export("curl --oauth2-bearer token123 https://api.example.com/me");
+    let request = &bearer["resources"][1];
+    assert_eq!(
+        request["authentication"],
+        json!({"type": "bearer", "token": "token123", "prefix": ""})
+    );
+    assert!(request["headers"].as_array().unwrap().is_empty());
+
+    let digest = export("curl --digest -u admin:secret https://api.example.com/me");
+    assert_eq!(digest["resources"][1]["authentication"]["type"], "digest");
+}
+
+#[test]
+fn test_form_bodies() {
+    let multipart =
+        export("curl -F 'name=report' -F 'file=@/tmp/data.csv' https://api.example.com/upload");
+    assert_eq!(
+        multipart["resources"][1]["body"],
+        json!({
+            "mimeType": "multipart/form-data",
+            "params": [
+                {"name": "name", "value": "report"},
+                {"name": "file", "type": "file", "fileName": "/tmp/data.csv"}
+            ]
+        })
+    );
+
+    let urlencoded = export("curl -d 'user=alice&remember=1' https://api.example.com/login");
+    let request = &urlencoded["resources"][1];
+    assert_eq!(
+        request["body"],
+        json!({
+            "mimeType": "application/x-www-form-urlencoded",
+            "params": [
+                {"name": "user", "value": "alice"},
+                {"name": "remember", "value": "1"}
+            ]
+        })
+    );
+    assert_eq!(
+        request["headers"],
+        json!([{"name": "Content-Type", "value": "application/x-www-form-urlencoded"}])
+    );
+}
+
+#[test]
+fn test_request_groups_per_host() {
+    let curl_cmds = vec![
+        parse_curl_command("curl https://api.example.com/users").unwrap(),
+        parse_curl_command("curl https://auth.example.com/token").unwrap(),
+        parse_curl_command("curl -X DELETE https://api.example.com/users/1").unwrap(),
+    ];
+
+    let converter = InsomniaExport;
+    let export: Value = serde_json::from_str(&converter.convert_many(&curl_cmds)).unwrap();
+
+    let resources = export["resources"].as_array().unwrap();
+    let groups: Vec<&Value> = resources
+        .iter()
+        .filter(|resource| resource["_type"] == "request_group")
+        .collect();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0]["name"], "api.example.com");
+    assert_eq!(groups[1]["name"], "auth.example.com");
+
+    let requests: Vec<&Value> = resources
+        .iter()
+        .filter(|resource| resource["_type"] == "request")
+        .collect();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0]["parentId"], groups[0]["_id"]);
+    assert_eq!(requests[1]["parentId"], groups[1]["_id"]);
+    assert_eq!(requests[2]["parentId"], groups[0]["_id"]);
+}
@@ -0,0 +1,49 @@
+use curl_parser::converters::java::HttpClient;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_standard_http_methods() {
+    let methods = vec!["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS"];
+
+    for method in methods {
+        let cmd = format!("curl -X {} https://api.example.com/resource", method);
+        let parsed = parse_curl_command(&cmd).unwrap();
+
+        let converter = HttpClient;
+        let java_code = converter.convert(&parsed);
+
+        assert!(java_code.contains(&format!(
+            "    .method(\"{}\", HttpRequest.BodyPublishers.noBody())",
+            method
+        )));
+    }
+}
+
+#[test]
+fn test_custom_method_with_data() {
+    let cmd = "curl -X PURGE -d 'key=value' https://api.example.com/cache";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    assert_eq!(parsed.method, "PURGE");
+
+    let converter = HttpClient;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code
+        .contains("    .method(\"PURGE\", HttpRequest.BodyPublishers.ofString(\"key=value\"))"));
+}
+
+#[test]
+fn test_json_body_is_escaped() {
+    let cmd = "curl -X PUT --json '{\"name\":\"C:\\\\temp\"}' https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("    .header(\"Content-Type\", \"application/json\")"));
+    assert!(java_code.contains(
+        "    .method(\"PUT\", HttpRequest.BodyPublishers.ofString(\"{\\\"name\\\":\\\"C:\\\\temp\\\"}\"))"
+    ));
+}
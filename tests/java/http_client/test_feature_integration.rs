@@ -0,0 +1,83 @@
+use curl_parser::converters::java::HttpClient;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_client_builder_options() {
+    let cmd = "curl -L --connect-timeout 10 -m 30 -x http://proxy.example.com:3128 \
+               https://api.example.com/resource";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("public class Main {"));
+    assert!(java_code.contains("    public static void main(String[] args) throws Exception {"));
+    assert!(java_code.contains("    .followRedirects(HttpClient.Redirect.NORMAL)"));
+    assert!(java_code.contains("    .connectTimeout(Duration.ofSeconds(10))"));
+    assert!(java_code.contains("    .timeout(Duration.ofSeconds(30))"));
+    assert!(java_code.contains(
+        "    .proxy(ProxySelector.of(new InetSocketAddress(\"proxy.example.com\", 3128)))"
+    ));
+    assert!(java_code.contains("import java.net.ProxySelector;"));
+    assert!(java_code.contains("import java.time.Duration;"));
+}
+
+#[test]
+fn test_basic_auth_uses_authenticator() {
+    let cmd = "curl -u admin:secret https://api.example.com/private";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("    .authenticator(new Authenticator() {"));
+    assert!(java_code
+        .contains("return new PasswordAuthentication(\"admin\", \"secret\".toCharArray());"));
+    assert!(java_code.contains("import java.net.Authenticator;"));
+}
+
+#[test]
+fn test_binary_file_and_output() {
+    let cmd = "curl --data-binary @/path/to/file.bin -o result.json https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("HttpRequest.BodyPublishers.ofFile(Path.of(\"/path/to/file.bin\"))"));
+    assert!(java_code.contains("HttpResponse.BodyHandlers.ofFile(Path.of(\"result.json\"))"));
+}
+
+#[test]
+fn test_insecure_and_multipart() {
+    let cmd = "curl -k -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpClient;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("    .sslContext(sslContext)"));
+    assert!(java_code.contains("parts.add(Files.readAllBytes(Path.of(\"/tmp/data.csv\")));"));
+    assert!(java_code.contains("filename=\\\"data.csv\\\"\\r\\nContent-Type: text/csv"));
+    assert!(java_code
+        .contains("    .header(\"Content-Type\", \"multipart/form-data; boundary=\" + boundary)"));
+    assert!(java_code.contains("HttpRequest.BodyPublishers.ofByteArrays(parts)"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = HttpClient;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains(".method(\"GET\", HttpRequest.BodyPublishers.noBody())"));
+    assert!(!java_code.contains("BodyPublishers.ofString"));
+}
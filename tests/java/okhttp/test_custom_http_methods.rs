@@ -0,0 +1,39 @@
+use curl_parser::converters::java::OkHttp;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_get_has_no_body() {
+    let cmd = "curl https://api.example.com/resource";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = OkHttp;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("    .method(\"GET\", null)"));
+}
+
+#[test]
+fn test_post_without_body_sends_empty_body() {
+    let cmd = "curl -X POST https://api.example.com/trigger";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = OkHttp;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("    .method(\"POST\", RequestBody.create(new byte[0]))"));
+}
+
+#[test]
+fn test_custom_method_with_json() {
+    let cmd = "curl -X PATCH --json '{\"active\":true}' https://api.example.com/users/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = OkHttp;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains(
+        "RequestBody body = RequestBody.create(\"{\\\"active\\\":true}\", MediaType.parse(\"application/json\"));"
+    ));
+    assert!(java_code.contains("    .method(\"PATCH\", body)"));
+}
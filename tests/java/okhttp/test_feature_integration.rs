@@ -0,0 +1,77 @@
+use curl_parser::converters::java::OkHttp;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_multipart_form() {
+    let cmd = "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv;filename=upload.csv' \
+               https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = OkHttp;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("RequestBody body = new MultipartBody.Builder()"));
+    assert!(java_code.contains("    .setType(MultipartBody.FORM)"));
+    assert!(java_code.contains("    .addFormDataPart(\"name\", \"report\")"));
+    assert!(java_code.contains(
+        "    .addFormDataPart(\"file\", \"upload.csv\", RequestBody.create(new File(\"/tmp/data.csv\"), MediaType.parse(\"text/csv\")))"
+    ));
+    assert!(java_code.contains("import okhttp3.MultipartBody;"));
+}
+
+#[test]
+fn test_insecure_trust_all() {
+    let cmd = "curl -k https://self-signed.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = OkHttp;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains("X509TrustManager trustAllManager = new X509TrustManager() {"));
+    assert!(java_code.contains(
+        "sslContext.init(null, new TrustManager[] { trustAllManager }, new SecureRandom());"
+    ));
+    assert!(
+        java_code.contains("    .sslSocketFactory(sslContext.getSocketFactory(), trustAllManager)")
+    );
+    assert!(java_code.contains("    .hostnameVerifier((hostname, session) -> true)"));
+}
+
+#[test]
+fn test_auth_timeouts_and_proxy() {
+    let cmd = "curl -u user:pass --connect-timeout 5 -m 20 -L \
+               -x socks5://127.0.0.1:1080 --proxy-user p:q https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = OkHttp;
+    let java_code = converter.convert(&parsed);
+
+    assert!(
+        java_code.contains("    .header(\"Authorization\", Credentials.basic(\"user\", \"pass\"))")
+    );
+    assert!(java_code.contains("    .connectTimeout(5, TimeUnit.SECONDS)"));
+    assert!(java_code.contains("    .callTimeout(20, TimeUnit.SECONDS)"));
+    assert!(java_code.contains("    .followRedirects(true)"));
+    assert!(java_code.contains(
+        "    .proxy(new Proxy(Proxy.Type.SOCKS, new InetSocketAddress(\"127.0.0.1\", 1080)))"
+    ));
+    assert!(java_code.contains("Credentials.basic(\"p\", \"q\")"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = OkHttp;
+    let java_code = converter.convert(&parsed);
+
+    assert!(java_code.contains(".method(\"GET\", null)"));
+    assert!(!java_code.contains("FormBody"));
+}
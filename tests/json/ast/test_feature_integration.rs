@@ -0,0 +1,100 @@
+use curl_parser::converters::json::{JsonAst, SCHEMA_VERSION};
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+use serde_json::{json, Value};
+
+#[test]
+fn test_versioned_ast() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' -d '{\"name\":\"test\"}' \
+               https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = JsonAst;
+    let result: Value = serde_json::from_str(&converter.convert(&parsed)).unwrap();
+
+    assert_eq!(result["schema_version"], SCHEMA_VERSION);
+    assert_eq!(result["method"], "POST");
+    assert_eq!(result["url"], "https://api.example.com/items");
+    assert_eq!(
+        result["headers"],
+        json!({"Content-Type": "application/json"})
+    );
+    assert_eq!(result["data"], "{\"name\":\"test\"}");
+    assert_eq!(result["ssl_options"]["cacert"], Value::Null);
+}
+
+#[test]
+fn test_maps_are_sorted() {
+    let cmd =
+        "curl -H 'X-Zeta: 1' -H 'Accept: */*' -H 'X-Alpha: 2' -b 'z=1; a=2' https://example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = JsonAst;
+    let result: Value = serde_json::from_str(&converter.convert(&parsed)).unwrap();
+
+    let headers: Vec<&String> = result["headers"].as_object().unwrap().keys().collect();
+    assert_eq!(headers, ["Accept", "X-Alpha", "X-Zeta"]);
+    let cookies: Vec<&String> = result["cookies"].as_object().unwrap().keys().collect();
+    assert_eq!(cookies, ["a", "z"]);
+}
+
+#[test]
+fn test_output_matches_schema() {
+    let schema = JsonAst::schema();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+
+    let converter = JsonAst;
+    for cmd in [
+        "curl https://example.com",
+        "curl -X PUT --digest -u admin:secret -k --cacert ca.pem -m 5 https://example.com/me",
+        "curl -F 'name=report' -F 'file=@data.csv;type=text/csv' https://example.com/upload",
+        "curl -x http://proxy:8080 -U user:pass --retry 3 -L --max-redirs 2 https://example.com",
+    ] {
+        let parsed = parse_curl_command(cmd).unwrap();
+        let instance: Value = serde_json::from_str(&converter.convert(&parsed)).unwrap();
+        assert!(validator.is_valid(&instance), "{} does not match", cmd);
+    }
+
+    let mut instance: Value = serde_json::from_str(
+        &converter.convert(&parse_curl_command("curl https://example.com").unwrap()),
+    )
+    .unwrap();
+    instance["schema_version"] = json!(SCHEMA_VERSION + 1);
+    assert!(!validator.is_valid(&instance));
+}
+
+#[test]
+fn test_several_commands_as_ndjson() {
+    let schema = JsonAst::schema();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+
+    let curl_cmds = vec![
+        parse_curl_command("curl https://example.com/users").unwrap(),
+        parse_curl_command("curl -X DELETE https://example.com/users/1").unwrap(),
+    ];
+
+    let converter = JsonAst;
+    let result = converter.convert_many(&curl_cmds);
+
+    let lines: Vec<Value> = result
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["method"], "GET");
+    assert_eq!(lines[1]["method"], "DELETE");
+    assert!(lines.iter().all(|line| validator.is_valid(line)));
+
+    assert_eq!(
+        converter.convert_many(&curl_cmds[..1]),
+        converter.convert(&curl_cmds[0])
+    );
+}
+
+#[test]
+fn test_published_schema_is_current() {
+    let published: Value =
+        serde_json::from_str(include_str!("../../../schema/curl-command.schema.json")).unwrap();
+
+    assert_eq!(published, JsonAst::schema());
+}
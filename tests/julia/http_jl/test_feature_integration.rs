@@ -0,0 +1,60 @@
+use curl_parser::converters::julia::HttpJl;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_request_with_dict_body() {
+    let cmd = "curl -X PUT -H 'X-Price: $5' --json '{\"name\":\"test\",\"tags\":[1,null]}' \
+               https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpJl;
+    let julia_code = converter.convert(&parsed);
+
+    assert!(julia_code.starts_with("using HTTP\nusing JSON3\n\n"));
+    assert!(julia_code.contains("    \"X-Price\" => \"\\$5\",\n"));
+    assert!(julia_code.contains(
+        "body = JSON3.write(Dict{String, Any}(\"name\" => \"test\", \"tags\" => Any[1, nothing]))"
+    ));
+    assert!(julia_code.contains(
+        "response = HTTP.request(\"PUT\", \"https://api.example.com/items/1\", headers, body;\n"
+    ));
+    assert!(julia_code.contains("    status_exception = false,\n)\n"));
+}
+
+#[test]
+fn test_keyword_options() {
+    let cmd = "curl -k -L --max-redirs 3 --connect-timeout 5 --retry 2 -u admin:secret \
+               -x http://proxy.local:3128 https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpJl;
+    let julia_code = converter.convert(&parsed);
+
+    assert!(julia_code.contains("using Base64\n"));
+    assert!(julia_code
+        .contains("    \"Authorization\" => \"Basic \" * base64encode(\"admin:secret\"),\n"));
+    assert!(julia_code
+        .contains("response = HTTP.request(\"GET\", \"https://api.example.com\", headers;\n"));
+    assert!(julia_code.contains("    require_ssl_verification = false,\n"));
+    assert!(julia_code.contains("    redirect = true,\n    redirect_limit = 3,\n"));
+    assert!(julia_code.contains("    connect_timeout = 5,\n"));
+    assert!(julia_code.contains("    proxy = \"http://proxy.local:3128\",\n"));
+    assert!(julia_code.contains("    retries = 2,\n"));
+}
+
+#[test]
+fn test_multipart_form() {
+    let cmd = "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' -w '%{http_code}' \
+               https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = HttpJl;
+    let julia_code = converter.convert(&parsed);
+
+    assert!(julia_code.contains("body = HTTP.Form([\n    \"name\" => \"report\",\n"));
+    assert!(julia_code.contains(
+        "    \"file\" => HTTP.Multipart(\"data.csv\", open(\"/tmp/data.csv\"), \"text/csv\"),\n"
+    ));
+    assert!(julia_code.ends_with("println(response.status)\n"));
+}
@@ -0,0 +1,2 @@
+mod test_custom_http_methods;
+mod test_feature_integration;
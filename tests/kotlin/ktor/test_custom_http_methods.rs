@@ -0,0 +1,50 @@
+use curl_parser::converters::kotlin::Ktor;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_standard_http_methods() {
+    let methods = vec![
+        ("GET", "HttpMethod.Get"),
+        ("POST", "HttpMethod.Post"),
+        ("PUT", "HttpMethod.Put"),
+        ("DELETE", "HttpMethod.Delete"),
+        ("PATCH", "HttpMethod.Patch"),
+    ];
+
+    for (method, ktor_method) in methods {
+        let cmd = format!("curl -X {} https://api.example.com/resource", method);
+        let parsed = parse_curl_command(&cmd).unwrap();
+
+        let converter = Ktor;
+        let kotlin_code = converter.convert(&parsed);
+
+        assert!(kotlin_code.contains(&format!("        method = {}", ktor_method)));
+    }
+}
+
+#[test]
+fn test_custom_method() {
+    let cmd = "curl -X PURGE https://cdn.example.com/assets/app.js";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Ktor;
+    let kotlin_code = converter.convert(&parsed);
+
+    assert!(kotlin_code.contains(
+        "    val response = client.request(\"https://cdn.example.com/assets/app.js\") {"
+    ));
+    assert!(kotlin_code.contains("        method = HttpMethod(\"PURGE\")"));
+}
+
+#[test]
+fn test_json_body_with_dollar_sign() {
+    let cmd = "curl -X PATCH --json '{\"price\":\"$5\"}' https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Ktor;
+    let kotlin_code = converter.convert(&parsed);
+
+    assert!(kotlin_code.contains("        contentType(ContentType.parse(\"application/json\"))"));
+    assert!(kotlin_code.contains("        setBody(\"{\\\"price\\\":\\\"\\$5\\\"}\")"));
+}
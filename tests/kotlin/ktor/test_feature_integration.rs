@@ -0,0 +1,94 @@
+use curl_parser::converters::kotlin::Ktor;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_client_plugins() {
+    let cmd = "curl -u admin:secret -L --connect-timeout 5 -m 30 https://api.example.com/private";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Ktor;
+    let kotlin_code = converter.convert(&parsed);
+
+    assert!(kotlin_code.contains("    val client = HttpClient(CIO) {"));
+    assert!(kotlin_code.contains("        followRedirects = true"));
+    assert!(kotlin_code.contains("        install(HttpTimeout) {"));
+    assert!(kotlin_code.contains("            connectTimeoutMillis = 5000"));
+    assert!(kotlin_code.contains("            requestTimeoutMillis = 30000"));
+    assert!(kotlin_code.contains("        install(Auth) {"));
+    assert!(kotlin_code.contains("            basic {"));
+    assert!(kotlin_code.contains(
+        "                    BasicAuthCredentials(username = \"admin\", password = \"secret\")"
+    ));
+    assert!(converter.warnings(&parsed).is_empty());
+}
+
+#[test]
+fn test_multipart_form_uses_submit_form_with_binary_data() {
+    let cmd =
+        "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Ktor;
+    let kotlin_code = converter.convert(&parsed);
+
+    assert!(kotlin_code.contains("    val response = client.submitFormWithBinaryData("));
+    assert!(kotlin_code.contains("        url = \"https://api.example.com/upload\","));
+    assert!(kotlin_code.contains("            append(\"name\", \"report\")"));
+    assert!(kotlin_code.contains(
+        "            append(\"file\", File(\"/tmp/data.csv\").readBytes(), Headers.build {"
+    ));
+    assert!(kotlin_code.contains("                append(HttpHeaders.ContentType, \"text/csv\")"));
+    assert!(!kotlin_code.contains("        method = HttpMethod.Post"));
+}
+
+#[test]
+fn test_unsupported_options_produce_warnings() {
+    let cmd = "curl --ntlm -u user:pass -L --max-redirs 3 -x socks5://127.0.0.1:1080 https://intranet.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Ktor;
+    let warnings = converter.warnings(&parsed);
+
+    assert_eq!(warnings.len(), 3);
+    assert!(warnings.contains(&"NTLM authentication is not supported by Ktor".to_string()));
+    assert!(
+        warnings.contains(&"Ktor does not support limiting redirects (--max-redirs)".to_string())
+    );
+
+    let kotlin_code = converter.convert(&parsed);
+    assert!(kotlin_code.starts_with("// Warning: NTLM authentication is not supported by Ktor\n"));
+    assert!(!kotlin_code.contains("install(Auth)"));
+}
+
+#[test]
+fn test_insecure_and_proxy_engine_config() {
+    let cmd = "curl -k -x proxy.example.com:3128 https://self-signed.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Ktor;
+    let kotlin_code = converter.convert(&parsed);
+
+    assert!(kotlin_code.contains("import io.ktor.client.engine.*\n"));
+    assert!(kotlin_code.contains("        engine {"));
+    assert!(kotlin_code
+        .contains("            proxy = ProxyBuilder.http(\"http://proxy.example.com:3128\")"));
+    assert!(kotlin_code.contains("                trustManager = object : X509TrustManager {"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Ktor;
+    let kotlin_code = converter.convert(&parsed);
+
+    assert!(kotlin_code.contains("method = HttpMethod.Get"));
+    assert!(!kotlin_code.contains("FormDataContent"));
+}
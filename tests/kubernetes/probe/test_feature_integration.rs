@@ -0,0 +1,83 @@
+use curl_parser::converters::kubernetes::KubernetesProbe;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use serde_json::{json, Value};
+
+fn probes(output: &str) -> Value {
+    serde_yaml_ng::from_str(output).unwrap()
+}
+
+#[test]
+fn test_http_get_probe() {
+    let cmd = "curl -H 'X-Probe: liveness' -m 2 'http://localhost:8080/healthz?full=1'";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = KubernetesProbe;
+    let result = converter.convert(&parsed);
+    let expected = json!({
+        "httpGet": {
+            "scheme": "HTTP",
+            "host": "localhost",
+            "port": 8080,
+            "path": "/healthz?full=1",
+            "httpHeaders": [{"name": "X-Probe", "value": "liveness"}],
+        },
+        "timeoutSeconds": 2,
+    });
+
+    assert!(converter.warnings(&parsed).is_empty());
+    assert_eq!(
+        probes(&result),
+        json!({"livenessProbe": expected, "readinessProbe": expected})
+    );
+}
+
+#[test]
+fn test_default_port_and_basic_auth_header() {
+    let cmd = "curl -u user:pass --retry 2 https://example.com/ready";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = KubernetesProbe;
+    let probe = &probes(&converter.convert(&parsed))["readinessProbe"];
+
+    assert_eq!(probe["httpGet"]["scheme"], "HTTPS");
+    assert_eq!(probe["httpGet"]["port"], 443);
+    assert_eq!(
+        probe["httpGet"]["httpHeaders"],
+        json!([{"name": "Authorization", "value": "Basic dXNlcjpwYXNz"}])
+    );
+    assert_eq!(probe["failureThreshold"], 3);
+}
+
+#[test]
+fn test_body_and_method_are_reported() {
+    let cmd = "curl -X POST -d 'check=deep' https://example.com/health";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = KubernetesProbe;
+    let warnings = converter.warnings(&parsed);
+
+    assert_eq!(
+        warnings,
+        vec![
+            "Probes always send GET, method POST ignored".to_string(),
+            "Probes cannot send a request body, body ignored".to_string(),
+        ]
+    );
+
+    let result = converter.convert(&parsed);
+    assert!(result.starts_with("# Warning: Probes always send GET"));
+    assert_eq!(
+        probes(&result)["livenessProbe"]["httpGet"]["path"],
+        "/health"
+    );
+}
+
+#[test]
+fn test_tls_and_proxy_options_are_reported() {
+    let cmd = "curl --cert client.pem -x http://proxy:3128 -o out.txt https://example.com/health";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = KubernetesProbe;
+    assert_eq!(converter.warnings(&parsed).len(), 3);
+}
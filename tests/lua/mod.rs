@@ -0,0 +1 @@
+pub mod resty_http;
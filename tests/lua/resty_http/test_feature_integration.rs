@@ -0,0 +1,56 @@
+use curl_parser::converters::lua::RestyHttp;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_request_uri_options() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' -u admin:secret -k \
+               -d '{\"name\":\"test\"}' https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = RestyHttp;
+    let lua_code = converter.convert(&parsed);
+
+    assert!(
+        lua_code.starts_with("local http = require(\"resty.http\")\n\nlocal httpc = http.new()\n")
+    );
+    assert!(lua_code
+        .contains("local res, err = httpc:request_uri(\"https://api.example.com/items\", {\n"));
+    assert!(lua_code.contains("  method = \"POST\",\n"));
+    assert!(lua_code.contains("  body = \"{\\\"name\\\":\\\"test\\\"}\",\n"));
+    assert!(lua_code.contains("    [\"Content-Type\"] = \"application/json\",\n"));
+    assert!(lua_code.contains(
+        "    [\"Authorization\"] = \"Basic \" .. ngx.encode_base64(\"admin:secret\"),\n"
+    ));
+    assert!(lua_code.contains("  ssl_verify = false,\n"));
+    assert!(lua_code.ends_with("ngx.say(res.body)\n"));
+}
+
+#[test]
+fn test_timeouts_and_proxy() {
+    let cmd = "curl --connect-timeout 2 -m 10 -x http://proxy.local:3128 https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = RestyHttp;
+    let lua_code = converter.convert(&parsed);
+
+    assert!(lua_code.contains("httpc:set_timeouts(2000, 10000, 10000)\n"));
+    assert!(lua_code.contains("  http_proxy = \"http://proxy.local:3128\",\n"));
+    assert!(lua_code.contains("  https_proxy = \"http://proxy.local:3128\",\n"));
+}
+
+#[test]
+fn test_multipart_body_and_warnings() {
+    let cmd = "curl -L -F name=report -F file=@/tmp/data.csv https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = RestyHttp;
+    let lua_code = converter.convert(&parsed);
+
+    assert!(lua_code.contains("local function read_file(path)\n"));
+    assert!(lua_code.contains("  read_file(\"/tmp/data.csv\"),\n"));
+    assert!(lua_code.contains("}, \"\\r\\n\")\n"));
+    assert!(lua_code
+        .contains("    [\"Content-Type\"] = \"multipart/form-data; boundary=\" .. boundary,\n"));
+    assert!(lua_code.starts_with("-- Warning: resty.http does not follow redirects"));
+}
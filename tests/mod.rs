@@ -1,2 +1,32 @@
+pub mod ansible;
+pub mod bruno;
+pub mod c;
+pub mod csharp;
+pub mod dart;
+pub mod elixir;
+pub mod erlang;
+pub mod har;
+pub mod http_file;
+pub mod hurl;
+pub mod importers;
+pub mod insomnia;
+pub mod java;
+pub mod json;
+pub mod julia;
+pub mod kotlin;
+pub mod kubernetes;
+pub mod lua;
 pub mod nodejs;
+pub mod openapi;
+pub mod parser;
+pub mod perl;
+pub mod php;
+pub mod postman;
+pub mod powershell;
 pub mod python;
+pub mod r;
+pub mod ruby;
+pub mod shell;
+pub mod swift;
+pub mod terraform;
+pub mod validation;
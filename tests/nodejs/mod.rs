@@ -1 +1,2 @@
 pub mod axios;
+pub mod k6;
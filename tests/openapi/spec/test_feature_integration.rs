@@ -0,0 +1,152 @@
+use curl_parser::converters::openapi::{OpenApiSpec, SpecFormat};
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+use serde_json::{json, Value};
+
+fn document(cmds: &[&str]) -> Value {
+    let curl_cmds: Vec<_> = cmds
+        .iter()
+        .map(|cmd| parse_curl_command(cmd).unwrap())
+        .collect();
+
+    let converter = OpenApiSpec {
+        format: SpecFormat::Json,
+    };
+    serde_json::from_str(&converter.convert_many(&curl_cmds)).unwrap()
+}
+
+#[test]
+fn test_path_templating_from_varying_segments() {
+    let spec = document(&[
+        "curl https://api.example.com/users/123",
+        "curl https://api.example.com/users/alice",
+        "curl https://api.example.com/users/123/posts/456",
+        "curl https://api.example.com/status",
+    ]);
+
+    assert_eq!(spec["openapi"], "3.1.0");
+    assert_eq!(spec["servers"], json!([{"url": "https://api.example.com"}]));
+
+    let paths: Vec<&String> = spec["paths"].as_object().unwrap().keys().collect();
+    assert_eq!(
+        paths,
+        vec!["/users/{id}", "/users/{id}/posts/{postId}", "/status"]
+    );
+
+    let operation = &spec["paths"]["/users/{id}"]["get"];
+    assert_eq!(operation["operationId"], "getUsersById");
+    assert_eq!(
+        operation["parameters"][0],
+        json!({
+            "name": "id",
+            "in": "path",
+            "required": true,
+            "schema": {"type": ["integer", "string"]},
+            "example": 123
+        })
+    );
+}
+
+#[test]
+fn test_path_templating_from_non_numeric_segments() {
+    let spec = document(&[
+        "curl https://api.example.com/users/alice",
+        "curl https://api.example.com/users/bob",
+        "curl https://api.example.com/users/alice/repos",
+    ]);
+
+    let paths: Vec<&String> = spec["paths"].as_object().unwrap().keys().collect();
+    assert_eq!(paths, vec!["/users/{id}", "/users/alice/repos"]);
+
+    let operation = &spec["paths"]["/users/{id}"]["get"];
+    assert_eq!(
+        operation["parameters"][0],
+        json!({
+            "name": "id",
+            "in": "path",
+            "required": true,
+            "schema": {"type": "string"},
+            "example": "alice"
+        })
+    );
+}
+
+#[test]
+fn test_query_and_header_parameters() {
+    let spec = document(&[
+        "curl -H 'X-Tenant: acme' -H 'Accept: application/json' \
+         'https://api.example.com/search?q=rust&page=2'",
+        "curl -H 'X-Tenant: acme' 'https://api.example.com/search?q=serde'",
+    ]);
+
+    let parameters = spec["paths"]["/search"]["get"]["parameters"]
+        .as_array()
+        .unwrap();
+    assert_eq!(parameters.len(), 3);
+    assert_eq!(parameters[0]["name"], "q");
+    assert_eq!(parameters[0]["required"], true);
+    assert_eq!(parameters[1]["name"], "page");
+    assert_eq!(parameters[1]["required"], false);
+    assert_eq!(parameters[1]["schema"], json!({"type": "integer"}));
+    assert_eq!(parameters[2]["name"], "X-Tenant");
+    assert_eq!(parameters[2]["in"], "header");
+}
+
+#[test]
+fn test_security_schemes() {
+    let spec = document(&[
+        "curl --oauth2-bearer token123 https://api.example.com/me",
+        "curl -u admin:secret https://api.example.com/admin",
+    ]);
+
+    assert_eq!(
+        spec["components"]["securitySchemes"],
+        json!({
+            "bearerAuth": {"type": "http", "scheme": "bearer"},
+            "basicAuth": {"type": "http", "scheme": "basic"}
+        })
+    );
+    assert_eq!(
+        spec["paths"]["/me"]["get"]["security"],
+        json!([{"bearerAuth": []}])
+    );
+    assert!(spec["paths"]["/me"]["get"].get("parameters").is_none());
+}
+
+#[test]
+fn test_request_body_schema_from_json() {
+    let spec = document(&[
+        "curl -X POST -H 'Content-Type: application/json' \
+         -d '{\"name\":\"a\",\"age\":3,\"tags\":[\"x\"]}' https://api.example.com/users",
+        "curl -X POST -H 'Content-Type: application/json' \
+         -d '{\"name\":\"b\",\"age\":4.5}' https://api.example.com/users",
+    ]);
+
+    let request_body = &spec["paths"]["/users"]["post"]["requestBody"];
+    assert_eq!(request_body["required"], true);
+    assert_eq!(
+        request_body["content"]["application/json"]["schema"],
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string"},
+                "age": {"type": "number"},
+                "tags": {"type": "array", "items": {"type": "string"}}
+            },
+            "required": ["name", "age"]
+        })
+    );
+}
+
+#[test]
+fn test_yaml_output() {
+    let parsed = parse_curl_command("curl https://api.example.com/users/42").unwrap();
+
+    let converter = OpenApiSpec {
+        format: SpecFormat::Yaml,
+    };
+    let result = converter.convert(&parsed);
+
+    assert!(result.starts_with("openapi: 3.1.0\n"));
+    assert!(result.contains("  /users/{id}:\n    get:\n      operationId: getUsersById\n"));
+}
@@ -0,0 +1,28 @@
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_data_urlencode_on_get_moves_params_to_query() {
+    let cmd = "curl --data-urlencode 'q=hello world' https://api.example.com/search";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    assert_eq!(parsed.method, "GET");
+    assert_eq!(parsed.url, "https://api.example.com/search?q=hello+world");
+    assert_eq!(
+        parsed.url_encoded_params.get("q").map(String::as_str),
+        Some("hello world")
+    );
+    assert!(parsed.data.is_none());
+}
+
+#[test]
+fn test_data_urlencode_with_post_keeps_url() {
+    let cmd = "curl -X POST --data-urlencode 'q=hello world' https://api.example.com/search";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    assert_eq!(parsed.method, "POST");
+    assert_eq!(parsed.url, "https://api.example.com/search");
+    assert_eq!(
+        parsed.url_encoded_params.get("q").map(String::as_str),
+        Some("hello world")
+    );
+}
@@ -0,0 +1,71 @@
+use curl_parser::converters::perl::Lwp;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_request_with_headers_and_body() {
+    let cmd = "curl -X PUT -H 'Content-Type: application/json' -u admin:secret \
+               -d '{\"name\":\"it'\\''s\"}' https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Lwp;
+    let perl_code = converter.convert(&parsed);
+
+    assert!(perl_code.contains("use LWP::UserAgent;\nuse HTTP::Request;\n"));
+    assert!(perl_code
+        .contains("my $req = HTTP::Request->new('PUT' => 'https://api.example.com/items/1');"));
+    assert!(perl_code.contains("$req->header('Content-Type' => 'application/json');"));
+    assert!(perl_code.contains("$req->authorization_basic('admin', 'secret');"));
+    assert!(perl_code.contains("$req->content('{\"name\":\"it\\'s\"}');"));
+    assert!(perl_code.ends_with("print $res->decoded_content;\n"));
+}
+
+#[test]
+fn test_form_data_upload() {
+    let cmd =
+        "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Lwp;
+    let perl_code = converter.convert(&parsed);
+
+    assert!(perl_code.contains("use HTTP::Request::Common qw(POST);"));
+    assert!(perl_code.contains(
+        "my $req = POST 'https://api.example.com/upload',\n    Content_Type => 'form-data',\n"
+    ));
+    assert!(perl_code.contains("        'name' => 'report',\n"));
+    assert!(perl_code.contains(
+        "        'file' => ['/tmp/data.csv', 'data.csv', 'Content-Type' => 'text/csv'],\n"
+    ));
+    assert!(!perl_code.contains("$req->method("));
+}
+
+#[test]
+fn test_user_agent_options() {
+    let cmd = "curl -k -L --max-redirs 3 -m 30 -A monitor/1.0 -x http://proxy.local:3128 \
+               --digest -u user:pass -o out.html https://api.example.com:8443/status";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Lwp;
+    let perl_code = converter.convert(&parsed);
+
+    assert!(perl_code.contains("    agent => 'monitor/1.0',\n"));
+    assert!(perl_code.contains("    ssl_opts => { verify_hostname => 0, SSL_verify_mode => 0 },\n"));
+    assert!(perl_code.contains("    timeout => 30,\n    max_redirect => 3,\n"));
+    assert!(perl_code.contains("$ua->proxy(['http', 'https'], 'http://proxy.local:3128');"));
+    assert!(perl_code.contains("$ua->credentials('api.example.com:8443', '', 'user', 'pass');"));
+    assert!(perl_code.ends_with("my $res = $ua->request($req, 'out.html');\n"));
+    assert_eq!(converter.warnings(&parsed).len(), 2);
+}
+
+#[test]
+fn test_compressed_uses_scalar_decodable() {
+    let cmd = "curl --compressed https://api.example.com/data";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Lwp;
+    let perl_code = converter.convert(&parsed);
+
+    assert!(perl_code
+        .contains("$req->header('Accept-Encoding' => scalar HTTP::Message::decodable());\n"));
+}
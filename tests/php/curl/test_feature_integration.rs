@@ -0,0 +1,100 @@
+use curl_parser::converters::php::Curl;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_basic_options() {
+    let cmd = "curl -H 'Accept: application/json' -A 'my-agent/1.0' -b 'session=abc' \
+               https://api.example.com/resource";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Curl;
+    let php_code = converter.convert(&parsed);
+
+    assert!(php_code.contains("$ch = curl_init();"));
+    assert!(php_code.contains("curl_setopt_array($ch, ["));
+    assert!(php_code.contains("    CURLOPT_URL => 'https://api.example.com/resource',"));
+    assert!(php_code.contains("    CURLOPT_RETURNTRANSFER => true,"));
+    assert!(php_code.contains("        'Accept: application/json',"));
+    assert!(php_code.contains("    CURLOPT_USERAGENT => 'my-agent/1.0',"));
+    assert!(php_code.contains("    CURLOPT_COOKIE => 'session=abc',"));
+    assert!(!php_code.contains("CURLOPT_CUSTOMREQUEST"));
+}
+
+#[test]
+fn test_auth_type_and_alpn() {
+    let cmd = "curl --ntlm -u user:pass --no-alpn https://intranet.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Curl;
+    let php_code = converter.convert(&parsed);
+
+    assert!(php_code.contains("    CURLOPT_USERPWD => 'user:pass',"));
+    assert!(php_code.contains("    CURLOPT_HTTPAUTH => CURLAUTH_NTLM,"));
+    assert!(php_code.contains("    CURLOPT_SSL_ENABLE_ALPN => false,"));
+}
+
+#[test]
+fn test_methods_and_bodies() {
+    let converter = Curl;
+
+    let parsed = parse_curl_command("curl -d 'a=1' https://api.example.com").unwrap();
+    let php_code = converter.convert(&parsed);
+    assert!(php_code.contains("    CURLOPT_POSTFIELDS => 'a=1',"));
+    assert!(!php_code.contains("CURLOPT_CUSTOMREQUEST"));
+
+    let parsed = parse_curl_command("curl -X DELETE https://api.example.com/items/1").unwrap();
+    assert!(converter
+        .convert(&parsed)
+        .contains("    CURLOPT_CUSTOMREQUEST => 'DELETE',"));
+
+    let parsed = parse_curl_command(
+        "curl -F 'file=@/tmp/photo.jpg;type=image/jpeg' https://api.example.com/upload",
+    )
+    .unwrap();
+    assert!(converter
+        .convert(&parsed)
+        .contains("        'file' => new CURLFile('/tmp/photo.jpg', 'image/jpeg', 'photo.jpg'),"));
+}
+
+#[test]
+fn test_tls_proxy_and_redirect_options() {
+    let cmd = "curl -k --cacert ca.pem --cert client.pem --cert-type PEM --key client.key \
+               -x http://proxy:3128 --proxy-user pu:pp -L --max-redirs 4 \
+               --connect-timeout 5 -m 20 --compressed https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Curl;
+    let php_code = converter.convert(&parsed);
+
+    assert!(php_code.contains("    CURLOPT_SSL_VERIFYPEER => false,"));
+    assert!(php_code.contains("    CURLOPT_SSL_VERIFYHOST => 0,"));
+    assert!(php_code.contains("    CURLOPT_CAINFO => 'ca.pem',"));
+    assert!(php_code.contains("    CURLOPT_SSLCERT => 'client.pem',"));
+    assert!(php_code.contains("    CURLOPT_SSLCERTTYPE => 'PEM',"));
+    assert!(php_code.contains("    CURLOPT_SSLKEY => 'client.key',"));
+    assert!(php_code.contains("    CURLOPT_PROXY => 'http://proxy:3128',"));
+    assert!(php_code.contains("    CURLOPT_PROXYUSERPWD => 'pu:pp',"));
+    assert!(php_code.contains("    CURLOPT_FOLLOWLOCATION => true,"));
+    assert!(php_code.contains("    CURLOPT_MAXREDIRS => 4,"));
+    assert!(php_code.contains("    CURLOPT_CONNECTTIMEOUT => 5,"));
+    assert!(php_code.contains("    CURLOPT_TIMEOUT => 20,"));
+    assert!(php_code.contains("    CURLOPT_ENCODING => '',"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Curl;
+    let php_code = converter.convert(&parsed);
+
+    assert!(php_code.contains("CURLOPT_URL => 'https://api.example.com/search?q=hello+world'"));
+    assert!(!php_code.contains("CURLOPT_POSTFIELDS"));
+}
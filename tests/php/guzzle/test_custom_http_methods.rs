@@ -0,0 +1,46 @@
+use curl_parser::converters::php::Guzzle;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_http_methods() {
+    let methods = vec!["GET", "POST", "PUT", "DELETE", "PATCH", "PURGE"];
+
+    for method in methods {
+        let cmd = format!("curl -X {} https://api.example.com/resource", method);
+        let parsed = parse_curl_command(&cmd).unwrap();
+
+        let converter = Guzzle;
+        let php_code = converter.convert(&parsed);
+
+        assert!(php_code.contains(&format!(
+            "$response = $client->request('{}', 'https://api.example.com/resource', [",
+            method
+        )));
+    }
+}
+
+#[test]
+fn test_json_body_rendered_as_array() {
+    let cmd =
+        "curl -X PATCH --json '{\"name\":\"O'\\''Brien\",\"tags\":[\"a\",\"b\"],\"active\":true}' \
+               https://api.example.com/users/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Guzzle;
+    let php_code = converter.convert(&parsed);
+
+    assert!(php_code.contains("    'json' => [\n        'name' => 'O\\'Brien',\n        'tags' => [\n            'a',\n            'b',\n        ],\n        'active' => true,\n    ],"));
+}
+
+#[test]
+fn test_raw_body() {
+    let cmd = "curl -X PUT -d 'plain text' https://api.example.com/notes/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Guzzle;
+    let php_code = converter.convert(&parsed);
+
+    assert!(php_code.contains("    'body' => 'plain text',"));
+    assert!(!php_code.contains("'json'"));
+}
@@ -0,0 +1,133 @@
+use curl_parser::converters::postman::PostmanCollection;
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+use serde_json::{json, Value};
+
+fn collection(cmd: &str) -> Value {
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = PostmanCollection;
+    serde_json::from_str(&converter.convert(&parsed)).unwrap()
+}
+
+#[test]
+fn test_collection_info_and_request() {
+    let collection = collection(
+        "curl -X POST -H 'Content-Type: application/json' -H 'X-Api-Key: abc' \
+         -d '{\"name\":\"test\"}' 'https://api.example.com:8443/v1/items?draft=true'",
+    );
+
+    assert_eq!(
+        collection["info"]["schema"],
+        "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+    );
+
+    let item = &collection["item"][0];
+    assert_eq!(item["name"], "POST /v1/items");
+    assert_eq!(item["request"]["method"], "POST");
+    assert_eq!(
+        item["request"]["header"],
+        json!([
+            {"key": "Content-Type", "value": "application/json"},
+            {"key": "X-Api-Key", "value": "abc"}
+        ])
+    );
+    assert_eq!(
+        item["request"]["url"],
+        json!({
+            "raw": "https://api.example.com:8443/v1/items?draft=true",
+            "protocol": "https",
+            "host": ["api", "example", "com"],
+            "port": "8443",
+            "path": ["v1", "items"],
+            "query": [{"key": "draft", "value": "true"}]
+        })
+    );
+    assert_eq!(
+        item["request"]["body"],
+        json!({
+            "mode": "raw",
+            "raw": "{\"name\":\"test\"}",
+            "options": {"raw": {"language": "json"}}
+        })
+    );
+}
+
+#[test]
+fn test_auth_blocks() {
+    let basic = collection("curl -u admin:secret https://api.example.com");
+    assert_eq!(
+        basic["item"][0]["request"]["auth"],
+        json!({
+            "type": "basic",
+            "basic": [
+                {"key": "username", "value": "admin", "type": "string"},
+                {"key": "password", "value": "secret", "type": "string"}
+            ]
+        })
+    );
+
+    let ntlm = collection("curl --ntlm -u admin:secret https://api.example.com");
+    assert_eq!(ntlm["item"][0]["request"]["auth"]["type"], "ntlm");
+
+    let bearer = collection("curl --oauth2-bearer token123 https://api.example.com");
+    let request = &bearer["item"][0]["request"];
+    assert_eq!(
+        request["auth"],
+        json!({"type": "bearer", "bearer": [{"key": "token", "value": "token123", "type": "string"}]})
+    );
+    assert!(request["header"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_body_modes() {
+    let form = collection("curl -F 'name=report' -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload");
+    assert_eq!(
+        form["item"][0]["request"]["body"],
+        json!({
+            "mode": "formdata",
+            "formdata": [
+                {"key": "name", "value": "report", "type": "text"},
+                {"key": "file", "src": "/tmp/data.csv", "type": "file", "contentType": "text/csv"}
+            ]
+        })
+    );
+
+    let urlencoded =
+        collection("curl -X POST --data-urlencode 'q=hello world' https://api.example.com/search");
+    assert_eq!(
+        urlencoded["item"][0]["request"]["body"],
+        json!({"mode": "urlencoded", "urlencoded": [{"key": "q", "value": "hello world", "type": "text"}]})
+    );
+
+    let file = collection("curl --data-binary @payload.bin https://api.example.com/upload");
+    assert_eq!(
+        file["item"][0]["request"]["body"],
+        json!({"mode": "file", "file": {"src": "payload.bin"}})
+    );
+}
+
+#[test]
+fn test_protocol_profile_behavior() {
+    let collection = collection("curl -L --max-redirs 5 -k https://api.example.com");
+    assert_eq!(
+        collection["item"][0]["protocolProfileBehavior"],
+        json!({"followRedirects": true, "maxRedirects": 5, "strictSSL": false})
+    );
+}
+
+#[test]
+fn test_many_commands_in_one_collection() {
+    let curl_cmds = vec![
+        parse_curl_command("curl https://api.example.com/users").unwrap(),
+        parse_curl_command("curl -X DELETE https://api.example.com/users/1").unwrap(),
+    ];
+
+    let converter = PostmanCollection;
+    let collection: Value = serde_json::from_str(&converter.convert_many(&curl_cmds)).unwrap();
+
+    let items = collection["item"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["name"], "GET /users");
+    assert_eq!(items[1]["name"], "DELETE /users/1");
+}
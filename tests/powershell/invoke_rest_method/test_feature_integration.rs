@@ -0,0 +1,105 @@
+use curl_parser::converters::powershell::InvokeRestMethod;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_headers_method_and_body() {
+    let cmd = "curl -X POST -H 'Accept: application/json' -H 'Content-Type: application/json' \
+               -d '{\"name\":\"it'\\''s\"}' https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = InvokeRestMethod;
+    let ps_code = converter.convert(&parsed);
+
+    assert!(ps_code.contains("$headers = @{\n    'Accept' = 'application/json'\n}"));
+    assert!(ps_code.contains("$body = '{\"name\":\"it''s\"}'"));
+    assert!(
+        ps_code.contains("$response = Invoke-RestMethod -Uri 'https://api.example.com/items' `")
+    );
+    assert!(ps_code.contains("    -Method Post `"));
+    assert!(ps_code.contains("    -Headers $headers `"));
+    assert!(ps_code.contains("    -ContentType 'application/json' `"));
+    assert!(ps_code.contains("    -Body $body `"));
+}
+
+#[test]
+fn test_form_with_files() {
+    let cmd = "curl -F name=report -F file=@/tmp/data.csv https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = InvokeRestMethod;
+    let ps_code = converter.convert(&parsed);
+
+    assert!(ps_code.contains("    'name' = 'report'"));
+    assert!(ps_code.contains("    'file' = Get-Item -Path '/tmp/data.csv'"));
+    assert!(ps_code.contains("    -Form $form `"));
+    assert!(converter.warnings(&parsed).is_empty());
+}
+
+#[test]
+fn test_credentials_and_transport_options() {
+    let cmd = "curl -k -L --max-redirs 3 -m 20 -u admin:secret -x http://proxy.local:8080 \
+               --proxy-user puser:ppass -o result.json https://api.example.com/report";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = InvokeRestMethod;
+    let ps_code = converter.convert(&parsed);
+
+    assert!(ps_code.contains(
+        "$credential = New-Object System.Management.Automation.PSCredential('admin', (ConvertTo-SecureString 'secret' -AsPlainText -Force))"
+    ));
+    assert!(ps_code.contains("    -Credential $credential `\n    -Authentication Basic `"));
+    assert!(ps_code.contains("    -Proxy 'http://proxy.local:8080' `"));
+    assert!(ps_code.contains("    -ProxyCredential $proxyCredential `"));
+    assert!(ps_code.contains("    -SkipCertificateCheck `"));
+    assert!(ps_code.contains("    -MaximumRedirection 3 `"));
+    assert!(ps_code.contains("    -TimeoutSec 20 `"));
+    assert!(ps_code.contains("    -OutFile 'result.json' `"));
+    assert!(!ps_code.contains("$response\n"));
+}
+
+#[test]
+fn test_custom_method_with_status_code() {
+    let cmd = "curl -X PURGE -w '%{http_code}' https://cdn.example.com/asset";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = InvokeRestMethod;
+    let ps_code = converter.convert(&parsed);
+
+    assert!(
+        ps_code.contains("$response = Invoke-WebRequest -Uri 'https://cdn.example.com/asset' `")
+    );
+    assert!(ps_code.contains("    -CustomMethod 'PURGE' `"));
+    assert!(ps_code.contains("    -MaximumRedirection 0 `"));
+    assert!(ps_code.ends_with("$response.StatusCode\n"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = InvokeRestMethod;
+    let ps_code = converter.convert(&parsed);
+
+    assert!(ps_code.contains("-Method Get"));
+    assert!(!ps_code.contains("-Body"));
+}
+
+#[test]
+fn test_out_file_with_status_code_passes_response_through() {
+    let cmd = "curl -o report.pdf -w '%{http_code}' https://api.example.com/report";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = InvokeRestMethod;
+    let ps_code = converter.convert(&parsed);
+
+    assert!(ps_code.contains("$response = Invoke-WebRequest -Uri 'https://api.example.com/report'"));
+    assert!(ps_code.contains("    -OutFile 'report.pdf' `\n    -PassThru `\n"));
+    assert!(ps_code.contains("$response.StatusCode\n"));
+}
@@ -0,0 +1 @@
+pub mod invoke_rest_method;
@@ -0,0 +1,97 @@
+use curl_parser::converters::python::Locust;
+use curl_parser::converters::{Convert, ConvertMany};
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_http_user_with_task() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' -d '{\"name\":\"test\"}' \
+               https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Locust;
+    let result = converter.convert(&parsed);
+
+    assert_eq!(
+        result,
+        "from locust import HttpUser, between, task\n\
+         \n\
+         \n\
+         class ApiUser(HttpUser):\n\
+         \x20   host = \"https://api.example.com\"\n\
+         \x20   wait_time = between(1, 3)\n\
+         \n\
+         \x20   @task\n\
+         \x20   def post_items(self):\n\
+         \x20       self.client.request(\n\
+         \x20           \"POST\",\n\
+         \x20           \"/items\",\n\
+         \x20           name=\"POST /items\",\n\
+         \x20           headers={\"Content-Type\": \"application/json\"},\n\
+         \x20           data=\"{\\\"name\\\":\\\"test\\\"}\",\n\
+         \x20           allow_redirects=False,\n\
+         \x20       )\n"
+    );
+}
+
+#[test]
+fn test_auth_timeout_and_tls_options() {
+    let cmd = "curl -L -k --digest -u admin:secret -m 10 https://api.example.com/me";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Locust;
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains("from requests.auth import HTTPDigestAuth\n"));
+    assert!(result.contains("            auth=HTTPDigestAuth(\"admin\", \"secret\"),\n"));
+    assert!(result.contains("            verify=False,\n"));
+    assert!(result.contains("            timeout=10,\n"));
+    assert!(!result.contains("allow_redirects"));
+}
+
+#[test]
+fn test_multipart_upload() {
+    let cmd = "curl -L -F 'name=report' -F 'file=@/tmp/data.csv;type=text/csv' \
+               https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Locust;
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains("            data={\"name\": \"report\"},\n"));
+    assert!(result.contains(
+        "            files={\"file\": (\"data.csv\", open(\"/tmp/data.csv\", \"rb\"), \"text/csv\")},\n"
+    ));
+}
+
+#[test]
+fn test_sequence_in_one_task() {
+    let curl_cmds = vec![
+        parse_curl_command("curl -L https://api.example.com/users").unwrap(),
+        parse_curl_command("curl -L --ntlm -u admin:secret https://api.example.com/admin").unwrap(),
+    ];
+
+    let converter = Locust;
+    let result = converter.convert_many(&curl_cmds);
+
+    assert_eq!(result.matches("@task").count(), 1);
+    assert!(result.contains("    def request_sequence(self):\n"));
+    assert_eq!(result.matches("self.client.request(").count(), 2);
+    assert!(result.starts_with(
+        "# Warning: Locust's client only supports basic and digest auth, credentials omitted\n"
+    ));
+}
+
+#[test]
+fn test_host_from_first_command() {
+    let curl_cmds = vec![
+        parse_curl_command("curl -L 'https://api.example.com/users?page=2'").unwrap(),
+        parse_curl_command("curl -L https://auth.example.com/token").unwrap(),
+    ];
+
+    let converter = Locust;
+    let result = converter.convert_many(&curl_cmds);
+
+    assert!(result.contains("    host = \"https://api.example.com\"\n"));
+    assert!(result.contains("            \"/users?page=2\",\n"));
+    assert!(result.contains("            \"https://auth.example.com/token\",\n"));
+}
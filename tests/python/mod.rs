@@ -1 +1,2 @@
+pub mod locust;
 pub mod requests;
@@ -0,0 +1,63 @@
+use curl_parser::converters::r::Httr2;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_pipeline_with_json_list() {
+    let cmd = "curl -H 'X-Api-Key: abc' -u admin:secret \
+               --json '{\"name\":\"test\",\"tags\":[1,null],\"active\":true,\"if\":{}}' \
+               https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httr2;
+    let r_code = converter.convert(&parsed);
+
+    assert!(r_code
+        .starts_with("library(httr2)\n\nreq <- request(\"https://api.example.com/items\") |>\n"));
+    assert!(r_code.contains("  req_method(\"POST\") |>\n"));
+    assert!(r_code.contains("  req_headers(\n    `X-Api-Key` = \"abc\"\n  ) |>\n"));
+    assert!(r_code.contains(
+        "  req_body_json(list(name = \"test\", tags = list(1, NULL), active = TRUE, `if` = setNames(list(), character(0)))) |>\n"
+    ));
+    assert!(r_code.contains("  req_auth_basic(\"admin\", \"secret\") |>\n"));
+    assert!(r_code.contains("resp <- req_perform(req)\ncat(resp_body_string(resp))\n"));
+}
+
+#[test]
+fn test_multipart_and_form_bodies() {
+    let cmd =
+        "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httr2;
+    let r_code = converter.convert(&parsed);
+
+    assert!(r_code.contains(
+        "  req_body_multipart(\n    name = \"report\",\n    file = curl::form_file(\"/tmp/data.csv\", type = \"text/csv\")\n  ) |>\n"
+    ));
+
+    let cmd = "curl -X POST --data-urlencode 'q=hello world' https://api.example.com/search";
+    let parsed = parse_curl_command(cmd).unwrap();
+    assert!(converter
+        .convert(&parsed)
+        .contains("  req_body_form(q = \"hello world\") |>\n"));
+}
+
+#[test]
+fn test_transport_options() {
+    let cmd = "curl -k --ntlm -u user:pass --connect-timeout 5 -m 30 --retry 2 \
+               -x http://proxy.local:3128 --proxy-user p:q -o out.json https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httr2;
+    let r_code = converter.convert(&parsed);
+
+    assert!(r_code.contains(
+        "  req_proxy(\"http://proxy.local\", 3128, username = \"p\", password = \"q\") |>\n"
+    ));
+    assert!(r_code.contains(
+        "  req_options(httpauth = 8, userpwd = \"user:pass\", ssl_verifypeer = 0, ssl_verifyhost = 0, connecttimeout = 5, followlocation = 0) |>\n"
+    ));
+    assert!(r_code.contains("  req_timeout(30) |>\n  req_retry(max_tries = 3) |>\n"));
+    assert!(r_code.ends_with("resp <- req_perform(req, path = \"out.json\")\n"));
+}
@@ -0,0 +1,2 @@
+pub mod faraday;
+pub mod net_http;
@@ -0,0 +1,52 @@
+use curl_parser::converters::ruby::NetHttp;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_standard_http_methods() {
+    let methods = vec![
+        ("GET", "Get"),
+        ("POST", "Post"),
+        ("PUT", "Put"),
+        ("DELETE", "Delete"),
+        ("PATCH", "Patch"),
+        ("HEAD", "Head"),
+        ("OPTIONS", "Options"),
+    ];
+
+    for (method, class) in methods {
+        let cmd = format!("curl -X {} https://api.example.com/resource", method);
+        let parsed = parse_curl_command(&cmd).unwrap();
+
+        let converter = NetHttp;
+        let ruby_code = converter.convert(&parsed);
+
+        assert!(ruby_code.contains(&format!("request = Net::HTTP::{}.new(uri)", class)));
+    }
+}
+
+#[test]
+fn test_custom_method_uses_generic_request() {
+    let cmd = "curl -X PURGE https://cdn.example.com/assets/app.js";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = NetHttp;
+    let ruby_code = converter.convert(&parsed);
+
+    assert!(ruby_code.contains("request = Net::HTTPGenericRequest.new('PURGE', false, true, uri)"));
+}
+
+#[test]
+fn test_custom_method_with_body() {
+    let cmd = "curl -X PROPFIND -H 'Depth: 1' -d '<propfind/>' https://dav.example.com/files/";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = NetHttp;
+    let ruby_code = converter.convert(&parsed);
+
+    assert!(
+        ruby_code.contains("request = Net::HTTPGenericRequest.new('PROPFIND', true, true, uri)")
+    );
+    assert!(ruby_code.contains("request['Depth'] = '1'"));
+    assert!(ruby_code.contains("request.body = '<propfind/>'"));
+}
@@ -0,0 +1,82 @@
+use curl_parser::converters::ruby::NetHttp;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_connection_options() {
+    let cmd = "curl -k --connect-timeout 5 -m 30 --cert client.pem --key client.key \
+               https://api.example.com/resource";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = NetHttp;
+    let ruby_code = converter.convert(&parsed);
+
+    assert!(ruby_code.contains("require 'openssl'"));
+    assert!(ruby_code.contains("http = Net::HTTP.new(uri.host, uri.port)"));
+    assert!(ruby_code.contains("http.use_ssl = uri.scheme == 'https'"));
+    assert!(ruby_code.contains("http.verify_mode = OpenSSL::SSL::VERIFY_NONE"));
+    assert!(ruby_code.contains("http.open_timeout = 5"));
+    assert!(ruby_code.contains("http.read_timeout = 30"));
+    assert!(
+        ruby_code.contains("http.cert = OpenSSL::X509::Certificate.new(File.read('client.pem'))")
+    );
+}
+
+#[test]
+fn test_proxy_and_basic_auth() {
+    let cmd = "curl -u admin:secret -x http://proxy.example.com:3128 --proxy-user pu:pp \
+               https://api.example.com/private";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = NetHttp;
+    let ruby_code = converter.convert(&parsed);
+
+    assert!(ruby_code.contains("proxy = Net::HTTP::Proxy('proxy.example.com', 3128, 'pu', 'pp')"));
+    assert!(ruby_code.contains("http = proxy.new(uri.host, uri.port)"));
+    assert!(ruby_code.contains("request.basic_auth('admin', 'secret')"));
+}
+
+#[test]
+fn test_multipart_set_form() {
+    let cmd =
+        "curl -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = NetHttp;
+    let ruby_code = converter.convert(&parsed);
+
+    assert!(ruby_code.contains("request.set_form([\n  ['name', 'report'],\n"));
+    assert!(ruby_code.contains(
+        "  ['file', File.open('/tmp/data.csv'), { filename: 'data.csv', content_type: 'text/csv' }],"
+    ));
+    assert!(ruby_code.contains("], 'multipart/form-data')"));
+}
+
+#[test]
+fn test_redirect_warning() {
+    let cmd = "curl -L https://example.com/moved";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = NetHttp;
+    let warnings = converter.warnings(&parsed);
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("does not follow redirects"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = NetHttp;
+    let ruby_code = converter.convert(&parsed);
+
+    assert!(ruby_code.contains("Net::HTTP::Get.new(uri)"));
+    assert!(!ruby_code.contains("set_form_data"));
+}
@@ -0,0 +1,79 @@
+use curl_parser::converters::shell::Httpie;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_json_request_items() {
+    let cmd = "curl -X PUT -H 'X-Api-Key: abc 123' --json '{\"name\":\"test\",\"count\":2,\"tags\":[\"a\"]}' \
+               https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httpie;
+    let http_cmd = converter.convert(&parsed);
+
+    assert!(http_cmd.starts_with("http \\\n"));
+    assert!(http_cmd.contains("  PUT \\\n  https://api.example.com/items/1 \\\n"));
+    assert!(http_cmd.contains("  'X-Api-Key:abc 123' \\\n"));
+    assert!(http_cmd.contains("  name=test \\\n"));
+    assert!(http_cmd.contains("  count:=2 \\\n"));
+    assert!(http_cmd.ends_with("  'tags:=[\"a\"]'\n"));
+}
+
+#[test]
+fn test_multipart_fields_and_files() {
+    let cmd = "curl -F name=report -F 'file=@/tmp/my data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httpie;
+    let http_cmd = converter.convert(&parsed);
+
+    assert!(http_cmd.contains("  --multipart \\\n"));
+    assert!(http_cmd.contains("  name=report \\\n"));
+    assert!(http_cmd.contains("  'file@/tmp/my data.csv;type=text/csv'\n"));
+}
+
+#[test]
+fn test_options_mapping() {
+    let cmd =
+        "curl -k -L --max-redirs 2 --digest -u user:pass -m 10 https://api.example.com/secure";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httpie;
+    let http_cmd = converter.convert(&parsed);
+
+    assert!(http_cmd.contains("  --auth-type=digest \\\n  --auth=user:pass \\\n"));
+    assert!(http_cmd.contains("  --verify=no \\\n"));
+    assert!(http_cmd.contains("  --follow \\\n  --max-redirects=2 \\\n"));
+    assert!(http_cmd.contains("  --timeout=10 \\\n"));
+    assert!(http_cmd.contains("  GET \\\n"));
+}
+
+#[test]
+fn test_raw_body_and_warnings() {
+    let cmd = "curl -d 'a=1&b=2' --retry 3 --connect-timeout 5 https://api.example.com/form";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Httpie;
+    let http_cmd = converter.convert(&parsed);
+
+    assert!(http_cmd.contains("  --raw='a=1&b=2' \\\n"));
+    assert!(http_cmd.contains("  Content-Type:application/x-www-form-urlencoded\n"));
+    assert_eq!(converter.warnings(&parsed).len(), 2);
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Httpie;
+    let http_cmd = converter.convert(&parsed);
+
+    assert!(http_cmd.contains("'https://api.example.com/search?q=hello+world'"));
+    assert!(!http_cmd.contains("--form"));
+}
@@ -0,0 +1,2 @@
+pub mod httpie;
+pub mod wget;
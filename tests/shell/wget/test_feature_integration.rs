@@ -0,0 +1,74 @@
+use curl_parser::converters::shell::Wget;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_method_headers_and_body() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' -d '{\"name\":\"it'\\''s\"}' \
+               'https://api.example.com/items?a=1&b=2'";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Wget;
+    let wget_cmd = converter.convert(&parsed);
+
+    assert!(wget_cmd.starts_with("wget \\\n"));
+    assert!(wget_cmd.contains("  --method=POST \\\n"));
+    assert!(wget_cmd.contains("  --header='Content-Type: application/json' \\\n"));
+    assert!(wget_cmd.contains("  --body-data='{\"name\":\"it'\\''s\"}' \\\n"));
+    assert!(wget_cmd.contains("  -O - \\\n"));
+    assert!(wget_cmd.ends_with("  'https://api.example.com/items?a=1&b=2'\n"));
+}
+
+#[test]
+fn test_auth_tls_and_redirects() {
+    let cmd =
+        "curl -k -L --max-redirs 4 --connect-timeout 5 -u admin:secret --data-binary @payload.bin \
+               -o result.bin https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Wget;
+    let wget_cmd = converter.convert(&parsed);
+
+    assert!(wget_cmd.contains("  --body-file=payload.bin \\\n"));
+    assert!(
+        wget_cmd.contains("  --user=admin \\\n  --password=secret \\\n  --auth-no-challenge \\\n")
+    );
+    assert!(wget_cmd.contains("  --no-check-certificate \\\n"));
+    assert!(wget_cmd.contains("  --max-redirect=4 \\\n"));
+    assert!(wget_cmd.contains("  --connect-timeout=5 \\\n"));
+    assert!(wget_cmd.contains("  -O result.bin \\\n"));
+}
+
+#[test]
+fn test_unsupported_options_warn() {
+    let cmd = "curl -F file=@/tmp/data.csv -m 30 https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = Wget;
+    let warnings = converter.warnings(&parsed);
+
+    assert_eq!(warnings.len(), 2);
+    assert!(converter
+        .convert(&parsed)
+        .starts_with("# Warning: wget cannot send multipart/form-data"));
+    assert!(converter
+        .convert(&parsed)
+        .contains("  --max-redirect=0 \\\n"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = Wget;
+    let wget_cmd = converter.convert(&parsed);
+
+    assert!(wget_cmd.contains("'https://api.example.com/search?q=hello+world'"));
+    assert!(!wget_cmd.contains("--body-data"));
+}
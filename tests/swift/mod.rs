@@ -0,0 +1 @@
+pub mod url_session;
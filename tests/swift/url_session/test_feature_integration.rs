@@ -0,0 +1,132 @@
+use curl_parser::converters::swift::UrlSession;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+use curl_parser::CurlCommand;
+use std::collections::HashMap;
+
+#[test]
+fn test_request_and_headers() {
+    let cmd = "curl -L -X PATCH -H 'Accept: application/json' --json '{\"name\":\"test\"}' \
+               https://api.example.com/items/1";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = UrlSession;
+    let swift_code = converter.convert(&parsed);
+
+    assert!(swift_code.contains("let url = URL(string: \"https://api.example.com/items/1\")!"));
+    assert!(swift_code.contains("request.httpMethod = \"PATCH\""));
+    assert!(swift_code
+        .contains("request.setValue(\"application/json\", forHTTPHeaderField: \"Accept\")"));
+    assert!(swift_code
+        .contains("request.httpBody = \"{\\\"name\\\":\\\"test\\\"}\".data(using: .utf8)"));
+    assert!(swift_code.contains("let session = URLSession(configuration: configuration)"));
+    assert!(swift_code.contains("let (data, response) = try await session.data(for: request)"));
+    assert!(!swift_code.contains("SessionDelegate"));
+}
+
+#[test]
+fn test_timeouts_in_configuration() {
+    let cmd = "curl -L --connect-timeout 10 -m 60 https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = UrlSession;
+    let swift_code = converter.convert(&parsed);
+
+    assert!(swift_code.contains("configuration.timeoutIntervalForRequest = 10"));
+    assert!(swift_code.contains("configuration.timeoutIntervalForResource = 60"));
+    assert_eq!(
+        converter.warnings(&parsed),
+        vec!["URLSession has no connect timeout, --connect-timeout mapped to the idle timeoutIntervalForRequest".to_string()]
+    );
+}
+
+#[test]
+fn test_insecure_delegate_stub() {
+    let cmd = "curl -k -L https://self-signed.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = UrlSession;
+    let swift_code = converter.convert(&parsed);
+
+    assert!(swift_code.contains("final class SessionDelegate: NSObject, URLSessionTaskDelegate {"));
+    assert!(swift_code.contains("        case NSURLAuthenticationMethodServerTrust:"));
+    assert!(swift_code.contains("            return (.useCredential, URLCredential(trust: trust))"));
+    assert!(swift_code.contains("delegate: SessionDelegate(), delegateQueue: nil)"));
+}
+
+#[test]
+fn test_client_certificate_delegate() {
+    let cmd = "curl -L --cert client.p12 --cert-type P12 https://mtls.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = UrlSession;
+    let swift_code = converter.convert(&parsed);
+
+    assert!(swift_code.contains("        case NSURLAuthenticationMethodClientCertificate:"));
+    assert!(swift_code.contains("URL(fileURLWithPath: \"client.p12\")"));
+    assert!(swift_code.contains("SecPKCS12Import"));
+    assert!(converter.warnings(&parsed).is_empty());
+}
+
+#[test]
+fn test_multipart_body() {
+    let cmd = "curl -L -F name=report -F 'file=@/tmp/data.csv;type=text/csv' https://api.example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = UrlSession;
+    let swift_code = converter.convert(&parsed);
+
+    assert!(swift_code.contains("let boundary = \"Boundary-\\(UUID().uuidString)\""));
+    assert!(swift_code.contains(
+        "body.append(\"Content-Disposition: form-data; name=\\\"file\\\"; filename=\\\"data.csv\\\"\\r\\n\".data(using: .utf8)!)"
+    ));
+    assert!(swift_code
+        .contains("body.append(try Data(contentsOf: URL(fileURLWithPath: \"/tmp/data.csv\")))"));
+    assert!(swift_code.contains("request.httpBody = body"));
+}
+
+#[test]
+fn test_redirects_disabled_without_location() {
+    let cmd = "curl https://example.com/moved";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = UrlSession;
+    let swift_code = converter.convert(&parsed);
+
+    assert!(swift_code.contains("        willPerformHTTPRedirection response: HTTPURLResponse,"));
+    assert!(swift_code.contains("        return nil"));
+}
+
+#[test]
+fn test_url_encoded_params_on_get_are_not_sent_as_body() {
+    let parsed = CurlCommand {
+        url: "https://api.example.com/search?q=hello+world".to_string(),
+        url_encoded_params: HashMap::from([("q".to_string(), "hello world".to_string())]),
+        ..Default::default()
+    };
+
+    let converter = UrlSession;
+    let swift_code = converter.convert(&parsed);
+
+    assert!(swift_code.contains("request.httpMethod = \"GET\""));
+    assert!(!swift_code.contains("httpBody"));
+}
+
+#[test]
+fn test_https_proxy_keys_are_macos_only() {
+    let cmd = "curl -x http://proxy.example.com:3128 https://api.example.com";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = UrlSession;
+    let swift_code = converter.convert(&parsed);
+
+    assert!(swift_code.contains(
+        "    kCFNetworkProxiesHTTPPort: 3128,\n\
+         ]\n\
+         #if os(macOS)\n\
+         configuration.connectionProxyDictionary?[kCFNetworkProxiesHTTPSEnable] = true\n\
+         configuration.connectionProxyDictionary?[kCFNetworkProxiesHTTPSProxy] = \"proxy.example.com\"\n\
+         configuration.connectionProxyDictionary?[kCFNetworkProxiesHTTPSPort] = 3128\n\
+         #endif\n"
+    ));
+}
@@ -0,0 +1,70 @@
+use curl_parser::converters::terraform::TerraformHttp;
+use curl_parser::converters::Convert;
+use curl_parser::parse_curl_command;
+
+#[test]
+fn test_http_data_source() {
+    let cmd = "curl -X POST -H 'Content-Type: application/json' -d '{\"name\":\"test\"}' \
+               https://api.example.com/items";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = TerraformHttp;
+    let result = converter.convert(&parsed);
+
+    assert_eq!(
+        result,
+        "data \"http\" \"post_items\" {\n\
+         \x20 url    = \"https://api.example.com/items\"\n\
+         \x20 method = \"POST\"\n\
+         \n\
+         \x20 request_headers = {\n\
+         \x20   \"Content-Type\" = \"application/json\"\n\
+         \x20 }\n\
+         \n\
+         \x20 request_body = \"{\\\"name\\\":\\\"test\\\"}\"\n\
+         }\n"
+    );
+}
+
+#[test]
+fn test_tls_timeout_and_retry() {
+    let cmd = "curl -k --cacert ca.pem -m 5 --retry 3 https://example.com/health";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = TerraformHttp;
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains("  insecure           = true\n"));
+    assert!(result.contains("  ca_cert_pem        = file(\"ca.pem\")\n"));
+    assert!(result.contains("  request_timeout_ms = 5000\n"));
+    assert!(result.contains("  retry {\n    attempts = 3\n  }\n"));
+}
+
+#[test]
+fn test_basic_auth_and_interpolation_escaping() {
+    let cmd = "curl -u admin:secret -d 'value=${var}' https://example.com/login";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = TerraformHttp;
+    let result = converter.convert(&parsed);
+
+    assert!(result.contains("\"Authorization\" = \"Basic ${base64encode(\"admin:secret\")}\""));
+    assert!(result.contains("\"Content-Type\"  = \"application/x-www-form-urlencoded\""));
+    assert!(result.contains("request_body = \"value=$${var}\""));
+}
+
+#[test]
+fn test_unsupported_options_warn() {
+    let cmd = "curl -F 'file=@data.csv' --digest -u user:pass -x http://proxy:8080 \
+               https://example.com/upload";
+    let parsed = parse_curl_command(cmd).unwrap();
+
+    let converter = TerraformHttp;
+    let warnings = converter.warnings(&parsed);
+    assert_eq!(warnings.len(), 3);
+
+    let result = converter.convert(&parsed);
+    assert!(result.starts_with("# "));
+    assert!(!result.contains("request_body"));
+    assert!(!result.contains("Authorization"));
+}
@@ -0,0 +1,85 @@
+use curl_parser::parse_curl_command;
+use curl_parser::validation::{Mismatch, SpecError, SpecValidator};
+
+const SPEC: &str = include_str!("openapi.yaml");
+
+fn validate(cmd: &str) -> Vec<Mismatch> {
+    let validator = SpecValidator::new(SPEC).unwrap();
+    validator.validate(&parse_curl_command(cmd).unwrap())
+}
+
+#[test]
+fn test_matching_command() {
+    let mismatches = validate("curl -H 'X-Tenant: acme' https://api.example.com/v1/users/42");
+
+    assert!(mismatches.is_empty());
+}
+
+#[test]
+fn test_unknown_path_and_method() {
+    assert_eq!(
+        validate("curl https://api.example.com/v1/orders"),
+        vec![Mismatch::UnknownPath("/v1/orders".to_string())]
+    );
+    assert_eq!(
+        validate("curl -X DELETE https://api.example.com/v1/users/42"),
+        vec![Mismatch::UnknownMethod {
+            method: "DELETE".to_string(),
+            path: "/users/{id}".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_missing_required_parameters() {
+    assert_eq!(
+        validate("curl https://api.example.com/v1/users/42"),
+        vec![Mismatch::MissingHeader("X-Tenant".to_string())]
+    );
+    assert_eq!(
+        validate("curl -X POST --json '{\"name\":\"a\"}' https://api.example.com/v1/users"),
+        vec![Mismatch::MissingQuery("page".to_string())]
+    );
+}
+
+#[test]
+fn test_content_type_and_body() {
+    let mismatches = validate(
+        "curl -X POST -H 'Content-Type: text/plain' -d 'hello' \
+         'https://api.example.com/v1/users?page=1'",
+    );
+    assert_eq!(
+        mismatches,
+        vec![Mismatch::WrongContentType {
+            actual: "text/plain".to_string(),
+            expected: vec!["application/json".to_string()],
+        }]
+    );
+
+    let mismatches = validate(
+        "curl -X POST -H 'Content-Type: application/json' -d '{\"age\":\"x\"}' \
+         'https://api.example.com/v1/users?page=1'",
+    );
+    assert_eq!(mismatches.len(), 2);
+    assert!(mismatches
+        .iter()
+        .all(|mismatch| matches!(mismatch, Mismatch::InvalidBody(_))));
+    assert!(mismatches[1].to_string().contains("/age"));
+
+    assert_eq!(
+        validate("curl -X POST 'https://api.example.com/v1/users?page=1'"),
+        vec![Mismatch::MissingBody]
+    );
+    assert!(validate(
+        "curl -X PUT -H 'Content-Type: image/png' --data-binary @me.png \
+         https://api.example.com/v1/avatars"
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_spec_without_paths() {
+    let result = SpecValidator::new("openapi: 3.1.0\ninfo:\n  title: Empty\n");
+
+    assert!(matches!(result, Err(SpecError::MissingPaths)));
+}